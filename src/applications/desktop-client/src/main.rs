@@ -28,20 +28,20 @@ impl Default for DiagramVisualizer {
             Node::new("3".into(), NodeType::Component("Cache".into())),
         ];
         let edges = vec![
-            Edge {
-                from_id: "1".into(),
-                to_id: "2".into(),
-                style_from: EdgeStyle::Solid,
-                style_to: EdgeStyle::Arrow,
-                label: None,
-            },
-            Edge {
-                from_id: "1".into(),
-                to_id: "3".into(),
-                style_from: EdgeStyle::Solid,
-                style_to: EdgeStyle::Arrow,
-                label: None,
-            },
+            Edge::new(
+                "1".into(),
+                "2".into(),
+                EdgeStyle::Solid,
+                EdgeStyle::Arrow,
+                None,
+            ),
+            Edge::new(
+                "1".into(),
+                "3".into(),
+                EdgeStyle::Solid,
+                EdgeStyle::Arrow,
+                None,
+            ),
         ];
 
         let diagram = Diagram::new(Some("Architecture".into()), nodes, edges);
@@ -128,14 +128,12 @@ impl canvas::Program<Message> for DiagramVisualizer {
                     frame.stroke(&node_rect, Stroke::default());
 
                     // Draw Text Label
-                    if let NodeType::Component(name) = &node.r#type {
-                        frame.fill_text(Text {
-                            content: name.clone(),
-                            position: *pos + Vector::new(50.0, 25.0), // Center text
-                            color: Color::WHITE,
-                            ..Text::default()
-                        });
-                    }
+                    frame.fill_text(Text {
+                        content: node.r#type.name().to_string(),
+                        position: *pos + Vector::new(50.0, 25.0), // Center text
+                        color: Color::WHITE,
+                        ..Text::default()
+                    });
                 }
             }
         });
@@ -1,6 +1,20 @@
+pub(crate) mod ascii_activity;
+pub(crate) mod ascii_border;
 pub(crate) mod ascii_border_decorator;
+pub(crate) mod ascii_column;
+pub(crate) mod ascii_compartments;
 pub mod ascii_composite;
+pub(crate) mod ascii_data;
 pub mod ascii_element;
+pub(crate) mod ascii_gantt;
+pub(crate) mod ascii_graph;
 pub mod ascii_grid;
+pub(crate) mod ascii_icon_decorator;
+pub(crate) mod ascii_note_decorator;
+pub(crate) mod ascii_row;
+pub(crate) mod ascii_sequence;
+pub(crate) mod ascii_table;
 pub mod ascii_text;
+pub(crate) mod ascii_timing;
+pub(crate) mod ascii_tree;
 pub mod position;
@@ -0,0 +1,863 @@
+use lib_core::domain::entities::activity::{ActivityStep, Branch, Loop};
+
+use crate::adapters::models::{
+    ascii_border_decorator::AsciiBorderDecorator, ascii_element::AsciiElement,
+    ascii_grid::AsciiGrid, ascii_text::AsciiText, position::Position,
+};
+
+// Rows between two steps: a line, then an arrow head
+const LINK_HEIGHT: i32 = 2;
+// Room between two flows side by side
+const BRANCH_SPACING: i32 = 3;
+
+const START_ICON: char = '●';
+const STOP_ICON: char = '◉';
+const END_ICON: char = '⊗';
+const DIAMOND: char = '◇';
+const BAR: char = '━';
+
+/// Draws the flow of an activity from top to bottom. Branches split from a diamond and merge
+/// back into another one, loops go back up on their left side, forks split from a bar and
+/// join into another one. Swimlanes are columns side by side, each step going in the lane
+/// it was written in; steps nested in a branch, a loop or a fork stay in the lane of the block.
+pub(crate) struct AsciiActivity {
+    steps: Vec<ActivityStep>,
+    position: Position,
+}
+
+impl AsciiActivity {
+    pub(crate) fn new(position: Position) -> Self {
+        Self {
+            steps: Vec::new(),
+            position,
+        }
+    }
+
+    pub(crate) fn with_step(mut self, step: ActivityStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    fn layout(&self) -> ActivityLayout {
+        let mut lanes: Vec<String> = Vec::new();
+        let mut lane: usize = 0;
+        let mut blocks: Vec<(usize, Flow)> = Vec::new();
+        let lane_of =
+            |name: &str, lanes: &mut Vec<String>| match lanes.iter().position(|l| l == name) {
+                Some(index) => index,
+                None => {
+                    lanes.push(name.to_string());
+                    lanes.len() - 1
+                }
+            };
+
+        for step in self.steps.iter() {
+            match step {
+                ActivityStep::Swimlane(name) => lane = lane_of(name, &mut lanes),
+                _ => {
+                    blocks.extend(Flow::of(step).map(|flow| (lane, flow)));
+                    if let Some(name) = last_swimlane(std::slice::from_ref(step)) {
+                        lane = lane_of(name, &mut lanes);
+                    }
+                }
+            }
+        }
+
+        // Each lane is wide enough for its name and for every block on both sides of its center
+        let framed: bool = !lanes.is_empty();
+        let mut centers: Vec<i32> = Vec::new();
+        let mut borders: Vec<i32> = vec![0];
+        for index in 0..lanes.len().max(1) {
+            let in_lane = || blocks.iter().filter(|(l, _)| *l == index);
+            let left: i32 = in_lane().map(|(_, f)| f.center).max().unwrap_or(0);
+            let right: i32 = in_lane()
+                .map(|(_, f)| f.width - f.center)
+                .max()
+                .unwrap_or(1);
+            let name: i32 = lanes.get(index).map_or(0, |name| text_width(name));
+            let start: i32 = borders[index] + if framed { 2 } else { 0 };
+            let inner: i32 = (left + right).max(name);
+
+            centers.push(start + (inner - left - right) / 2 + left);
+            borders.push(start + inner + if framed { 1 } else { 0 });
+        }
+
+        let header: i32 = if framed { 2 } else { 0 };
+        let flows: i32 = blocks.iter().map(|(_, f)| f.height).sum::<i32>();
+        let links: i32 = LINK_HEIGHT * (blocks.len() as i32 - 1).max(0);
+
+        ActivityLayout {
+            width: borders.last().copied().unwrap_or(0) + if framed { 1 } else { 0 },
+            height: match blocks.is_empty() {
+                true => header,
+                false => header + flows + links,
+            },
+            header,
+            lanes,
+            centers,
+            borders,
+            blocks,
+        }
+    }
+}
+
+impl AsciiElement for AsciiActivity {
+    fn draw(&mut self, grid: &mut AsciiGrid) {
+        let mut layout: ActivityLayout = self.layout();
+        let Position { x, y } = self.position();
+
+        if !layout.lanes.is_empty() {
+            for (index, name) in layout.lanes.iter().enumerate() {
+                let (start, end) = (layout.borders[index], layout.borders[index + 1]);
+                let room: i32 = end - start - 1;
+
+                draw_text(grid, name, x + start + 1 + (room - text_width(name)) / 2, y);
+            }
+            for column in 0..layout.width {
+                put(grid, '─', x + column, y + 1);
+            }
+            for (index, border) in layout.borders.iter().enumerate() {
+                for row in 0..layout.height {
+                    put(grid, '│', x + border, y + row);
+                }
+                let junction: char = match index {
+                    0 => '├',
+                    i if i + 1 == layout.borders.len() => '┤',
+                    _ => '┼',
+                };
+                put(grid, junction, x + border, y + 1);
+            }
+        }
+
+        let mut top: i32 = y + layout.header;
+        let mut previous: Option<(i32, bool)> = None;
+        for (lane, flow) in layout.blocks.iter_mut() {
+            let center: i32 = x + layout.centers[*lane];
+
+            // A step in another lane is reached by going across the lanes in between
+            match previous {
+                Some((from, true)) if from != center => {
+                    let (start, end) = match from < center {
+                        true => ('└', '┐'),
+                        false => ('┘', '┌'),
+                    };
+
+                    for column in from.min(center)..=from.max(center) {
+                        let crossing: bool = layout.borders.iter().any(|b| x + b == column);
+
+                        put(grid, if crossing { '┼' } else { '─' }, column, top - 2);
+                    }
+                    put(grid, start, from, top - 2);
+                    put(grid, end, center, top - 2);
+                    put(grid, '▼', center, top - 1);
+                }
+                Some((from, true)) => {
+                    put(grid, '│', from, top - 2);
+                    put(grid, '▼', from, top - 1);
+                }
+                _ => {}
+            }
+
+            flow.draw(grid, center - flow.center, top);
+            previous = Some((center, flow.open));
+            top += flow.height + LINK_HEIGHT;
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position.clone()
+    }
+
+    fn width(&self) -> u16 {
+        self.layout().width as u16
+    }
+
+    fn height(&self) -> u16 {
+        self.layout().height as u16
+    }
+
+    fn r#move(&mut self, new_position: Position) {
+        self.position = new_position;
+    }
+}
+
+struct ActivityLayout {
+    lanes: Vec<String>,
+    // Lane index, Flow of every top level step
+    blocks: Vec<(usize, Flow)>,
+    // Column of the flow going down each lane
+    centers: Vec<i32>,
+    // Columns of the lines between lanes, including the outer ones
+    borders: Vec<i32>,
+    // Rows of the lane names
+    header: i32,
+    width: i32,
+    height: i32,
+}
+
+// A part of the flow laid out in a box of its own, entered at the top and left at the bottom
+// through its center column
+struct Flow {
+    kind: FlowKind,
+    width: i32,
+    height: i32,
+    center: i32,
+    // The flow does not go on past "stop" or "end"
+    open: bool,
+}
+
+enum FlowKind {
+    // An icon or an action box
+    Step(Box<dyn AsciiElement>),
+    // Offset, Flow; stacked from top to bottom
+    Steps(Vec<(i32, Flow)>),
+    // Condition, then Offset, Label and Flow of each branch
+    If(String, Vec<(i32, Option<String>, Flow)>),
+    // Condition, Label, Exit label, Offset of the body, Body
+    While(String, Option<String>, Option<String>, i32, Box<Flow>),
+    Repeat(String, Option<String>, Option<String>, i32, Box<Flow>),
+    // Offset, Flow of each branch
+    Fork(Vec<(i32, Flow)>),
+}
+
+impl Flow {
+    // Swimlanes take no room in the flow, they only move the steps that follow
+    fn of(step: &ActivityStep) -> Option<Flow> {
+        let icon = |icon: char, open: bool| {
+            Flow::step(
+                Box::new(AsciiText::new(Position::default(), icon.to_string())),
+                open,
+            )
+        };
+
+        Some(match step {
+            ActivityStep::Start => icon(START_ICON, true),
+            ActivityStep::Stop => icon(STOP_ICON, false),
+            ActivityStep::End => icon(END_ICON, false),
+            ActivityStep::Action(text) => Flow::step(
+                Box::new(AsciiBorderDecorator::new(
+                    Box::new(AsciiText::new(Position::default(), text.clone())),
+                    Position::default(),
+                    1,
+                    0,
+                    ' ',
+                )),
+                true,
+            ),
+            ActivityStep::Swimlane(_) => return None,
+            ActivityStep::If(branches) => Flow::branching(branches),
+            ActivityStep::While(l) => Flow::while_loop(l),
+            ActivityStep::Repeat(l) => Flow::repeat_loop(l),
+            ActivityStep::Fork(flows) => Flow::fork(flows),
+        })
+    }
+
+    fn step(element: Box<dyn AsciiElement>, open: bool) -> Flow {
+        Flow {
+            width: element.width() as i32,
+            height: element.height() as i32,
+            center: element.width() as i32 / 2,
+            kind: FlowKind::Step(element),
+            open,
+        }
+    }
+
+    // Steps share their center column, an empty flow is a mere line
+    fn steps(steps: &[ActivityStep]) -> Flow {
+        let flows: Vec<Flow> = steps.iter().filter_map(Flow::of).collect();
+        let center: i32 = flows.iter().map(|f| f.center).max().unwrap_or(0);
+        let flows: Vec<(i32, Flow)> = flows.into_iter().map(|f| (center - f.center, f)).collect();
+
+        Flow {
+            width: flows.iter().map(|(o, f)| o + f.width).max().unwrap_or(1),
+            height: flows.iter().map(|(_, f)| f.height).sum::<i32>()
+                + LINK_HEIGHT * (flows.len() as i32 - 1).max(0),
+            center,
+            open: flows.last().is_none_or(|(_, f)| f.open),
+            kind: FlowKind::Steps(flows),
+        }
+    }
+
+    // ◇ condition
+    // ┌──┴──┐
+    // │ yes │ no
+    // ▼     ▼
+    // ...   ...
+    // └──◇──┘
+    fn branching(branches: &[Branch]) -> Flow {
+        let mut labels: Vec<(Option<String>, Flow)> = branches
+            .iter()
+            .enumerate()
+            .map(|(index, branch)| {
+                let label: Option<String> = match (index, &branch.condition) {
+                    (0, _) | (_, None) => branch.label.clone(),
+                    (_, Some(condition)) => Some(match &branch.label {
+                        Some(label) => format!("[{}] {}", condition, label),
+                        None => format!("[{}]", condition),
+                    }),
+                };
+
+                (label, Flow::steps(&branch.steps))
+            })
+            .collect();
+        // Without an "else", the flow goes straight to the merge
+        if branches.len() < 2 || branches.iter().skip(1).all(|b| b.condition.is_some()) {
+            labels.push((None, Flow::steps(&[])));
+        }
+
+        let (offsets, width) = side_by_side(
+            labels
+                .iter()
+                .map(|(label, flow)| (flow, 2 + label.as_deref().map_or(0, text_width))),
+        );
+        let centers: Vec<i32> = centers(&offsets, labels.iter().map(|(_, f)| f));
+        let center: i32 = (centers[0] + centers[centers.len() - 1]) / 2;
+        let condition: String = branches
+            .first()
+            .and_then(|b| b.condition.clone())
+            .unwrap_or_default();
+
+        Flow {
+            width: width.max(center + 2 + text_width(&condition)),
+            height: 6 + labels.iter().map(|(_, f)| f.height).max().unwrap_or(0),
+            center,
+            open: labels.iter().any(|(_, f)| f.open),
+            kind: FlowKind::If(
+                condition,
+                offsets
+                    .into_iter()
+                    .zip(labels)
+                    .map(|(offset, (label, flow))| (offset, label, flow))
+                    .collect(),
+            ),
+        }
+    }
+
+    // ┌─▶◇ condition ─┐
+    // │   │ yes       │ no
+    // │   ▼           │
+    // │  ...          │
+    // └───┘           │
+    //     ┌───────────┘
+    fn while_loop(l: &Loop) -> Flow {
+        let body: Flow = Flow::steps(&l.steps);
+        let offset: i32 = 2;
+        let center: i32 = offset + body.center;
+        let right: i32 = (offset + body.width + 1)
+            .max(center + text_width(&l.condition) + 4)
+            .max(center + label_width(&l.label) + 3);
+
+        Flow {
+            width: right + 1 + l.exit_label.as_deref().map_or(0, |e| 1 + text_width(e)),
+            height: 6 + body.height,
+            center,
+            open: true,
+            kind: FlowKind::While(
+                l.condition.clone(),
+                l.label.clone(),
+                l.exit_label.clone(),
+                offset,
+                Box::new(body),
+            ),
+        }
+    }
+
+    // ┌─▶◇
+    // │  ▼
+    // │ ...
+    // │  ▼
+    // └─ yes ─◇ condition
+    //         │ no
+    fn repeat_loop(l: &Loop) -> Flow {
+        let body: Flow = Flow::steps(&l.steps);
+        let offset: i32 = match &l.label {
+            Some(label) => 2.max(text_width(label) + 4 - body.center),
+            None => 2,
+        };
+        let center: i32 = offset + body.center;
+
+        Flow {
+            width: (offset + body.width)
+                .max(center + 2 + text_width(&l.condition))
+                .max(center + 2 + label_width(&l.exit_label)),
+            height: 6 + body.height + i32::from(l.exit_label.is_some()),
+            center,
+            open: true,
+            kind: FlowKind::Repeat(
+                l.condition.clone(),
+                l.label.clone(),
+                l.exit_label.clone(),
+                offset,
+                Box::new(body),
+            ),
+        }
+    }
+
+    // ━━━━━━━━━
+    // ▼       ▼
+    // ...   ...
+    // ━━━━━━━━━
+    fn fork(flows: &[Vec<ActivityStep>]) -> Flow {
+        let flows: Vec<Flow> = flows.iter().map(|steps| Flow::steps(steps)).collect();
+        let (offsets, width) = side_by_side(flows.iter().map(|flow| (flow, 1)));
+        let centers: Vec<i32> = centers(&offsets, flows.iter());
+        let (first, last) = (
+            centers.first().copied().unwrap_or(0),
+            centers.last().copied().unwrap_or(0),
+        );
+
+        Flow {
+            width: width.max(last + 2),
+            height: 5 + flows.iter().map(|f| f.height).max().unwrap_or(0),
+            center: (first + last) / 2,
+            open: flows.iter().any(|f| f.open),
+            kind: FlowKind::Fork(offsets.into_iter().zip(flows).collect()),
+        }
+    }
+
+    fn draw(&mut self, grid: &mut AsciiGrid, x: i32, y: i32) {
+        let (center, height, width) = (x + self.center, self.height, self.width);
+
+        match &mut self.kind {
+            FlowKind::Step(element) => {
+                element.r#move(Position::new(x, y));
+                element.draw(grid);
+            }
+            FlowKind::Steps(flows) => {
+                let mut top: i32 = y;
+                let count: usize = flows.len();
+
+                for (index, (offset, flow)) in flows.iter_mut().enumerate() {
+                    flow.draw(grid, x + *offset, top);
+                    top += flow.height;
+
+                    if index + 1 < count && flow.open {
+                        put(grid, '│', center, top);
+                        put(grid, '▼', center, top + 1);
+                    }
+                    top += LINK_HEIGHT;
+                }
+            }
+            FlowKind::If(condition, branches) => {
+                let inner: i32 = branches.iter().map(|(_, _, f)| f.height).max().unwrap_or(0);
+                let columns: Vec<i32> = branches.iter().map(|(o, _, f)| x + o + f.center).collect();
+
+                put(grid, DIAMOND, center, y);
+                draw_text(grid, condition, center + 2, y);
+                split(grid, &columns, center, y + 1);
+
+                for (column, (offset, label, flow)) in columns.iter().zip(branches.iter_mut()) {
+                    put(grid, '│', *column, y + 2);
+                    if let Some(label) = label {
+                        draw_text(grid, label, column + 2, y + 2);
+                    }
+                    enter(grid, flow, *column, y + 3);
+                    flow.draw(grid, x + *offset, y + 4);
+                    leave(grid, flow, *column, y + 4, inner + 1);
+                }
+
+                let open: Vec<i32> = columns
+                    .iter()
+                    .zip(branches.iter())
+                    .filter(|(_, (_, _, f))| f.open)
+                    .map(|(column, _)| *column)
+                    .collect();
+                if !open.is_empty() {
+                    merge(grid, &open, center, y + height - 1);
+                }
+            }
+            FlowKind::While(condition, label, exit_label, offset, body) => {
+                let right: i32 =
+                    x + width - 1 - exit_label.as_deref().map_or(0, |e| 1 + text_width(e));
+
+                put(grid, '┌', x, y);
+                (x + 1..center - 1).for_each(|column| put(grid, '─', column, y));
+                put(grid, '▶', center - 1, y);
+                put(grid, DIAMOND, center, y);
+                draw_text(grid, condition, center + 2, y);
+                (center + 3 + text_width(condition)..right).for_each(|c| put(grid, '─', c, y));
+                put(grid, '┐', right, y);
+
+                put(grid, '│', center, y + 1);
+                if let Some(label) = label {
+                    draw_text(grid, label, center + 2, y + 1);
+                }
+                if let Some(exit_label) = exit_label {
+                    draw_text(grid, exit_label, right + 2, y + 1);
+                }
+                enter(grid, body, center, y + 2);
+                body.draw(grid, x + *offset, y + 3);
+                leave(grid, body, center, y + 3, body.height + 1);
+
+                for row in y + 1..y + height - 2 {
+                    put(grid, '│', x, row);
+                }
+                for row in y + 1..y + height - 1 {
+                    put(grid, '│', right, row);
+                }
+                put(grid, '└', x, y + height - 2);
+                (x + 1..center).for_each(|column| put(grid, '─', column, y + height - 2));
+                put(grid, '┘', center, y + height - 2);
+
+                put(grid, '┌', center, y + height - 1);
+                (center + 1..right).for_each(|column| put(grid, '─', column, y + height - 1));
+                put(grid, '┘', right, y + height - 1);
+            }
+            FlowKind::Repeat(condition, label, exit_label, offset, body) => {
+                let bottom: i32 = y + 5 + body.height;
+
+                put(grid, '┌', x, y);
+                (x + 1..center - 1).for_each(|column| put(grid, '─', column, y));
+                put(grid, '▶', center - 1, y);
+                put(grid, DIAMOND, center, y);
+
+                put(grid, '│', center, y + 1);
+                enter(grid, body, center, y + 2);
+                body.draw(grid, x + *offset, y + 3);
+                leave(grid, body, center, y + 3, body.height + 1);
+                put(grid, '▼', center, bottom - 1);
+
+                for row in y + 1..bottom {
+                    put(grid, '│', x, row);
+                }
+                put(grid, '└', x, bottom);
+                (x + 1..center).for_each(|column| put(grid, '─', column, bottom));
+                if let Some(label) = label {
+                    draw_text(grid, &format!(" {} ", label), x + 1, bottom);
+                }
+                put(grid, DIAMOND, center, bottom);
+                draw_text(grid, condition, center + 2, bottom);
+
+                if let Some(exit_label) = exit_label {
+                    put(grid, '│', center, bottom + 1);
+                    draw_text(grid, exit_label, center + 2, bottom + 1);
+                }
+            }
+            FlowKind::Fork(flows) => {
+                let inner: i32 = flows.iter().map(|(_, f)| f.height).max().unwrap_or(0);
+                let columns: Vec<i32> = flows.iter().map(|(o, f)| x + o + f.center).collect();
+                let (first, last) = (
+                    columns.first().copied().unwrap_or(center),
+                    columns.last().copied().unwrap_or(center),
+                );
+
+                for bar in [y, y + height - 1] {
+                    ((first - 1).max(x)..=last + 1).for_each(|column| put(grid, BAR, column, bar));
+                }
+                for (column, (offset, flow)) in columns.iter().zip(flows.iter_mut()) {
+                    put(grid, '│', *column, y + 1);
+                    enter(grid, flow, *column, y + 2);
+                    flow.draw(grid, x + *offset, y + 3);
+                    leave(grid, flow, *column, y + 3, inner + 1);
+                }
+            }
+        }
+    }
+}
+
+// Offsets of flows put side by side, each one with some room on the right of its center
+// for a label, then the width they take together
+fn side_by_side<'a>(flows: impl Iterator<Item = (&'a Flow, i32)>) -> (Vec<i32>, i32) {
+    let mut offsets: Vec<i32> = Vec::new();
+    let mut left: i32 = 0;
+
+    for (flow, room) in flows {
+        offsets.push(left);
+        left += flow.center + (flow.width - flow.center).max(room) + BRANCH_SPACING;
+    }
+
+    (offsets, (left - BRANCH_SPACING).max(0))
+}
+
+fn centers<'a>(offsets: &[i32], flows: impl Iterator<Item = &'a Flow>) -> Vec<i32> {
+    offsets
+        .iter()
+        .zip(flows)
+        .map(|(o, f)| o + f.center)
+        .collect()
+}
+
+// The head above a flow, or the line going through an empty one
+fn enter(grid: &mut AsciiGrid, flow: &Flow, column: i32, y: i32) {
+    put(grid, if flow.height > 0 { '▼' } else { '│' }, column, y);
+}
+
+// The line from the bottom of a flow down to the row `rows` below its top
+fn leave(grid: &mut AsciiGrid, flow: &Flow, column: i32, top: i32, rows: i32) {
+    if flow.open {
+        (top + flow.height..top + rows).for_each(|row| put(grid, '│', column, row));
+    }
+}
+
+// ┌──┬──┴──┐ from the center of the block down to each flow
+fn split(grid: &mut AsciiGrid, columns: &[i32], center: i32, y: i32) {
+    rule(grid, columns, center, y, ('┌', '┬', '┐'));
+    let crossed: bool = columns[1..columns.len().saturating_sub(1)].contains(&center);
+    put(grid, if crossed { '┼' } else { '┴' }, center, y);
+}
+
+// └──┴──◇──┘ from each flow back to the center of the block
+fn merge(grid: &mut AsciiGrid, columns: &[i32], center: i32, y: i32) {
+    rule(grid, columns, center, y, ('└', '┴', '┘'));
+    put(grid, DIAMOND, center, y);
+}
+
+fn rule(
+    grid: &mut AsciiGrid,
+    columns: &[i32],
+    center: i32,
+    y: i32,
+    (first, middle, last): (char, char, char),
+) {
+    let left: i32 = columns.iter().copied().min().unwrap_or(center).min(center);
+    let right: i32 = columns.iter().copied().max().unwrap_or(center).max(center);
+
+    (left..=right).for_each(|column| put(grid, '─', column, y));
+    for column in columns {
+        let ch: char = match *column {
+            c if c == left && c < center => first,
+            c if c == right && c > center => last,
+            _ => middle,
+        };
+        put(grid, ch, *column, y);
+    }
+}
+
+// The lane the flow is in after the given steps, if any of them switches lanes
+fn last_swimlane(steps: &[ActivityStep]) -> Option<&str> {
+    steps.iter().rev().find_map(|step| match step {
+        ActivityStep::Swimlane(name) => Some(name.as_str()),
+        ActivityStep::If(branches) => branches.iter().rev().find_map(|b| last_swimlane(&b.steps)),
+        ActivityStep::While(l) | ActivityStep::Repeat(l) => last_swimlane(&l.steps),
+        ActivityStep::Fork(flows) => flows.iter().rev().find_map(|f| last_swimlane(f)),
+        _ => None,
+    })
+}
+
+fn draw_text(grid: &mut AsciiGrid, text: &str, x: i32, y: i32) {
+    AsciiText::new(Position::new(x, y), text.to_string()).draw(grid);
+}
+
+fn text_width(text: &str) -> i32 {
+    AsciiText::new(Position::default(), text.to_string()).width() as i32
+}
+
+fn label_width(label: &Option<String>) -> i32 {
+    label.as_deref().map_or(0, text_width)
+}
+
+fn put(grid: &mut AsciiGrid, ch: char, x: i32, y: i32) {
+    grid.put_char(ch, &Position::new(x, y));
+}
+
+#[cfg(test)]
+mod test {
+    use lib_core::domain::entities::activity::Loop;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn action(text: &str) -> ActivityStep {
+        ActivityStep::Action(text.to_string())
+    }
+
+    fn drawn(activity: &mut AsciiActivity) -> String {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        activity.draw(&mut grid);
+
+        grid.to_string()
+    }
+
+    #[test]
+    fn branches_merge_into_a_diamond() {
+        let mut activity: AsciiActivity = AsciiActivity::new(Position::default())
+            .with_step(ActivityStep::Start)
+            .with_step(ActivityStep::If(vec![
+                Branch::new(
+                    Some("ok?".to_string()),
+                    Some("yes".to_string()),
+                    vec![action("A")],
+                ),
+                Branch::new(None, None, vec![action("B"), ActivityStep::Stop]),
+            ]))
+            .with_step(ActivityStep::End);
+
+        assert_eq!(
+            drawn(&mut activity),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '●'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '▼'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '◇', ' ', 'o', 'k', '?'],
+                    vec![
+                        ' ', ' ', '┌', '─', '─', '─', '─', '┴', '─', '─', '─', '─', '┐'
+                    ],
+                    vec![
+                        ' ', ' ', '│', ' ', 'y', 'e', 's', ' ', ' ', ' ', ' ', ' ', '│'
+                    ],
+                    vec![
+                        ' ', ' ', '▼', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '▼'
+                    ],
+                    vec![
+                        '╭', '─', '─', '─', '╮', ' ', ' ', ' ', ' ', ' ', '╭', '─', '─', '─', '╮'
+                    ],
+                    vec![
+                        '│', ' ', 'A', ' ', '│', ' ', ' ', ' ', ' ', ' ', '│', ' ', 'B', ' ', '│'
+                    ],
+                    vec![
+                        '╰', '─', '─', '─', '╯', ' ', ' ', ' ', ' ', ' ', '╰', '─', '─', '─', '╯'
+                    ],
+                    vec![
+                        ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'
+                    ],
+                    vec![
+                        ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '▼'
+                    ],
+                    vec![
+                        ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '◉'
+                    ],
+                    vec![' ', ' ', '│'],
+                    vec![' ', ' ', '└', '─', '─', '─', '─', '◇'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '▼'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '⊗'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn loops_go_back_up_on_their_left() {
+        let mut activity: AsciiActivity = AsciiActivity::new(Position::default())
+            .with_step(ActivityStep::While(Loop {
+                label: Some("y".to_string()),
+                exit_label: Some("n".to_string()),
+                ..Loop::new("c".to_string(), vec![action("A")])
+            }))
+            .with_step(ActivityStep::Repeat(Loop {
+                label: Some("y".to_string()),
+                ..Loop::new("d".to_string(), vec![action("B")])
+            }));
+
+        assert_eq!(
+            drawn(&mut activity),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![' ', '┌', '─', '─', '▶', '◇', ' ', 'c', ' ', '─', '┐'],
+                    vec![
+                        ' ', '│', ' ', ' ', ' ', '│', ' ', 'y', ' ', ' ', '│', ' ', 'n'
+                    ],
+                    vec![' ', '│', ' ', ' ', ' ', '▼', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', '│', ' ', '╭', '─', '─', '─', '╮', ' ', ' ', '│'],
+                    vec![' ', '│', ' ', '│', ' ', 'A', ' ', '│', ' ', ' ', '│'],
+                    vec![' ', '│', ' ', '╰', '─', '─', '─', '╯', ' ', ' ', '│'],
+                    vec![' ', '│', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', '└', '─', '─', '─', '┘', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', '┌', '─', '─', '─', '─', '┘'],
+                    vec![' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', '▼'],
+                    vec!['┌', '─', '─', '─', '▶', '◇'],
+                    vec!['│', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', '▼'],
+                    vec!['│', ' ', ' ', '╭', '─', '─', '─', '╮'],
+                    vec!['│', ' ', ' ', '│', ' ', 'B', ' ', '│'],
+                    vec!['│', ' ', ' ', '╰', '─', '─', '─', '╯'],
+                    vec!['│', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', '▼'],
+                    vec!['└', ' ', 'y', ' ', '─', '◇', ' ', 'd'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn swimlanes_are_columns() {
+        let mut activity: AsciiActivity = AsciiActivity::new(Position::default())
+            .with_step(ActivityStep::Swimlane("Ui".to_string()))
+            .with_step(ActivityStep::Start)
+            .with_step(ActivityStep::Swimlane("Api".to_string()))
+            .with_step(ActivityStep::Fork(vec![vec![action("A")], vec![]]))
+            .with_step(ActivityStep::Swimlane("Ui".to_string()))
+            .with_step(ActivityStep::Stop);
+
+        assert_eq!(activity.width(), 19);
+        assert_eq!(
+            drawn(&mut activity),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![
+                        '│', ' ', 'U', 'i', ' ', '│', ' ', ' ', ' ', ' ', 'A', 'p', 'i', ' ', ' ',
+                        ' ', ' ', ' ', '│'
+                    ],
+                    vec![
+                        '├', '─', '─', '─', '─', '┼', '─', '─', '─', '─', '─', '─', '─', '─', '─',
+                        '─', '─', '─', '┤'
+                    ],
+                    vec![
+                        '│', ' ', '●', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ',
+                        ' ', ' ', ' ', '│'
+                    ],
+                    vec![
+                        '│', ' ', '└', '─', '─', '┼', '─', '─', '─', '─', '─', '─', '┐', ' ', ' ',
+                        ' ', ' ', ' ', '│'
+                    ],
+                    vec![
+                        '│', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', '▼', ' ', ' ',
+                        ' ', ' ', ' ', '│'
+                    ],
+                    vec![
+                        '│', ' ', ' ', ' ', ' ', '│', ' ', ' ', '━', '━', '━', '━', '━', '━', '━',
+                        '━', '━', ' ', '│'
+                    ],
+                    vec![
+                        '│', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ',
+                        '│', ' ', ' ', '│'
+                    ],
+                    vec![
+                        '│', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', '▼', ' ', ' ', ' ', ' ', ' ',
+                        '│', ' ', ' ', '│'
+                    ],
+                    vec![
+                        '│', ' ', ' ', ' ', ' ', '│', ' ', '╭', '─', '─', '─', '╮', ' ', ' ', ' ',
+                        '│', ' ', ' ', '│'
+                    ],
+                    vec![
+                        '│', ' ', ' ', ' ', ' ', '│', ' ', '│', ' ', 'A', ' ', '│', ' ', ' ', ' ',
+                        '│', ' ', ' ', '│'
+                    ],
+                    vec![
+                        '│', ' ', ' ', ' ', ' ', '│', ' ', '╰', '─', '─', '─', '╯', ' ', ' ', ' ',
+                        '│', ' ', ' ', '│'
+                    ],
+                    vec![
+                        '│', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ',
+                        '│', ' ', ' ', '│'
+                    ],
+                    vec![
+                        '│', ' ', ' ', ' ', ' ', '│', ' ', ' ', '━', '━', '━', '━', '━', '━', '━',
+                        '━', '━', ' ', '│'
+                    ],
+                    vec![
+                        '│', ' ', '┌', '─', '─', '┼', '─', '─', '─', '─', '─', '─', '┘', ' ', ' ',
+                        ' ', ' ', ' ', '│'
+                    ],
+                    vec![
+                        '│', ' ', '▼', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ',
+                        ' ', ' ', ' ', '│'
+                    ],
+                    vec![
+                        '│', ' ', '◉', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ',
+                        ' ', ' ', ' ', '│'
+                    ],
+                ]
+            )
+            .to_string()
+        )
+    }
+}
@@ -0,0 +1,97 @@
+/// Characters used by the border decorator to outline its wrapped element
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct AsciiBorder {
+    pub(crate) top_left: char,
+    pub(crate) top_right: char,
+    pub(crate) bottom_left: char,
+    pub(crate) bottom_right: char,
+    pub(crate) horizontal: char,
+    pub(crate) left: char,
+    pub(crate) right: char,
+    // Draws a line right below the top border, like the lid of a cylinder
+    pub(crate) header_separator: bool,
+}
+
+impl AsciiBorder {
+    pub(crate) fn new(corners: [char; 4], horizontal: char, left: char, right: char) -> Self {
+        let [top_left, top_right, bottom_left, bottom_right] = corners;
+
+        Self {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            horizontal,
+            left,
+            right,
+            header_separator: false,
+        }
+    }
+
+    // ╭──╮
+    // ╰──╯
+    pub(crate) fn rounded() -> Self {
+        Self::new(['╭', '╮', '╰', '╯'], '─', '│', '│')
+    }
+
+    // ┌──┐
+    // └──┘
+    pub(crate) fn square() -> Self {
+        Self::new(['┌', '┐', '└', '┘'], '─', '│', '│')
+    }
+
+    // ┌──╲
+    // └──┘
+    pub(crate) fn folded() -> Self {
+        Self::new(['┌', '╲', '└', '┘'], '─', '│', '│')
+    }
+
+    // ╭──╮
+    // ├──┤
+    // ╰──╯
+    pub(crate) fn cylinder() -> Self {
+        Self {
+            header_separator: true,
+            ..Self::rounded()
+        }
+    }
+
+    // ╭──╮
+    // │  )
+    // ╰──╯
+    pub(crate) fn tube() -> Self {
+        Self::new(['╭', '╮', '╰', '╯'], '─', '│', ')')
+    }
+
+    // ╒══╕
+    // ╘══╛
+    pub(crate) fn double() -> Self {
+        Self::new(['╒', '╕', '╘', '╛'], '═', '│', '│')
+    }
+
+    // ╭──╮
+    // (  )
+    // ╰──╯
+    pub(crate) fn ellipse() -> Self {
+        Self::new(['╭', '╮', '╰', '╯'], '─', '(', ')')
+    }
+
+    // ┏━━┓
+    // ┗━━┛
+    pub(crate) fn heavy() -> Self {
+        Self::new(['┏', '┓', '┗', '┛'], '━', '┃', '┃')
+    }
+
+    // ╭~~╮
+    // (  )
+    // ╰~~╯
+    pub(crate) fn cloud() -> Self {
+        Self::new(['╭', '╮', '╰', '╯'], '~', '(', ')')
+    }
+}
+
+impl Default for AsciiBorder {
+    fn default() -> Self {
+        Self::rounded()
+    }
+}
@@ -1,5 +1,6 @@
 use crate::adapters::models::{
-    ascii_element::AsciiElement, ascii_grid::AsciiGrid, position::Position,
+    ascii_border::AsciiBorder, ascii_element::AsciiElement, ascii_grid::AsciiGrid,
+    position::Position,
 };
 
 pub(crate) struct AsciiBorderDecorator {
@@ -8,6 +9,7 @@ pub(crate) struct AsciiBorderDecorator {
     horizontal_padding: u16,
     vertical_padding: u16,
     position: Position,
+    border: AsciiBorder,
 }
 
 impl AsciiBorderDecorator {
@@ -24,12 +26,42 @@ impl AsciiBorderDecorator {
             horizontal_padding,
             vertical_padding,
             position,
+            border: AsciiBorder::default(),
+        }
+    }
+
+    pub(crate) fn with_border(self, border: AsciiBorder) -> Self {
+        Self { border, ..self }
+    }
+
+    fn border_char_at(&self, x: i32, y: i32) -> Option<char> {
+        let initial_position: Position = self.position();
+
+        let left: i32 = initial_position.x;
+        let right: i32 = initial_position.x + self.width() as i32 - 1;
+        let top: i32 = initial_position.y;
+        let bottom: i32 = initial_position.y + self.height() as i32 - 1;
+
+        let separator: bool = self.border.header_separator && y == top + 1 && y < bottom - 1;
+
+        match (x, y) {
+            (x, y) if x == left && y == top => Some(self.border.top_left),
+            (x, y) if x == left && y == bottom => Some(self.border.bottom_left),
+            (x, y) if x == right && y == top => Some(self.border.top_right),
+            (x, y) if x == right && y == bottom => Some(self.border.bottom_right),
+            (_, y) if y == top || y == bottom => Some(self.border.horizontal),
+            (x, _) if x == left && separator => Some('├'),
+            (x, _) if x == right && separator => Some('┤'),
+            (x, _) if x == left => Some(self.border.left),
+            (x, _) if x == right => Some(self.border.right),
+            (_, _) if separator => Some('─'),
+            _ => None,
         }
     }
 }
 
 impl AsciiElement for AsciiBorderDecorator {
-    fn draw(&mut self, grid: &mut super::ascii_grid::AsciiGrid) {
+    fn draw(&mut self, grid: &mut AsciiGrid) {
         let initial_position: Position = self.position();
 
         let width: u16 = self.width();
@@ -37,35 +69,9 @@ impl AsciiElement for AsciiBorderDecorator {
 
         for y in initial_position.y..(initial_position.y + height as i32) {
             for x in initial_position.x..(initial_position.x + width as i32) {
-                let pos = Position::new(x, y);
-                let mut ch: char = self.fill_char;
-
-                if pos == initial_position {
-                    ch = '╭';
-                } else if pos
-                    == Position::new(initial_position.x, initial_position.y + height as i32 - 1)
-                {
-                    ch = '╰'
-                } else if pos
-                    == Position::new(initial_position.x + width as i32 - 1, initial_position.y)
-                {
-                    ch = '╮';
-                } else if pos
-                    == Position::new(
-                        initial_position.x + width as i32 - 1,
-                        initial_position.y + height as i32 - 1,
-                    )
-                {
-                    ch = '╯';
-                } else if (y == initial_position.y) || (y == initial_position.y + height as i32 - 1)
-                {
-                    ch = '─';
-                } else if (x == initial_position.x) || (x == initial_position.x + width as i32 - 1)
-                {
-                    ch = '│';
-                }
-
-                grid.put_char(ch, &pos);
+                let ch: char = self.border_char_at(x, y).unwrap_or(self.fill_char);
+
+                grid.put_char(ch, &Position::new(x, y));
             }
         }
 
@@ -467,4 +473,56 @@ mod test {
             .to_string()
         )
     }
+
+    #[test]
+    fn short_text_with_square_border() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let text: Box<dyn AsciiElement> =
+            Box::new(AsciiText::new(Position::default(), "Short".to_string()));
+        let mut decorator: AsciiBorderDecorator =
+            AsciiBorderDecorator::new(text, Position::default(), 1, 0, ' ')
+                .with_border(AsciiBorder::square());
+
+        decorator.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['┌', '─', '─', '─', '─', '─', '─', '─', '┐'],
+                    vec!['│', ' ', 'S', 'h', 'o', 'r', 't', ' ', '│'],
+                    vec!['└', '─', '─', '─', '─', '─', '─', '─', '┘'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn short_text_with_cylinder_border() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let text: Box<dyn AsciiElement> =
+            Box::new(AsciiText::new(Position::default(), "Short".to_string()));
+        let mut decorator: AsciiBorderDecorator =
+            AsciiBorderDecorator::new(text, Position::default(), 1, 1, ' ')
+                .with_border(AsciiBorder::cylinder());
+
+        decorator.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['╭', '─', '─', '─', '─', '─', '─', '─', '╮'],
+                    vec!['├', '─', '─', '─', '─', '─', '─', '─', '┤'],
+                    vec!['│', ' ', 'S', 'h', 'o', 'r', 't', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['╰', '─', '─', '─', '─', '─', '─', '─', '╯'],
+                ]
+            )
+            .to_string()
+        )
+    }
 }
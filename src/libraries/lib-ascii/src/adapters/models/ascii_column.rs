@@ -0,0 +1,109 @@
+use crate::adapters::models::{
+    ascii_element::AsciiElement, ascii_grid::AsciiGrid, position::Position,
+};
+
+/// Stacks its elements from top to bottom, each one horizontally centered.
+/// Empty elements are skipped so they do not leave extra spacing behind.
+pub(crate) struct AsciiColumn {
+    elements: Vec<Box<dyn AsciiElement>>,
+    position: Position,
+    spacing: u16,
+}
+
+impl AsciiColumn {
+    pub(crate) fn new(
+        elements: Vec<Box<dyn AsciiElement>>,
+        position: Position,
+        spacing: u16,
+    ) -> Self {
+        Self {
+            elements: elements.into_iter().filter(|e| e.height() > 0).collect(),
+            position,
+            spacing,
+        }
+    }
+
+    pub(crate) fn add(&mut self, element: Box<dyn AsciiElement>) {
+        if element.height() > 0 {
+            self.elements.push(element);
+        }
+    }
+}
+
+impl AsciiElement for AsciiColumn {
+    fn draw(&mut self, grid: &mut AsciiGrid) {
+        let initial_position: Position = self.position();
+        let width: u16 = self.width();
+        let mut y: i32 = initial_position.y;
+
+        for element in self.elements.iter_mut() {
+            let offset: i32 = ((width - element.width()) / 2) as i32;
+
+            element.r#move(Position::new(initial_position.x + offset, y));
+            element.draw(grid);
+
+            y += (element.height() + self.spacing) as i32;
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position.clone()
+    }
+
+    fn width(&self) -> u16 {
+        self.elements.iter().map(|e| e.width()).max().unwrap_or(0)
+    }
+
+    fn height(&self) -> u16 {
+        let spacing: u16 = self.spacing * (self.elements.len().max(1) - 1) as u16;
+
+        self.elements.iter().map(|e| e.height()).sum::<u16>() + spacing
+    }
+
+    fn r#move(&mut self, new_position: Position) {
+        self.position = new_position;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use crate::adapters::models::ascii_text::AsciiText;
+
+    use super::*;
+
+    #[test]
+    fn elements_stacked_and_centered() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut column: AsciiColumn = AsciiColumn::new(
+            vec![
+                Box::new(AsciiText::new(Position::default(), "Title".to_string())),
+                Box::new(AsciiText::new(Position::default(), "".to_string())),
+                Box::new(AsciiText::new(
+                    Position::default(),
+                    "Body\nof text".to_string(),
+                )),
+            ],
+            Position::default(),
+            1,
+        );
+
+        column.draw(&mut grid);
+
+        assert_eq!(column.height(), 4);
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![' ', 'T', 'i', 't', 'l', 'e'],
+                    vec![],
+                    vec!['B', 'o', 'd', 'y'],
+                    vec!['o', 'f', ' ', 't', 'e', 'x', 't'],
+                ]
+            )
+            .to_string()
+        )
+    }
+}
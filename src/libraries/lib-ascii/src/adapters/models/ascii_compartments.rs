@@ -0,0 +1,174 @@
+use crate::adapters::models::{
+    ascii_border::AsciiBorder, ascii_element::AsciiElement, ascii_grid::AsciiGrid,
+    position::Position,
+};
+
+/// A box split into compartments stacked from top to bottom, e.g., the name,
+/// fields and methods of a class, with a separator line between each one.
+/// The first compartment is a header and is centered, the others are aligned to the left.
+pub(crate) struct AsciiCompartments {
+    compartments: Vec<Box<dyn AsciiElement>>,
+    position: Position,
+    horizontal_padding: u16,
+    border: AsciiBorder,
+}
+
+impl AsciiCompartments {
+    pub(crate) fn new(position: Position, horizontal_padding: u16) -> Self {
+        Self {
+            compartments: Vec::new(),
+            position,
+            horizontal_padding,
+            border: AsciiBorder::default(),
+        }
+    }
+
+    pub(crate) fn with_border(self, border: AsciiBorder) -> Self {
+        Self { border, ..self }
+    }
+
+    pub(crate) fn with_compartment(mut self, compartment: Box<dyn AsciiElement>) -> Self {
+        self.compartments.push(compartment);
+        self
+    }
+}
+
+impl AsciiElement for AsciiCompartments {
+    fn draw(&mut self, grid: &mut AsciiGrid) {
+        let Position { x: left, y: top } = self.position();
+        let width: u16 = self.width();
+        let right: i32 = left + width as i32 - 1;
+        let bottom: i32 = top + self.height() as i32 - 1;
+        let border: &AsciiBorder = &self.border;
+        let rule = |grid: &mut AsciiGrid, y: i32, ends: [char; 2], horizontal: char| {
+            grid.put_char(ends[0], &Position::new(left, y));
+            for x in (left + 1)..right {
+                grid.put_char(horizontal, &Position::new(x, y));
+            }
+            grid.put_char(ends[1], &Position::new(right, y));
+        };
+
+        rule(
+            grid,
+            top,
+            [border.top_left, border.top_right],
+            border.horizontal,
+        );
+        for y in (top + 1)..bottom {
+            grid.put_char(border.left, &Position::new(left, y));
+            for x in (left + 1)..right {
+                grid.put_char(' ', &Position::new(x, y));
+            }
+            grid.put_char(border.right, &Position::new(right, y));
+        }
+        rule(
+            grid,
+            bottom,
+            [border.bottom_left, border.bottom_right],
+            border.horizontal,
+        );
+
+        let mut y: i32 = top + 1;
+        for (index, compartment) in self.compartments.iter_mut().enumerate() {
+            if index > 0 {
+                rule(grid, y, separator_ends(border), '─');
+                y += 1;
+            }
+
+            let offset: u16 = match index {
+                0 => (width - 2 - compartment.width()) / 2,
+                _ => self.horizontal_padding,
+            };
+
+            compartment.r#move(Position::new(left + 1 + offset as i32, y));
+            compartment.draw(grid);
+
+            y += compartment.height() as i32;
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position.clone()
+    }
+
+    fn width(&self) -> u16 {
+        let content: u16 = self
+            .compartments
+            .iter()
+            .map(|c| c.width())
+            .max()
+            .unwrap_or(0);
+
+        content + self.horizontal_padding * 2 + 2
+    }
+
+    fn height(&self) -> u16 {
+        let separators: u16 = self.compartments.len().max(1) as u16 - 1;
+
+        self.compartments.iter().map(|c| c.height()).sum::<u16>() + separators + 2
+    }
+
+    fn r#move(&mut self, new_position: Position) {
+        self.position = new_position;
+    }
+}
+
+// Separators meet the sides of the border, e.g., "┠──┨" between heavy sides;
+// sides a line cannot meet, such as those of a cloud, are left as they are
+fn separator_ends(border: &AsciiBorder) -> [char; 2] {
+    let end = |side: char, tees: [char; 3]| match side {
+        '│' => tees[0],
+        '┃' => tees[1],
+        '║' => tees[2],
+        _ => side,
+    };
+
+    [
+        end(border.left, ['├', '┠', '╟']),
+        end(border.right, ['┤', '┨', '╢']),
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use crate::adapters::models::ascii_text::AsciiText;
+
+    use super::*;
+
+    fn text(content: &str) -> Box<dyn AsciiElement> {
+        Box::new(AsciiText::new(Position::default(), content.to_string()))
+    }
+
+    #[test]
+    fn compartments_split_by_separator_lines() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut compartments: AsciiCompartments = AsciiCompartments::new(Position::default(), 1)
+            .with_border(AsciiBorder::square())
+            .with_compartment(text("User"))
+            .with_compartment(text("-id"))
+            .with_compartment(text("+name()"));
+
+        compartments.draw(&mut grid);
+
+        assert_eq!(compartments.width(), 11);
+        assert_eq!(compartments.height(), 7);
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['┌', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┐'],
+                    vec!['│', ' ', ' ', 'U', 's', 'e', 'r', ' ', ' ', ' ', '│'],
+                    vec!['├', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┤'],
+                    vec!['│', ' ', '-', 'i', 'd', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['├', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┤'],
+                    vec!['│', ' ', '+', 'n', 'a', 'm', 'e', '(', ')', ' ', '│'],
+                    vec!['└', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┘'],
+                ]
+            )
+            .to_string()
+        )
+    }
+}
@@ -0,0 +1,247 @@
+use lib_core::domain::entities::data::{DataEntry, DataValue};
+
+use crate::adapters::models::{
+    ascii_element::AsciiElement, ascii_grid::AsciiGrid, ascii_text::AsciiText, position::Position,
+};
+
+/// Draws JSON or YAML data as a table of keys and values, with the tables of nested objects
+/// and arrays drawn inside the cells of their key. The items of an array have no key column.
+/// Rows on a highlighted path are drawn with heavy vertical lines, e.g., "┃ city ┃ Paris ┃".
+pub(crate) struct AsciiData {
+    root: DataValue,
+    position: Position,
+}
+
+impl AsciiData {
+    pub(crate) fn new(position: Position, root: DataValue) -> Self {
+        Self { root, position }
+    }
+}
+
+impl AsciiElement for AsciiData {
+    fn draw(&mut self, grid: &mut AsciiGrid) {
+        let Position { x, y } = self.position();
+
+        for (index, line) in lines(&self.root).into_iter().enumerate() {
+            AsciiText::new(Position::new(x, y + index as i32), line).draw(grid);
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position.clone()
+    }
+
+    fn width(&self) -> u16 {
+        lines(&self.root)
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0) as u16
+    }
+
+    fn height(&self) -> u16 {
+        lines(&self.root).len() as u16
+    }
+
+    fn r#move(&mut self, new_position: Position) {
+        self.position = new_position;
+    }
+}
+
+// Every line of a value is padded to the same width
+fn lines(value: &DataValue) -> Vec<String> {
+    let text: String = match value {
+        DataValue::Null => "null".to_string(),
+        DataValue::Boolean(value) => value.to_string(),
+        DataValue::Number(value) | DataValue::Text(value) => value.clone(),
+        DataValue::Array(items) if items.is_empty() => "[]".to_string(),
+        DataValue::Object(entries) if entries.is_empty() => "{}".to_string(),
+        DataValue::Array(items) => return table(items, false),
+        DataValue::Object(entries) => return table(entries, true),
+    };
+
+    padded(text.lines().map(str::to_string).collect())
+}
+
+fn table(entries: &[DataEntry], keyed: bool) -> Vec<String> {
+    let values: Vec<Vec<String>> = entries.iter().map(|entry| lines(&entry.value)).collect();
+    let key_width: usize = match keyed {
+        true => entries
+            .iter()
+            .map(|e| e.key.chars().count())
+            .max()
+            .unwrap_or(0),
+        false => 0,
+    };
+    let value_width: usize = values
+        .iter()
+        .filter_map(|value| value.first())
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    // e.g., "├──────┼───────┤"
+    let rule = |ends: [char; 3]| match keyed {
+        true => format!(
+            "{}{}{}{}{}",
+            ends[0],
+            "─".repeat(key_width + 2),
+            ends[1],
+            "─".repeat(value_width + 2),
+            ends[2]
+        ),
+        false => format!("{}{}{}", ends[0], "─".repeat(value_width + 2), ends[2]),
+    };
+
+    let mut lines: Vec<String> = vec![rule(['┌', '┬', '┐'])];
+
+    for (index, (entry, value)) in entries.iter().zip(values).enumerate() {
+        if index > 0 {
+            lines.push(rule(['├', '┼', '┤']));
+        }
+
+        let border: char = match entry.highlighted {
+            true => '┃',
+            false => '│',
+        };
+
+        for (row, value_line) in value.iter().enumerate() {
+            let value_cell: String = format!("{:<1$}", value_line, value_width);
+
+            lines.push(match keyed {
+                true => {
+                    let key: &str = if row == 0 { &entry.key } else { "" };
+
+                    format!(
+                        "{0} {1:<2$} {0} {3} {0}",
+                        border, key, key_width, value_cell
+                    )
+                }
+                false => format!("{0} {1} {0}", border, value_cell),
+            });
+        }
+    }
+
+    lines.push(rule(['└', '┴', '┘']));
+    lines
+}
+
+fn padded(lines: Vec<String>) -> Vec<String> {
+    let width: usize = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+
+    lines
+        .into_iter()
+        .map(|line| format!("{:<1$}", line, width))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn text(value: &str) -> DataValue {
+        DataValue::Text(value.to_string())
+    }
+
+    fn entry(key: &str, value: DataValue) -> DataEntry {
+        DataEntry::new(key.to_string(), value)
+    }
+
+    #[test]
+    fn nested_values_are_drawn_inside_their_cell() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut data: AsciiData = AsciiData::new(
+            Position::default(),
+            DataValue::Object(vec![
+                entry("id", DataValue::Number("7".to_string())),
+                entry(
+                    "tags",
+                    DataValue::Array(vec![entry("0", text("a")), entry("1", text("b"))]),
+                ),
+            ]),
+        );
+
+        data.draw(&mut grid);
+
+        assert_eq!(data.width(), 16);
+        assert_eq!(data.height(), 9);
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![
+                        '┌', '─', '─', '─', '─', '─', '─', '┬', '─', '─', '─', '─', '─', '─', '─',
+                        '┐'
+                    ],
+                    vec![
+                        '│', ' ', 'i', 'd', ' ', ' ', ' ', '│', ' ', '7', ' ', ' ', ' ', ' ', ' ',
+                        '│'
+                    ],
+                    vec![
+                        '├', '─', '─', '─', '─', '─', '─', '┼', '─', '─', '─', '─', '─', '─', '─',
+                        '┤'
+                    ],
+                    vec![
+                        '│', ' ', 't', 'a', 'g', 's', ' ', '│', ' ', '┌', '─', '─', '─', '┐', ' ',
+                        '│'
+                    ],
+                    vec![
+                        '│', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', '│', ' ', 'a', ' ', '│', ' ',
+                        '│'
+                    ],
+                    vec![
+                        '│', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', '├', '─', '─', '─', '┤', ' ',
+                        '│'
+                    ],
+                    vec![
+                        '│', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', '│', ' ', 'b', ' ', '│', ' ',
+                        '│'
+                    ],
+                    vec![
+                        '│', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', '└', '─', '─', '─', '┘', ' ',
+                        '│'
+                    ],
+                    vec![
+                        '└', '─', '─', '─', '─', '─', '─', '┴', '─', '─', '─', '─', '─', '─', '─',
+                        '┘'
+                    ],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn highlighted_rows_have_heavy_borders() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut data: AsciiData = AsciiData::new(
+            Position::default(),
+            DataValue::Object(vec![
+                entry("a", DataValue::Null),
+                DataEntry {
+                    highlighted: true,
+                    ..entry("b", DataValue::Boolean(true))
+                },
+            ]),
+        );
+
+        data.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['┌', '─', '─', '─', '┬', '─', '─', '─', '─', '─', '─', '┐'],
+                    vec!['│', ' ', 'a', ' ', '│', ' ', 'n', 'u', 'l', 'l', ' ', '│'],
+                    vec!['├', '─', '─', '─', '┼', '─', '─', '─', '─', '─', '─', '┤'],
+                    vec!['┃', ' ', 'b', ' ', '┃', ' ', 't', 'r', 'u', 'e', ' ', '┃'],
+                    vec!['└', '─', '─', '─', '┴', '─', '─', '─', '─', '─', '─', '┘'],
+                ]
+            )
+            .to_string()
+        )
+    }
+}
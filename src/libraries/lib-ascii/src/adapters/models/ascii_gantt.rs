@@ -0,0 +1,243 @@
+use lib_core::domain::entities::gantt::{Date, Gantt, MAX_DAYS, ScheduledTask};
+
+use crate::adapters::models::{
+    ascii_element::AsciiElement, ascii_grid::AsciiGrid, ascii_text::AsciiText, position::Position,
+};
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Draws a gantt chart as a bar per task below a header of dates, three columns a day.
+/// Milestones are drawn as a diamond and closed days are shaded, e.g.,
+///
+/// ```text
+///         Jan 2026
+///          1  2  3  4  5  6  7  8
+/// Design  ██████░░░░░░███░░░
+/// Build         ░░░░░░   ░░░██████
+/// ```
+///
+/// Days are numbered from 1 when the project has no start date, and only the first
+/// `MAX_DAYS` days are drawn. A plan which cannot be scheduled is not drawn, the parser
+/// reports the tasks starting from each other or from unknown ones.
+pub(crate) struct AsciiGantt {
+    gantt: Gantt,
+    position: Position,
+}
+
+impl AsciiGantt {
+    pub(crate) fn new(position: Position, gantt: Gantt) -> Self {
+        Self { gantt, position }
+    }
+
+    fn lines(&self) -> Vec<String> {
+        let tasks: Vec<ScheduledTask> = match self.gantt.schedule() {
+            Ok(tasks) => tasks,
+            Err(_) => return Vec::new(),
+        };
+        let (Some(first), Some(last)) = (
+            tasks.iter().map(|t| t.start).chain(self.gantt.start).min(),
+            tasks.iter().map(|t| t.end).max(),
+        ) else {
+            return Vec::new();
+        };
+        let days: Vec<Date> = (0..=first.days_until(&last).clamp(0, MAX_DAYS as i64 - 1) as u32)
+            .map(|day| first.add_days(day))
+            .collect();
+        let name_width: usize = tasks
+            .iter()
+            .map(|t| t.name.chars().count() + 1)
+            .max()
+            .unwrap_or(0);
+        let margin: String = " ".repeat(name_width);
+
+        let mut lines: Vec<String> = Vec::new();
+
+        // e.g., "Jan 2026" above the first day of each month, and above the first day shown
+        // when there is room for it before the next month
+        if self.gantt.start.is_some() {
+            let labels: Vec<(usize, String)> = days
+                .iter()
+                .enumerate()
+                .filter(|(index, day)| *index == 0 || day.day() == 1)
+                .map(|(index, day)| {
+                    let month: &str = MONTHS[day.month() as usize - 1];
+
+                    (index * 3, format!("{} {}", month, day.year()))
+                })
+                .collect();
+            let mut months: String = String::new();
+
+            for (index, (x, label)) in labels.iter().enumerate() {
+                let room: usize = labels
+                    .get(index + 1)
+                    .map_or(usize::MAX, |(next, _)| next - x);
+
+                if label.len() < room {
+                    months = format!("{:<2$}{}", months, label, x);
+                }
+            }
+            lines.push(format!("{}{}", margin, months));
+        }
+
+        lines.push(format!(
+            "{}{}",
+            margin,
+            days.iter()
+                .enumerate()
+                .map(|(index, day)| match self.gantt.start {
+                    Some(_) => format!("{:>2} ", day.day()),
+                    None => format!("{:>2} ", index + 1),
+                })
+                .collect::<String>()
+        ));
+
+        for task in tasks.iter() {
+            let cells: String = days
+                .iter()
+                .map(
+                    |day| match (task.start <= *day && *day <= task.end, task.milestone) {
+                        (true, true) => " ◆ ",
+                        _ if self.gantt.is_closed(day) => "░░░",
+                        (true, false) => "███",
+                        (false, _) => "   ",
+                    },
+                )
+                .collect();
+
+            lines.push(format!("{:<2$}{}", task.name, cells, name_width));
+        }
+
+        lines
+    }
+}
+
+impl AsciiElement for AsciiGantt {
+    fn draw(&mut self, grid: &mut AsciiGrid) {
+        let Position { x, y } = self.position();
+
+        for (index, line) in self.lines().into_iter().enumerate() {
+            AsciiText::new(Position::new(x, y + index as i32), line).draw(grid);
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position.clone()
+    }
+
+    fn width(&self) -> u16 {
+        self.lines()
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0) as u16
+    }
+
+    fn height(&self) -> u16 {
+        self.lines().len() as u16
+    }
+
+    fn r#move(&mut self, new_position: Position) {
+        self.position = new_position;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use lib_core::domain::entities::gantt::{
+        ClosedDays, GanttTask, TaskAnchor, TaskStart, Weekday,
+    };
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn tasks_are_drawn_below_their_dates() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        // 2026-01-30 is a Friday
+        let mut gantt: AsciiGantt = AsciiGantt::new(
+            Position::default(),
+            Gantt {
+                closed_days: vec![ClosedDays::Weekday(Weekday::Saturday)],
+                ..Gantt::new(
+                    Some(Date::new(2026, 1, 30)),
+                    vec![
+                        GanttTask::new("A".to_string(), "A".to_string(), 2),
+                        GanttTask {
+                            milestone: true,
+                            start: TaskStart::After("A".to_string(), TaskAnchor::End, 0),
+                            ..GanttTask::new("M".to_string(), "M".to_string(), 0)
+                        },
+                    ],
+                )
+            },
+        );
+
+        gantt.draw(&mut grid);
+
+        assert_eq!(gantt.height(), 4);
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![
+                        ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', 'F', 'e', 'b', ' ', '2', '0', '2',
+                        '6'
+                    ],
+                    vec![
+                        ' ', ' ', '3', '0', ' ', '3', '1', ' ', ' ', '1', ' ', ' ', '2', ' '
+                    ],
+                    vec![
+                        'A', ' ', '█', '█', '█', '░', '░', '░', '█', '█', '█', ' ', ' ', ' '
+                    ],
+                    vec![
+                        'M', ' ', ' ', ' ', ' ', '░', '░', '░', ' ', ' ', ' ', ' ', '◆', ' '
+                    ],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn plans_which_cannot_be_scheduled_are_not_drawn() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut gantt: AsciiGantt = AsciiGantt::new(
+            Position::default(),
+            Gantt::new(
+                None,
+                vec![GanttTask {
+                    start: TaskStart::After("A".to_string(), TaskAnchor::Start, 0),
+                    ..GanttTask::new("A".to_string(), "A".to_string(), 1)
+                }],
+            ),
+        );
+
+        gantt.draw(&mut grid);
+
+        assert_eq!(gantt.height(), 0);
+        assert_eq!(grid.to_string(), "")
+    }
+
+    #[test]
+    fn days_past_the_last_one_drawn_are_cut_off() {
+        let gantt: AsciiGantt = AsciiGantt::new(
+            Position::default(),
+            Gantt::new(
+                None,
+                vec![
+                    GanttTask::new("A".to_string(), "A".to_string(), 1),
+                    GanttTask {
+                        start: TaskStart::On(Date::default().add_days(1_000_000)),
+                        ..GanttTask::new("B".to_string(), "B".to_string(), 1)
+                    },
+                ],
+            ),
+        );
+
+        // Below the day numbers and the first task
+        assert_eq!(gantt.lines()[2].chars().count() as u32, 2 + MAX_DAYS * 3);
+    }
+}
@@ -0,0 +1,1626 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+use lib_core::domain::entities::diagram::{
+    Edge, EdgeDirection, EdgeStyle, LayoutDirection, LineStyle, Port,
+};
+
+use crate::adapters::models::{
+    ascii_element::AsciiElement, ascii_grid::AsciiGrid, ascii_text::AsciiText, position::Position,
+};
+
+// Room between two ranks: a line, a bend and an arrow head
+const RANK_SPACING: i32 = 3;
+// Room between two nodes of the same rank: a line and an arrow head
+const NODE_SPACING: i32 = 2;
+const PORT_MARKER: char = '□';
+const LOLLIPOP: char = '○';
+
+/// Lays its nodes out in ranks following the edges between them, then draws the edges.
+/// Ranks go from top to bottom, or from left to right, an edge putting its target in the
+/// rank after its source unless its direction hint says otherwise.
+/// Edges are drawn first so the nodes hide the lines running behind them.
+/// An edge from a node to itself loops around the corner after the node on both axes.
+/// Ports no edge reaches are marked on the far side of their node along the main axis,
+/// and every port is named next to its marker.
+pub(crate) struct AsciiGraph {
+    // Id, Element
+    nodes: Vec<(String, Box<dyn AsciiElement>)>,
+    edges: Vec<Edge>,
+    // Node id, Port
+    ports: Vec<(String, Port)>,
+    direction: LayoutDirection,
+    position: Position,
+}
+
+impl AsciiGraph {
+    pub(crate) fn new(direction: LayoutDirection, position: Position) -> Self {
+        Self {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            ports: Vec::new(),
+            direction,
+            position,
+        }
+    }
+
+    pub(crate) fn with_node(mut self, id: String, element: Box<dyn AsciiElement>) -> Self {
+        self.nodes.push((id, element));
+        self
+    }
+
+    pub(crate) fn with_edge(mut self, edge: Edge) -> Self {
+        self.edges.push(edge);
+        self
+    }
+
+    pub(crate) fn with_port(mut self, id: String, port: Port) -> Self {
+        self.ports.push((id, port));
+        self
+    }
+
+    // Edges between two distinct nodes of the graph, with the indexes of their ends
+    fn links(&self) -> Vec<(usize, usize, &Edge)> {
+        let indexes: HashMap<&str, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, (id, _))| (id.as_str(), i))
+            .collect();
+
+        self.edges
+            .iter()
+            .filter_map(|edge| {
+                let from: usize = *indexes.get(edge.from_id.as_str())?;
+                let to: usize = *indexes.get(edge.to_id.as_str())?;
+
+                (from != to).then_some((from, to, edge))
+            })
+            .collect()
+    }
+
+    // Edges from a node of the graph to itself, with the index of that node
+    fn loops(&self) -> Vec<(usize, &Edge)> {
+        self.edges
+            .iter()
+            .filter(|edge| edge.from_id == edge.to_id)
+            .filter_map(|edge| {
+                let node: usize = self.nodes.iter().position(|(id, _)| *id == edge.from_id)?;
+
+                Some((node, edge))
+            })
+            .collect()
+    }
+
+    fn placement(&self, edge: &Edge) -> Placement {
+        match (&self.direction, &edge.direction) {
+            (_, None) => Placement::After,
+            (LayoutDirection::TopToBottom, Some(EdgeDirection::Down))
+            | (LayoutDirection::LeftToRight, Some(EdgeDirection::Right)) => Placement::After,
+            (LayoutDirection::TopToBottom, Some(EdgeDirection::Up))
+            | (LayoutDirection::LeftToRight, Some(EdgeDirection::Left)) => Placement::Before,
+            (LayoutDirection::TopToBottom, Some(EdgeDirection::Right))
+            | (LayoutDirection::LeftToRight, Some(EdgeDirection::Down)) => Placement::Beside(true),
+            (LayoutDirection::TopToBottom, Some(EdgeDirection::Left))
+            | (LayoutDirection::LeftToRight, Some(EdgeDirection::Up)) => Placement::Beside(false),
+        }
+    }
+
+    // Sizes along the ranks (main axis) and within a rank (cross axis)
+    fn size_of(&self, node: usize) -> Point {
+        let element: &dyn AsciiElement = self.nodes[node].1.as_ref();
+
+        self.point(element.width() as i32, element.height() as i32)
+    }
+
+    fn point(&self, x: i32, y: i32) -> Point {
+        match self.direction {
+            LayoutDirection::TopToBottom => Point::new(y, x),
+            LayoutDirection::LeftToRight => Point::new(x, y),
+        }
+    }
+
+    fn position_of(&self, point: Point) -> Position {
+        let (x, y) = match self.direction {
+            LayoutDirection::TopToBottom => (point.cross, point.main),
+            LayoutDirection::LeftToRight => (point.main, point.cross),
+        };
+
+        Position::new(self.position.x + x, self.position.y + y)
+    }
+
+    // Labels are written along the first segment of an edge when it is horizontal,
+    // which needs room for the label, the line start and the arrow head
+    fn label_room(&self, edge: &Edge, along_main_axis: bool) -> i32 {
+        let horizontal: bool = along_main_axis == (self.direction == LayoutDirection::LeftToRight);
+
+        match (&edge.label, horizontal, edge.hidden) {
+            (Some(label), true, false) => AsciiText::new(Position::default(), label.clone())
+                .width()
+                .saturating_add(3) as i32,
+            _ => 0,
+        }
+    }
+
+    fn layout(&self) -> Layout {
+        let links: Vec<(usize, usize, &Edge)> = self.links();
+        let placements: Vec<(usize, usize, Placement)> = links
+            .iter()
+            .map(|(from, to, edge)| (*from, *to, self.placement(edge)))
+            .collect();
+        let ranks: Vec<usize> = rank(self.nodes.len(), &placements);
+        let orders: Vec<usize> = order(self.nodes.len(), &placements);
+        let looped: Vec<usize> = self.loops().iter().map(|(node, _)| *node).collect();
+
+        let rank_count: usize = ranks.iter().max().map_or(0, |r| r + 1);
+        let mut members: Vec<Vec<usize>> = vec![Vec::new(); rank_count];
+        (0..self.nodes.len()).for_each(|node| members[ranks[node]].push(node));
+        members
+            .iter_mut()
+            .for_each(|rank| rank.sort_by_key(|&node| (orders[node], node)));
+
+        let mut gaps: Vec<i32> = vec![RANK_SPACING; rank_count];
+        for (from, to, edge) in links.iter() {
+            let gap: usize = match ranks[*from].cmp(&ranks[*to]) {
+                Ordering::Less => ranks[*from],
+                Ordering::Greater => ranks[*from] - 1,
+                Ordering::Equal => continue,
+            };
+
+            gaps[gap] = gaps[gap].max(self.label_room(edge, true));
+        }
+        // Loops need a line more before the bends of the edges leaving their rank,
+        // and the names of unconnected ports need room after their node
+        looped
+            .iter()
+            .for_each(|node| gaps[ranks[*node]] = gaps[ranks[*node]].max(RANK_SPACING + 1));
+        let port_rooms: Vec<i32> = (0..self.nodes.len()).map(|n| self.port_room(n)).collect();
+        for (node, room) in port_rooms.iter().enumerate() {
+            gaps[ranks[node]] = gaps[ranks[node]].max(RANK_SPACING + room);
+        }
+
+        let band_sizes: Vec<i32> = members
+            .iter()
+            .map(|rank| {
+                rank.iter()
+                    .map(|&n| self.size_of(n).main)
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let mut band_starts: Vec<i32> = Vec::new();
+        let mut main: i32 = 0;
+        for (band_size, gap) in band_sizes.iter().zip(gaps.iter()) {
+            band_starts.push(main);
+            main += band_size + gap;
+        }
+        let main_size: i32 = band_starts
+            .last()
+            .zip(band_sizes.last())
+            .map_or(0, |(s, l)| s + l);
+        let loop_room: i32 = match looped.iter().any(|node| ranks[*node] + 1 == rank_count) {
+            true => 2,
+            false => 0,
+        };
+        let port_room: i32 = (0..self.nodes.len())
+            .filter(|node| ranks[*node] + 1 == rank_count)
+            .map(|node| port_rooms[node])
+            .max()
+            .unwrap_or(0);
+        let main_size: i32 = main_size + loop_room.max(port_room);
+
+        // Nodes of a rank are spread along the cross axis, wider apart to fit edge labels
+        let offsets: Vec<Vec<i32>> = members
+            .iter()
+            .map(|rank| {
+                let mut cross: i32 = 0;
+
+                rank.iter()
+                    .enumerate()
+                    .map(|(i, &node)| {
+                        if let Some(&previous) = i.checked_sub(1).map(|p| &rank[p]) {
+                            let room: i32 = links
+                                .iter()
+                                .filter(|(from, to, _)| {
+                                    (*from, *to) == (previous, node)
+                                        || (*from, *to) == (node, previous)
+                                })
+                                .map(|(_, _, edge)| self.label_room(edge, false))
+                                .max()
+                                .unwrap_or(0);
+
+                            let room: i32 = match looped.contains(&previous) {
+                                true => room.max(NODE_SPACING + 1),
+                                false => room,
+                            };
+
+                            cross += self.size_of(previous).cross + NODE_SPACING.max(room);
+                        }
+
+                        cross
+                    })
+                    .collect()
+            })
+            .collect();
+        let rank_cross_sizes: Vec<i32> = members
+            .iter()
+            .zip(offsets.iter())
+            .map(|(rank, offsets)| {
+                rank.last()
+                    .zip(offsets.last())
+                    .map_or(0, |(&node, offset)| {
+                        let room: i32 = match looped.contains(&node) {
+                            true => 2,
+                            false => 0,
+                        };
+
+                        offset + self.size_of(node).cross + room
+                    })
+            })
+            .collect();
+        let cross_size: i32 = rank_cross_sizes.iter().copied().max().unwrap_or(0);
+
+        let mut starts: Vec<Point> = vec![Point::default(); self.nodes.len()];
+        for (r, rank) in members.iter().enumerate() {
+            let rank_start: i32 = (cross_size - rank_cross_sizes[r]) / 2;
+
+            for (&node, offset) in rank.iter().zip(offsets[r].iter()) {
+                starts[node] = Point::new(
+                    band_starts[r] + (band_sizes[r] - self.size_of(node).main) / 2,
+                    rank_start + offset,
+                );
+            }
+        }
+
+        // Edges between nodes of a rank with others in between go around those nodes,
+        // past the far side of the rank, rather than behind them
+        let around: Vec<(usize, usize)> = links
+            .iter()
+            .filter(|(from, to, _)| {
+                let (low, high) = match starts[*from].cross < starts[*to].cross {
+                    true => (starts[*from].cross, starts[*to].cross),
+                    false => (starts[*to].cross, starts[*from].cross),
+                };
+
+                ranks[*from] == ranks[*to]
+                    && (0..self.nodes.len()).any(|node| {
+                        ranks[node] == ranks[*from]
+                            && starts[node].cross > low
+                            && starts[node].cross < high
+                    })
+            })
+            .map(|(from, to, _)| (*from, *to))
+            .collect();
+        let band_ends: Vec<i32> = band_starts
+            .iter()
+            .zip(band_sizes.iter())
+            .map(|(start, size)| start + size)
+            .collect();
+        // The last rank has no gap after it for the edges going around its nodes
+        let main_size: i32 = match around
+            .iter()
+            .any(|(from, _)| ranks[*from] + 1 == rank_count)
+        {
+            true => main_size.max(band_ends[rank_count - 1] + 2),
+            false => main_size,
+        };
+
+        let mut layout: Layout = Layout {
+            ranks,
+            starts,
+            band_ends,
+            gaps,
+            detours: Vec::new(),
+            around,
+            size: Point::new(main_size, cross_size),
+        };
+
+        // Edges going past nodes of the ranks in between take a lane of their own,
+        // beyond the far side of the ranks, rather than going behind those nodes
+        let detours: Vec<(usize, usize)> = links
+            .iter()
+            .filter(|(from, to, edge)| {
+                let ranks: &[usize] = &layout.ranks;
+                let (low, high) = match ranks[*from] < ranks[*to] {
+                    true => (ranks[*from], ranks[*to]),
+                    false => (ranks[*to], ranks[*from]),
+                };
+                let cross: i32 = self.anchor(&layout, *from, *to, edge, false).cross;
+
+                (0..self.nodes.len()).any(|node| {
+                    let (start, span) = (layout.starts[node].cross, self.size_of(node).cross);
+
+                    ranks[node] > low
+                        && ranks[node] < high
+                        && (start..start + span).contains(&cross)
+                })
+            })
+            .map(|(from, to, _)| (*from, *to))
+            .collect();
+        layout.size.cross += 2 * detours.len() as i32;
+        layout.detours = detours;
+
+        layout
+    }
+
+    // Orthogonal path from the side of the source facing the target to the facing side
+    // of the target, bending in the gap next to the source rank. A detour goes through
+    // its lane in between, bending again in the gap next to the target rank.
+    fn route(&self, layout: &Layout, from: usize, to: usize, edge: &Edge) -> Vec<Point> {
+        let (source, target) = (layout.starts[from], layout.starts[to]);
+        let (source_size, target_size) = (self.size_of(from), self.size_of(to));
+        let source_center: Point = self.anchor(layout, from, to, edge, true);
+        let target_center: Point = self.anchor(layout, from, to, edge, false);
+        let (source_rank, target_rank) = (layout.ranks[from], layout.ranks[to]);
+        let lane: Option<i32> = layout
+            .detours
+            .iter()
+            .position(|d| *d == (from, to))
+            .map(|detour| layout.size.cross - 2 * (layout.detours.len() - detour) as i32 + 1);
+
+        match source_rank.cmp(&target_rank) {
+            Ordering::Less => {
+                let bend: i32 = layout.band_ends[source_rank] + layout.gaps[source_rank] - 2;
+                let start: Point = Point::new(source.main + source_size.main, source_center.cross);
+                let end: Point = Point::new(target.main - 1, target_center.cross);
+                let last_bend: i32 =
+                    layout.band_ends[target_rank - 1] + layout.gaps[target_rank - 1] - 2;
+
+                bent(start, bend, end, lane.map(|lane| (lane, last_bend)))
+            }
+            Ordering::Greater => {
+                let bend: i32 = layout.band_ends[source_rank - 1] + 1;
+                let start: Point = Point::new(source.main - 1, source_center.cross);
+                let end: Point = Point::new(target.main + target_size.main, target_center.cross);
+                let last_bend: i32 = layout.band_ends[target_rank] + 1;
+
+                bent(start, bend, end, lane.map(|lane| (lane, last_bend)))
+            }
+            Ordering::Equal if layout.around.contains(&(from, to)) => {
+                let start: Point = Point::new(source.main + source_size.main, source_center.cross);
+                let end: Point = Point::new(target.main + target_size.main, target_center.cross);
+
+                bent(start, layout.band_ends[source_rank] + 1, end, None)
+            }
+            Ordering::Equal => {
+                let (start, end) = match target.cross > source.cross {
+                    true => (
+                        Point::new(source_center.main, source.cross + source_size.cross),
+                        Point::new(target_center.main, target.cross - 1),
+                    ),
+                    false => (
+                        Point::new(source_center.main, source.cross - 1),
+                        Point::new(target_center.main, target.cross + target_size.cross),
+                    ),
+                };
+                let bend: i32 = (start.cross + end.cross) / 2;
+
+                vec![
+                    start,
+                    Point::new(start.main, bend),
+                    Point::new(end.main, bend),
+                    end,
+                ]
+            }
+        }
+    }
+
+    // Side of a node an edge leaves it by, or reaches it by
+    fn side(&self, layout: &Layout, from: usize, to: usize, at_source: bool) -> Side {
+        let (source, target) = match layout.ranks[from].cmp(&layout.ranks[to]) {
+            Ordering::Less => (Side::After, Side::Before),
+            Ordering::Greater => (Side::Before, Side::After),
+            Ordering::Equal if layout.around.contains(&(from, to)) => (Side::After, Side::After),
+            Ordering::Equal if layout.starts[to].cross > layout.starts[from].cross => {
+                (Side::CrossAfter, Side::CrossBefore)
+            }
+            Ordering::Equal => (Side::CrossBefore, Side::CrossAfter),
+        };
+
+        match at_source {
+            true => source,
+            false => target,
+        }
+    }
+
+    // Where an edge attaches to one of its ends. The ports of a node are spread evenly
+    // along its sides, in the order edges reach them. Other edges are spread along the
+    // side they go by, in the order of the nodes at their other end so they do not cross,
+    // each with a cell of its own for its head, unless the side is too short for them.
+    fn anchor(
+        &self,
+        layout: &Layout,
+        from: usize,
+        to: usize,
+        edge: &Edge,
+        at_source: bool,
+    ) -> Point {
+        let (node, port) = match at_source {
+            true => (from, edge.from_port.as_deref()),
+            false => (to, edge.to_port.as_deref()),
+        };
+        let (start, size) = (layout.starts[node], self.size_of(node));
+        let ports: Vec<&str> = self.ports_of(node);
+
+        if let Some(index) = port.and_then(|port| ports.iter().position(|p| *p == port)) {
+            return Point::new(
+                spread(start.main, size.main, index, ports.len()),
+                spread(start.cross, size.cross, index, ports.len()),
+            );
+        }
+
+        let side: Side = self.side(layout, from, to, at_source);
+        let mut ends: Vec<(i32, &Edge, bool)> = self
+            .links()
+            .into_iter()
+            .filter(|(_, _, other)| !other.hidden)
+            .flat_map(|(f, t, other)| [(f, t, other, true), (f, t, other, false)])
+            .filter(|(f, t, other, source)| {
+                let (end, end_port) = match source {
+                    true => (*f, &other.from_port),
+                    false => (*t, &other.to_port),
+                };
+
+                end == node && end_port.is_none() && self.side(layout, *f, *t, *source) == side
+            })
+            .map(|(f, t, other, source)| {
+                let far: usize = if source { t } else { f };
+                let far_center: Point = layout.starts[far].center(self.size_of(far));
+                let key: i32 = match side {
+                    Side::Before | Side::After => far_center.cross,
+                    Side::CrossBefore | Side::CrossAfter => far_center.main,
+                };
+
+                (key, other, source)
+            })
+            .collect();
+        ends.sort_by_key(|(key, ..)| *key);
+        let index: Option<usize> = ends
+            .iter()
+            .position(|(_, other, source)| std::ptr::eq(*other, edge) && *source == at_source);
+        let center: Point = start.center(size);
+
+        match (index, &side) {
+            (Some(index), Side::Before | Side::After) if size.cross - 2 >= ends.len() as i32 => {
+                Point::new(
+                    center.main,
+                    spread(start.cross, size.cross, index, ends.len()),
+                )
+            }
+            (Some(index), Side::CrossBefore | Side::CrossAfter)
+                if size.main - 2 >= ends.len() as i32 =>
+            {
+                Point::new(
+                    spread(start.main, size.main, index, ends.len()),
+                    center.cross,
+                )
+            }
+            _ => center,
+        }
+    }
+
+    // From the middle of the far side of a node along the cross axis, around its corner and
+    // back into its far side along the main axis, next to that corner
+    fn loop_path(&self, layout: &Layout, node: usize) -> Vec<Point> {
+        let (start, size) = (layout.starts[node], self.size_of(node));
+        let (main_end, cross_end) = (start.main + size.main, start.cross + size.cross);
+        let back: i32 = (cross_end - 2).max(start.cross);
+        let center: Point = start.center(size);
+
+        vec![
+            Point::new(center.main, cross_end),
+            Point::new(center.main, cross_end + 1),
+            Point::new(main_end + 1, cross_end + 1),
+            Point::new(main_end + 1, back),
+            Point::new(main_end, back),
+        ]
+    }
+
+    // Ports of a node in the order edges reach them, then the ones no edge reaches
+    fn ports_of(&self, node: usize) -> Vec<&str> {
+        let id: &str = &self.nodes[node].0;
+        let mut ports: Vec<&str> = Vec::new();
+
+        for edge in self.edges.iter() {
+            for (end, end_port) in [
+                (&edge.from_id, &edge.from_port),
+                (&edge.to_id, &edge.to_port),
+            ] {
+                if let Some(end_port) = end_port
+                    .as_deref()
+                    .filter(|p| end == id && !ports.contains(p))
+                {
+                    ports.push(end_port);
+                }
+            }
+        }
+        for (owner, port) in self.ports.iter() {
+            if owner == id && !ports.contains(&port.id.as_str()) {
+                ports.push(&port.id);
+            }
+        }
+
+        ports
+    }
+
+    fn port_name(&self, node_id: &str, port_id: &str) -> Option<&str> {
+        self.ports
+            .iter()
+            .find(|(owner, port)| owner == node_id && port.id == port_id)
+            .map(|(_, port)| port.name.as_str())
+    }
+
+    // Ports of a node no edge reaches
+    fn unconnected_ports(&self, node: usize) -> Vec<&Port> {
+        let id: &str = &self.nodes[node].0;
+
+        self.ports
+            .iter()
+            .filter(|(owner, port)| {
+                owner == id
+                    && !self.edges.iter().any(|edge| {
+                        (edge.from_id == id && edge.from_port.as_ref() == Some(&port.id))
+                            || (edge.to_id == id && edge.to_port.as_ref() == Some(&port.id))
+                    })
+            })
+            .map(|(_, port)| port)
+            .collect()
+    }
+
+    // Room along the main axis for the names of the ports no edge reaches: a line each
+    // below the node, or the widest name beside it
+    fn port_room(&self, node: usize) -> i32 {
+        let names: Vec<AsciiText> = self
+            .unconnected_ports(node)
+            .into_iter()
+            .map(|port| AsciiText::new(Position::default(), port.name.clone()))
+            .collect();
+
+        match self.direction {
+            LayoutDirection::TopToBottom => names.iter().map(|name| name.height() as i32).sum(),
+            LayoutDirection::LeftToRight => names
+                .iter()
+                .map(|name| name.width() as i32 + 1)
+                .max()
+                .unwrap_or(0),
+        }
+    }
+
+    // The border cell of each port no edge reaches, on the far side of its node along the
+    // main axis, and where its name goes
+    fn unconnected_port_cells(
+        &self,
+        layout: &Layout,
+        node: usize,
+    ) -> Vec<(&Port, Position, Position)> {
+        let (start, size) = (layout.starts[node], self.size_of(node));
+        let ports: Vec<&str> = self.ports_of(node);
+        let border: i32 = start.main + size.main - 1;
+        let mut line: i32 = border + 1;
+
+        self.unconnected_ports(node)
+            .into_iter()
+            .filter_map(|port| {
+                let index: usize = ports.iter().position(|p| *p == port.id)?;
+                let cross: i32 = spread(start.cross, size.cross, index, ports.len());
+                let marker: Position = self.position_of(Point::new(border, cross));
+                let name: Position = match self.direction {
+                    LayoutDirection::TopToBottom => {
+                        let name: Position = Position::new(cross, line);
+
+                        line +=
+                            AsciiText::new(Position::default(), port.name.clone()).height() as i32;
+                        name
+                    }
+                    LayoutDirection::LeftToRight => Position::new(border + 2, cross),
+                };
+
+                Some((port, marker, name))
+            })
+            .collect()
+    }
+
+    // Every cell an edge goes through, from the source to the target
+    fn cells(&self, path: &[Point]) -> Vec<Position> {
+        let corners: Vec<Position> = path.iter().map(|p| self.position_of(*p)).collect();
+
+        through(&corners)
+    }
+}
+
+impl AsciiElement for AsciiGraph {
+    fn draw(&mut self, grid: &mut AsciiGrid) {
+        let layout: Layout = self.layout();
+
+        let routes: Vec<(&Edge, Vec<Position>)> = self
+            .links()
+            .into_iter()
+            .filter(|(_, _, edge)| !edge.hidden)
+            .map(|(from, to, edge)| (edge, self.cells(&self.route(&layout, from, to, edge))))
+            .chain(
+                self.loops()
+                    .into_iter()
+                    .filter(|(_, edge)| !edge.hidden)
+                    .map(|(node, edge)| (edge, self.cells(&self.loop_path(&layout, node)))),
+            )
+            .collect();
+        let mut junctions: HashMap<(i32, i32), Junction> = HashMap::new();
+
+        routes
+            .iter()
+            .for_each(|(edge, cells)| trace(&mut junctions, edge, cells));
+        junctions
+            .iter()
+            .for_each(|((x, y), junction)| put(grid, junction.char(), &Position::new(*x, *y)));
+        routes
+            .iter()
+            .for_each(|(edge, cells)| decorate(grid, edge, cells));
+        let mut taken: HashSet<(i32, i32)> = HashSet::new();
+        for (node, start) in layout.starts.iter().enumerate() {
+            let position: Position = self.position_of(*start);
+            let element: &dyn AsciiElement = self.nodes[node].1.as_ref();
+
+            for x in 0..element.width() as i32 {
+                for y in 0..element.height() as i32 {
+                    taken.insert((position.x + x, position.y + y));
+                }
+            }
+        }
+        for (edge, cells) in routes.iter() {
+            if let Some(label) = &edge.label {
+                put_label(grid, label, cells, &mut taken);
+            }
+        }
+        let mut markers: Vec<(char, Position)> = routes
+            .iter()
+            .flat_map(|(edge, cells)| border_markers(edge, cells))
+            .collect();
+        // Port names go next to the marker, along the edge reaching the port if any
+        for (edge, cells) in routes.iter() {
+            let ends = [
+                (&edge.from_id, &edge.from_port, cells.clone()),
+                (
+                    &edge.to_id,
+                    &edge.to_port,
+                    cells.iter().rev().cloned().collect(),
+                ),
+            ];
+
+            for (id, port, cells) in ends {
+                let name: Option<&str> = port.as_deref().and_then(|port| self.port_name(id, port));
+
+                if let (Some(name), [first, second, ..]) = (name, cells.as_slice()) {
+                    let marker: Position =
+                        Position::new(2 * first.x - second.x, 2 * first.y - second.y);
+                    let cells: Vec<Position> = [marker].into_iter().chain(cells).collect();
+
+                    put_label(grid, name, &cells, &mut taken);
+                }
+            }
+        }
+        for node in 0..self.nodes.len() {
+            for (port, marker, name) in self.unconnected_port_cells(&layout, node) {
+                markers.push((PORT_MARKER, marker));
+                AsciiText::new(name, port.name.clone()).draw(grid);
+            }
+        }
+
+        for (node, start) in layout.starts.iter().enumerate() {
+            let position: Position = self.position_of(*start);
+            let element: &mut Box<dyn AsciiElement> = &mut self.nodes[node].1;
+
+            element.r#move(position);
+            element.draw(grid);
+        }
+
+        markers
+            .iter()
+            .for_each(|(marker, position)| put(grid, *marker, position));
+    }
+
+    fn position(&self) -> Position {
+        self.position.clone()
+    }
+
+    fn width(&self) -> u16 {
+        let size: Point = self.layout().size;
+
+        match self.direction {
+            LayoutDirection::TopToBottom => size.cross as u16,
+            LayoutDirection::LeftToRight => size.main as u16,
+        }
+    }
+
+    fn height(&self) -> u16 {
+        let size: Point = self.layout().size;
+
+        match self.direction {
+            LayoutDirection::TopToBottom => size.main as u16,
+            LayoutDirection::LeftToRight => size.cross as u16,
+        }
+    }
+
+    fn r#move(&mut self, new_position: Position) {
+        self.position = new_position;
+    }
+}
+
+// Where an edge puts its target relative to its source
+#[derive(Debug, Clone, PartialEq)]
+enum Placement {
+    After,
+    Before,
+    // Forward
+    Beside(bool),
+}
+
+// Coordinates along the ranks (main) and within a rank (cross)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct Point {
+    main: i32,
+    cross: i32,
+}
+
+impl Point {
+    fn new(main: i32, cross: i32) -> Self {
+        Self { main, cross }
+    }
+
+    fn center(&self, size: Point) -> Point {
+        Point::new(self.main + size.main / 2, self.cross + size.cross / 2)
+    }
+}
+
+// Directions a cell is linked to by edge lines
+#[derive(Debug, Clone, Default)]
+struct Junction {
+    up: bool,
+    down: bool,
+    left: bool,
+    right: bool,
+    // Dotted only when every edge going through it is
+    solid: bool,
+}
+
+impl Junction {
+    fn link(&mut self, dx: i32, dy: i32) {
+        self.up |= dy < 0;
+        self.down |= dy > 0;
+        self.left |= dx < 0;
+        self.right |= dx > 0;
+    }
+
+    fn char(&self) -> char {
+        match (self.up, self.down, self.left, self.right, self.solid) {
+            (_, _, false, false, true) => '│',
+            (_, _, false, false, false) => '┆',
+            (false, false, _, _, true) => '─',
+            (false, false, _, _, false) => '┄',
+            (false, true, false, true, _) => '┌',
+            (false, true, true, false, _) => '┐',
+            (true, false, false, true, _) => '└',
+            (true, false, true, false, _) => '┘',
+            (true, true, false, true, _) => '├',
+            (true, true, true, false, _) => '┤',
+            (false, true, true, true, _) => '┬',
+            (true, false, true, true, _) => '┴',
+            (true, true, true, true, _) => '┼',
+        }
+    }
+}
+
+// Sides of a node, along the main axis or the cross axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Side {
+    Before,
+    After,
+    CrossBefore,
+    CrossAfter,
+}
+
+struct Layout {
+    // Per node
+    ranks: Vec<usize>,
+    starts: Vec<Point>,
+    // Per rank, along the main axis
+    band_ends: Vec<i32>,
+    gaps: Vec<i32>,
+    // Edges going around the ranks, one lane each
+    detours: Vec<(usize, usize)>,
+    // Edges going around the nodes of their own rank, past its far side
+    around: Vec<(usize, usize)>,
+    size: Point,
+}
+
+// Evenly spread position of one of `count` items along a side, off its corners
+fn spread(start: i32, size: i32, index: usize, count: usize) -> i32 {
+    start + 1 + (index as i32 + 1) * (size - 2) / (count as i32 + 1)
+}
+
+// Every cell of the straight segments between corners
+fn through(corners: &[Position]) -> Vec<Position> {
+    let mut cells: Vec<Position> = corners.first().cloned().into_iter().collect();
+
+    for segment in corners.windows(2) {
+        let (dx, dy) = (
+            (segment[1].x - segment[0].x).signum(),
+            (segment[1].y - segment[0].y).signum(),
+        );
+        let mut current: Position = segment[0].clone();
+
+        while current != segment[1] {
+            current = Position::new(current.x + dx, current.y + dy);
+            cells.push(current.clone());
+        }
+    }
+
+    cells
+}
+
+// Leaves `start` along the main axis and goes across at `bend` to the end, or first to
+// the lane of a detour and back across at the last bend
+fn bent(start: Point, bend: i32, end: Point, detour: Option<(i32, i32)>) -> Vec<Point> {
+    match detour {
+        Some((lane, last_bend)) => vec![
+            start,
+            Point::new(bend, start.cross),
+            Point::new(bend, lane),
+            Point::new(last_bend, lane),
+            Point::new(last_bend, end.cross),
+            end,
+        ],
+        None => vec![
+            start,
+            Point::new(bend, start.cross),
+            Point::new(bend, end.cross),
+            end,
+        ],
+    }
+}
+
+/// Longest path ranking: a target goes at least one rank after its source.
+/// Edges closing a cycle are left out, as a state going back to an earlier one,
+/// and it still stops after as many rounds as there are nodes.
+fn rank(count: usize, placements: &[(usize, usize, Placement)]) -> Vec<usize> {
+    let mut ranks: Vec<usize> = vec![0; count];
+    let closing: Vec<bool> = closing_cycles(count, placements);
+
+    for _ in 0..=count {
+        let mut changed: bool = false;
+
+        for (index, (from, to, placement)) in placements.iter().enumerate() {
+            if closing[index] {
+                continue;
+            }
+
+            let (before, after) = match placement {
+                Placement::After => (*from, *to),
+                Placement::Before => (*to, *from),
+                Placement::Beside(_) => {
+                    let rank: usize = ranks[*from].max(ranks[*to]);
+                    changed |= ranks[*from] != rank || ranks[*to] != rank;
+                    (ranks[*from], ranks[*to]) = (rank, rank);
+                    continue;
+                }
+            };
+
+            if ranks[after] <= ranks[before] {
+                ranks[after] = ranks[before] + 1;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    // Cycles may leave unused ranks behind
+    let mut used: Vec<usize> = ranks.clone();
+    used.sort_unstable();
+    used.dedup();
+
+    ranks
+        .iter()
+        .map(|rank| used.binary_search(rank).unwrap_or_default())
+        .collect()
+}
+
+/// Marks the edges reaching a node still being visited in a depth first search,
+/// which starts from the nodes in declaration order so the first ones keep the top ranks
+fn closing_cycles(count: usize, placements: &[(usize, usize, Placement)]) -> Vec<bool> {
+    let ordered: Vec<Option<(usize, usize)>> = placements
+        .iter()
+        .map(|(from, to, placement)| match placement {
+            Placement::After => Some((*from, *to)),
+            Placement::Before => Some((*to, *from)),
+            Placement::Beside(_) => None,
+        })
+        .collect();
+    let mut closing: Vec<bool> = vec![false; placements.len()];
+    // Not visited, being visited, visited
+    let mut states: Vec<u8> = vec![0; count];
+
+    fn visit(
+        node: usize,
+        ordered: &[Option<(usize, usize)>],
+        states: &mut [u8],
+        closing: &mut [bool],
+    ) {
+        states[node] = 1;
+
+        for (index, edge) in ordered.iter().enumerate() {
+            match edge {
+                Some((before, after)) if *before == node => match states[*after] {
+                    0 => visit(*after, ordered, states, closing),
+                    1 => closing[index] = true,
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+
+        states[node] = 2;
+    }
+
+    for node in 0..count {
+        if states[node] == 0 {
+            visit(node, &ordered, &mut states, &mut closing);
+        }
+    }
+
+    closing
+}
+
+/// Order within a rank: "a -right-> b" puts b after a, other nodes keep their declaration order
+fn order(count: usize, placements: &[(usize, usize, Placement)]) -> Vec<usize> {
+    let mut orders: Vec<usize> = vec![0; count];
+
+    for _ in 0..=count {
+        let mut changed: bool = false;
+
+        for (from, to, placement) in placements {
+            let (before, after) = match placement {
+                Placement::Beside(true) => (*from, *to),
+                Placement::Beside(false) => (*to, *from),
+                _ => continue,
+            };
+
+            if orders[after] <= orders[before] {
+                orders[after] = orders[before] + 1;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    orders
+}
+
+// Links an edge to the cells it goes through, lines crossing or merging in a cell
+// being drawn as a single junction
+fn trace(junctions: &mut HashMap<(i32, i32), Junction>, edge: &Edge, cells: &[Position]) {
+    let solid: bool = edge.line_style != LineStyle::Dotted;
+
+    for cell in cells {
+        junctions.entry((cell.x, cell.y)).or_default().solid |= solid;
+    }
+
+    for pair in cells.windows(2) {
+        let (a, b) = (&pair[0], &pair[1]);
+
+        junctions
+            .entry((a.x, a.y))
+            .or_default()
+            .link(b.x - a.x, b.y - a.y);
+        junctions
+            .entry((b.x, b.y))
+            .or_default()
+            .link(a.x - b.x, a.y - b.y);
+    }
+}
+
+// Arrow heads and multiplicities at the ends
+fn decorate(grid: &mut AsciiGrid, edge: &Edge, cells: &[Position]) {
+    if let [first, second, ..] = cells {
+        if let Some(head) = head(&edge.style_from, second, first) {
+            put(grid, head, first);
+        }
+        if let Some(multiplicity) = &edge.from_multiplicity {
+            put_multiplicity(grid, multiplicity, second, first);
+        }
+    }
+    if let [.., before_last, last] = cells {
+        if let Some(head) = head(&edge.style_to, before_last, last) {
+            put(grid, head, last);
+        }
+        if let Some(multiplicity) = &edge.to_multiplicity {
+            put_multiplicity(grid, multiplicity, before_last, last);
+        }
+    }
+}
+
+// The label next to the first cell of its edge where it fits, beside a vertical segment or
+// below a horizontal one, without covering nodes, lines or other labels, and with nothing
+// in between so it is not read as the label of another line
+fn put_label(
+    grid: &mut AsciiGrid,
+    label: &str,
+    cells: &[Position],
+    taken: &mut HashSet<(i32, i32)>,
+) {
+    let mut text: AsciiText = AsciiText::new(Position::default(), label.to_owned());
+    let (width, height) = (text.width() as i32, text.height() as i32);
+    // Label position, with the first and last columns that must be blank
+    let candidates: Vec<(Position, i32, i32)> = cells
+        .iter()
+        .enumerate()
+        .flat_map(|(index, cell)| {
+            let (from, to) = match cells.get(index + 1) {
+                Some(next) => (cell, next),
+                None => (&cells[index.saturating_sub(1)], cell),
+            };
+
+            let (x, y) = (cell.x, cell.y);
+
+            match (to.x - from.x).signum() {
+                0 => [
+                    (Position::new(x + 2, y), x + 1, x + width + 1),
+                    (Position::new(x - width - 1, y), x - width - 1, x - 1),
+                ],
+                1 => [
+                    (Position::new(x + 1, y + 1), x + 1, x + width),
+                    (Position::new(x + 1, y - height), x + 1, x + width),
+                ],
+                _ => [
+                    (Position::new(x - width, y + 1), x - width, x - 1),
+                    (Position::new(x - width, y - height), x - width, x - 1),
+                ],
+            }
+        })
+        .collect();
+    let fits = |(position, first, last): &(Position, i32, i32)| {
+        (*first..=*last).all(|x| {
+            (0..height).all(|y| {
+                let cell: Position = Position::new(x, position.y + y);
+
+                !taken.contains(&(cell.x, cell.y)) && grid.is_blank(&cell)
+            })
+        })
+    };
+    let Some((position, ..)) = candidates
+        .iter()
+        .find(|candidate| fits(candidate))
+        .or(candidates.first())
+        .cloned()
+    else {
+        return;
+    };
+
+    // With a blank cell on each side so labels do not run into each other
+    for x in -1..=width {
+        for y in 0..height {
+            taken.insert((position.x + x, position.y + y));
+        }
+    }
+    text.r#move(position);
+    text.draw(grid);
+}
+
+// On the border of the node, right behind each end of the edge connected to a port, to
+// a component providing an interface or to a component requiring the interface at the
+// other end
+fn border_markers(edge: &Edge, cells: &[Position]) -> Vec<(char, Position)> {
+    let behind =
+        |end: &Position, next: &Position| Position::new(2 * end.x - next.x, 2 * end.y - next.y);
+    let marker = |port: &Option<String>, style: &EdgeStyle, other: &EdgeStyle, end, next| match (
+        port, style, other,
+    ) {
+        (Some(_), _, _) => Some(PORT_MARKER),
+        (None, EdgeStyle::Lollipop, _) => Some(LOLLIPOP),
+        (None, _, EdgeStyle::Socket) => Some(socket(end, next)),
+        (None, _, _) => None,
+    };
+    let mut markers: Vec<(char, Position)> = Vec::new();
+
+    if let [first, second, ..] = cells
+        && let Some(marker) = marker(
+            &edge.from_port,
+            &edge.style_from,
+            &edge.style_to,
+            first,
+            second,
+        )
+    {
+        markers.push((marker, behind(first, second)));
+    }
+    if let [.., before_last, last] = cells
+        && let Some(marker) = marker(
+            &edge.to_port,
+            &edge.style_to,
+            &edge.style_from,
+            last,
+            before_last,
+        )
+    {
+        markers.push((marker, behind(last, before_last)));
+    }
+
+    markers
+}
+
+// A socket open towards the edge leaving the node from `end` to `next`
+fn socket(end: &Position, next: &Position) -> char {
+    match (next.x.cmp(&end.x), next.y.cmp(&end.y)) {
+        (_, Ordering::Less) => '◡',
+        (_, Ordering::Greater) => '⌒',
+        (Ordering::Less, _) => ')',
+        _ => '(',
+    }
+}
+
+// The head drawn at `at`, the end of a segment coming from `from`
+fn head(style: &EdgeStyle, from: &Position, at: &Position) -> Option<char> {
+    let heads: [char; 4] = match style {
+        EdgeStyle::Solid => return None,
+        EdgeStyle::Arrow => ['▲', '▼', '◀', '▶'],
+        EdgeStyle::OpenArrow => ['^', 'v', '<', '>'],
+        EdgeStyle::Extension => ['△', '▽', '◁', '▷'],
+        EdgeStyle::Composition => return Some('◆'),
+        EdgeStyle::Aggregation => return Some('◇'),
+        // On the border of the component instead, see `border_markers`
+        EdgeStyle::Lollipop | EdgeStyle::Socket => return None,
+    };
+
+    Some(match (at.x.cmp(&from.x), at.y.cmp(&from.y)) {
+        (_, Ordering::Less) => heads[0],
+        (_, Ordering::Greater) => heads[1],
+        (Ordering::Less, _) => heads[2],
+        _ => heads[3],
+    })
+}
+
+// Left of a vertical segment, above a horizontal one, on the side away from the end `at`
+fn put_multiplicity(grid: &mut AsciiGrid, multiplicity: &str, from: &Position, at: &Position) {
+    let width: i32 = multiplicity.chars().count() as i32;
+    let start: Position = match (at.x.cmp(&from.x), at.y - 1) {
+        (Ordering::Equal, _) => Position::new(at.x - width - 1, at.y),
+        (Ordering::Greater, y) => Position::new(at.x - width, y.max(0)),
+        (Ordering::Less, y) => Position::new(at.x + 1, y.max(0)),
+    };
+
+    for (index, ch) in multiplicity.chars().enumerate() {
+        put(grid, ch, &Position::new(start.x + index as i32, start.y));
+    }
+}
+
+// Label and edges may reach outside the grid, which only grows to the right and bottom
+fn put(grid: &mut AsciiGrid, ch: char, position: &Position) {
+    if position.x >= 0 && position.y >= 0 {
+        grid.put_char(ch, position);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use lib_core::domain::entities::diagram::PortKind;
+
+    use crate::adapters::models::ascii_border_decorator::AsciiBorderDecorator;
+
+    use super::*;
+
+    fn text(content: &str) -> Box<dyn AsciiElement> {
+        Box::new(AsciiText::new(Position::default(), content.to_string()))
+    }
+
+    fn edge(from: &str, to: &str) -> Edge {
+        Edge::new(
+            from.to_string(),
+            to.to_string(),
+            EdgeStyle::Solid,
+            EdgeStyle::Arrow,
+            None,
+        )
+    }
+
+    #[test]
+    fn top_to_bottom_ranks() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut graph: AsciiGraph =
+            AsciiGraph::new(LayoutDirection::TopToBottom, Position::default())
+                .with_node("a".to_string(), text("A"))
+                .with_node("b".to_string(), text("B"))
+                .with_node("c".to_string(), text("C"))
+                .with_edge(edge("a", "b"))
+                .with_edge(edge("a", "c"));
+
+        graph.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![' ', 'A'],
+                    vec![' ', '│'],
+                    vec!['┌', '┴', '─', '┐'],
+                    vec!['▼', ' ', ' ', '▼'],
+                    vec!['B', ' ', ' ', 'C'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn direction_hints_and_hidden_edges() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut graph: AsciiGraph =
+            AsciiGraph::new(LayoutDirection::TopToBottom, Position::default())
+                .with_node("a".to_string(), text("A"))
+                .with_node("b".to_string(), text("B"))
+                .with_node("c".to_string(), text("C"))
+                .with_edge(Edge {
+                    direction: Some(EdgeDirection::Up),
+                    ..edge("a", "b")
+                })
+                .with_edge(Edge {
+                    direction: Some(EdgeDirection::Left),
+                    line_style: LineStyle::Dotted,
+                    ..edge("a", "c")
+                })
+                .with_edge(Edge {
+                    hidden: true,
+                    ..edge("b", "c")
+                });
+
+        graph.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![' ', 'B'],
+                    vec![' ', '▲'],
+                    vec![' ', '└', '─', '┐'],
+                    vec![' ', ' ', ' ', '│'],
+                    vec!['C', '◀', '┄', 'A'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn left_to_right_ranks_with_label() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut graph: AsciiGraph =
+            AsciiGraph::new(LayoutDirection::LeftToRight, Position::default())
+                .with_node("a".to_string(), text("A"))
+                .with_node("b".to_string(), text("B"))
+                .with_edge(Edge {
+                    label: Some("uses".to_string()),
+                    ..edge("a", "b")
+                });
+
+        graph.draw(&mut grid);
+
+        assert_eq!(graph.width(), 9);
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['A', '─', '─', '─', '─', '─', '─', '▶', 'B'],
+                    vec![' ', ' ', 'u', 's', 'e', 's'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn edges_leaving_one_node_keep_their_own_cells_and_labels() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let boxed: Box<dyn AsciiElement> = Box::new(AsciiBorderDecorator::new(
+            text("A"),
+            Position::default(),
+            2,
+            0,
+            ' ',
+        ));
+        let mut graph: AsciiGraph =
+            AsciiGraph::new(LayoutDirection::TopToBottom, Position::default())
+                .with_node("a".to_string(), boxed)
+                .with_node("b".to_string(), text("B"))
+                .with_node("c".to_string(), text("C"))
+                .with_edge(Edge {
+                    label: Some("x".to_string()),
+                    ..edge("a", "b")
+                })
+                .with_edge(Edge {
+                    label: Some("y".to_string()),
+                    style_to: EdgeStyle::Extension,
+                    ..edge("a", "c")
+                });
+
+        graph.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['╭', '─', '─', '─', '─', '─', '╮'],
+                    vec!['│', ' ', ' ', 'A', ' ', ' ', '│'],
+                    vec!['╰', '─', '─', '─', '─', '─', '╯'],
+                    vec!['x', ' ', '│', ' ', '│', ' ', 'y'],
+                    vec![' ', '┌', '┘', ' ', '│'],
+                    vec![' ', '▼', ' ', ' ', '▽'],
+                    vec![' ', 'B', ' ', ' ', 'C'],
+                ]
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn edges_from_a_node_to_itself_loop_around_its_corner() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let boxed: Box<dyn AsciiElement> = Box::new(AsciiBorderDecorator::new(
+            text("A"),
+            Position::default(),
+            1,
+            0,
+            ' ',
+        ));
+        let mut graph: AsciiGraph =
+            AsciiGraph::new(LayoutDirection::TopToBottom, Position::default())
+                .with_node("a".to_string(), boxed)
+                .with_node("b".to_string(), text("B"))
+                .with_edge(Edge {
+                    label: Some("retry".to_string()),
+                    ..edge("a", "a")
+                })
+                .with_edge(edge("a", "b"));
+
+        graph.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['╭', '─', '─', '─', '╮', ' ', 'r', 'e', 't', 'r', 'y'],
+                    vec!['│', ' ', 'A', ' ', '│', '─', '┐'],
+                    vec!['╰', '─', '─', '─', '╯', ' ', '│'],
+                    vec![' ', ' ', '│', '▲', ' ', ' ', '│'],
+                    vec![' ', ' ', '│', '└', '─', '─', '┘'],
+                    vec![' ', ' ', '└', '┐'],
+                    vec![' ', ' ', ' ', '▼'],
+                    vec![' ', ' ', ' ', 'B'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn edges_go_around_nodes_of_their_rank() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let beside = |from: &str, to: &str| Edge {
+            direction: Some(EdgeDirection::Right),
+            ..edge(from, to)
+        };
+        let mut graph: AsciiGraph =
+            AsciiGraph::new(LayoutDirection::TopToBottom, Position::default())
+                .with_node("a".to_string(), text("A"))
+                .with_node("b".to_string(), text("B"))
+                .with_node("c".to_string(), text("C"))
+                .with_edge(beside("a", "b"))
+                .with_edge(beside("b", "c"))
+                .with_edge(beside("a", "c"));
+
+        graph.draw(&mut grid);
+
+        assert_eq!(graph.height(), 3);
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['A', '─', '▶', 'B', '─', '▶', 'C'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', '▲'],
+                    vec!['└', '─', '─', '─', '─', '─', '┘'],
+                ]
+            )
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn ports_are_marked_on_borders() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let boxed: Box<dyn AsciiElement> = Box::new(AsciiBorderDecorator::new(
+            text("C"),
+            Position::default(),
+            2,
+            0,
+            ' ',
+        ));
+        let mut graph: AsciiGraph =
+            AsciiGraph::new(LayoutDirection::TopToBottom, Position::default())
+                .with_node("a".to_string(), text("A"))
+                .with_node("c".to_string(), boxed)
+                .with_node("b".to_string(), text("B"))
+                .with_edge(Edge {
+                    to_port: Some("in".to_string()),
+                    ..edge("a", "c")
+                })
+                .with_edge(Edge {
+                    from_port: Some("out".to_string()),
+                    ..edge("c", "b")
+                });
+
+        graph.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![' ', ' ', ' ', 'A'],
+                    vec![' ', ' ', ' ', '│'],
+                    vec![' ', ' ', '┌', '┘'],
+                    vec![' ', ' ', '▼'],
+                    vec!['╭', '─', '□', '─', '─', '─', '╮'],
+                    vec!['│', ' ', ' ', 'C', ' ', ' ', '│'],
+                    vec!['╰', '─', '─', '─', '□', '─', '╯'],
+                    vec![' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', '┌', '┘'],
+                    vec![' ', ' ', ' ', '▼'],
+                    vec![' ', ' ', ' ', 'B'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn port_names_are_drawn_with_unconnected_ports() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let boxed: Box<dyn AsciiElement> = Box::new(AsciiBorderDecorator::new(
+            text("C"),
+            Position::default(),
+            2,
+            0,
+            ' ',
+        ));
+        let port = |id: &str, kind: PortKind| Port::new(id.to_string(), id.to_string(), kind);
+        let mut graph: AsciiGraph =
+            AsciiGraph::new(LayoutDirection::TopToBottom, Position::default())
+                .with_node("a".to_string(), text("A"))
+                .with_node("c".to_string(), boxed)
+                .with_port("c".to_string(), port("in", PortKind::In))
+                .with_port("c".to_string(), port("log", PortKind::Out))
+                .with_edge(Edge {
+                    to_port: Some("in".to_string()),
+                    ..edge("a", "c")
+                });
+
+        graph.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![' ', ' ', ' ', 'A'],
+                    vec![' ', ' ', ' ', '│'],
+                    vec![' ', ' ', '┌', '┘'],
+                    vec![' ', ' ', '▼', ' ', 'i', 'n'],
+                    vec!['╭', '─', '□', '─', '─', '─', '╮'],
+                    vec!['│', ' ', ' ', 'C', ' ', ' ', '│'],
+                    vec!['╰', '─', '─', '─', '□', '─', '╯'],
+                    vec![' ', ' ', ' ', ' ', 'l', 'o', 'g'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    fn boxed(content: &str) -> Box<dyn AsciiElement> {
+        Box::new(AsciiBorderDecorator::new(
+            text(content),
+            Position::default(),
+            1,
+            0,
+            ' ',
+        ))
+    }
+
+    fn provides(component: &str, interface: &str) -> Edge {
+        Edge::new(
+            component.to_string(),
+            interface.to_string(),
+            EdgeStyle::Lollipop,
+            EdgeStyle::Solid,
+            None,
+        )
+    }
+
+    #[test]
+    fn sockets_and_lollipops_on_component_borders() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut graph: AsciiGraph =
+            AsciiGraph::new(LayoutDirection::LeftToRight, Position::default())
+                .with_node("client".to_string(), boxed("Client"))
+                .with_node("api".to_string(), text("API"))
+                .with_node("server".to_string(), boxed("Server"))
+                .with_edge(Edge {
+                    style_to: EdgeStyle::Socket,
+                    ..edge("client", "api")
+                })
+                .with_edge(provides("server", "api"));
+
+        graph.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['╭', '─', '─', '─', '─', '─', '─', '─', '─', '╮'],
+                    vec!['│', ' ', 'C', 'l', 'i', 'e', 'n', 't', ' ', '(', '─', '┐'],
+                    vec!['╰', '─', '─', '─', '─', '─', '─', '─', '─', '╯', ' ', '│'],
+                    vec![
+                        ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '├', '─', 'A', 'P',
+                        'I'
+                    ],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['╭', '─', '─', '─', '─', '─', '─', '─', '─', '╮', ' ', '│'],
+                    vec!['│', ' ', 'S', 'e', 'r', 'v', 'e', 'r', ' ', '○', '─', '┘'],
+                    vec!['╰', '─', '─', '─', '─', '─', '─', '─', '─', '╯'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn lollipops_on_components_from_top_to_bottom() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut graph: AsciiGraph =
+            AsciiGraph::new(LayoutDirection::TopToBottom, Position::default())
+                .with_node("store".to_string(), boxed("Store"))
+                .with_node("api".to_string(), text("API"))
+                .with_edge(provides("store", "api"));
+
+        graph.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['╭', '─', '─', '─', '─', '─', '─', '─', '╮'],
+                    vec!['│', ' ', 'S', 't', 'o', 'r', 'e', ' ', '│'],
+                    vec!['╰', '─', '─', '─', '○', '─', '─', '─', '╯'],
+                    vec![' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', 'A', 'P', 'I'],
+                ]
+            )
+            .to_string()
+        )
+    }
+}
@@ -34,6 +34,17 @@ impl AsciiGrid {
         self.grid[y][x] = ch;
     }
 
+    // Cells not written yet are blank as well
+    pub(crate) fn is_blank(&self, pos: &Position) -> bool {
+        pos.x >= 0
+            && pos.y >= 0
+            && self
+                .grid
+                .get(pos.y as usize)
+                .and_then(|row| row.get(pos.x as usize))
+                .is_none_or(|ch| *ch == ' ' || *ch == self.fill_char)
+    }
+
     pub(crate) fn get_grid(&self) -> Vec<Vec<char>> {
         self.grid.clone()
     }
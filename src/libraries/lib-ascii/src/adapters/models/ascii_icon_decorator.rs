@@ -0,0 +1,110 @@
+use crate::adapters::models::{
+    ascii_element::AsciiElement, ascii_grid::AsciiGrid, ascii_text::AsciiText, position::Position,
+};
+
+/// Draws an icon (stick figure, circle...) horizontally centered above the wrapped element
+pub(crate) struct AsciiIconDecorator {
+    wrapped: Box<dyn AsciiElement>,
+    icon: AsciiText,
+    position: Position,
+}
+
+impl AsciiIconDecorator {
+    pub(crate) fn new(wrapped: Box<dyn AsciiElement>, position: Position, icon: &str) -> Self {
+        Self {
+            wrapped,
+            icon: AsciiText::new(Position::default(), icon.to_string()),
+            position,
+        }
+    }
+}
+
+impl AsciiElement for AsciiIconDecorator {
+    fn draw(&mut self, grid: &mut AsciiGrid) {
+        let initial_position: Position = self.position();
+        let width: u16 = self.width();
+
+        let icon_offset: i32 = ((width - self.icon.width()) / 2) as i32;
+        let wrapped_offset: i32 = ((width - self.wrapped.width()) / 2) as i32;
+
+        self.icon.r#move(Position::new(
+            initial_position.x + icon_offset,
+            initial_position.y,
+        ));
+        self.icon.draw(grid);
+
+        self.wrapped.r#move(Position::new(
+            initial_position.x + wrapped_offset,
+            initial_position.y + self.icon.height() as i32,
+        ));
+        self.wrapped.draw(grid);
+    }
+
+    fn position(&self) -> Position {
+        self.position.clone()
+    }
+
+    fn width(&self) -> u16 {
+        self.icon.width().max(self.wrapped.width())
+    }
+
+    fn height(&self) -> u16 {
+        self.icon.height() + self.wrapped.height()
+    }
+
+    fn r#move(&mut self, new_position: Position) {
+        self.position = new_position;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn icon_wider_than_text() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let text: Box<dyn AsciiElement> =
+            Box::new(AsciiText::new(Position::default(), "a".to_string()));
+        let mut decorator: AsciiIconDecorator =
+            AsciiIconDecorator::new(text, Position::default(), " O \n/|\\\n/ \\");
+
+        decorator.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![' ', 'O', ' '],
+                    vec!['/', '|', '\\'],
+                    vec!['/', ' ', '\\'],
+                    vec![' ', 'a'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn text_wider_than_icon() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let text: Box<dyn AsciiElement> =
+            Box::new(AsciiText::new(Position::default(), "Short".to_string()));
+        let mut decorator: AsciiIconDecorator =
+            AsciiIconDecorator::new(text, Position::default(), "○");
+
+        decorator.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![vec![' ', ' ', '○'], vec!['S', 'h', 'o', 'r', 't'],]
+            )
+            .to_string()
+        )
+    }
+}
@@ -0,0 +1,190 @@
+use lib_core::domain::entities::diagram::NoteSide;
+
+use crate::adapters::models::{
+    ascii_column::AsciiColumn, ascii_element::AsciiElement, ascii_grid::AsciiGrid,
+    ascii_row::AsciiRow, position::Position,
+};
+
+// ┄┄ between the element and its left or right notes
+const HORIZONTAL_CONNECTOR: char = '┄';
+const HORIZONTAL_CONNECTOR_LENGTH: u16 = 2;
+// ┆ between the element and its top or bottom notes
+const VERTICAL_CONNECTOR: char = '┆';
+const VERTICAL_CONNECTOR_LENGTH: u16 = 1;
+
+/// Places notes around the wrapped element, each side linked to it by a dotted line.
+/// Left and right notes are top-aligned with the element, top and bottom ones centered.
+pub(crate) struct AsciiNoteDecorator {
+    wrapped: Box<dyn AsciiElement>,
+    left: AsciiColumn,
+    right: AsciiColumn,
+    top: AsciiRow,
+    bottom: AsciiRow,
+    position: Position,
+}
+
+impl AsciiNoteDecorator {
+    pub(crate) fn new(wrapped: Box<dyn AsciiElement>, position: Position) -> Self {
+        Self {
+            wrapped,
+            left: AsciiColumn::new(vec![], Position::default(), 1),
+            right: AsciiColumn::new(vec![], Position::default(), 1),
+            top: AsciiRow::new(vec![], Position::default(), 1),
+            bottom: AsciiRow::new(vec![], Position::default(), 1),
+            position,
+        }
+    }
+
+    pub(crate) fn with_note(mut self, side: &NoteSide, note: Box<dyn AsciiElement>) -> Self {
+        match side {
+            NoteSide::Left => self.left.add(note),
+            NoteSide::Right => self.right.add(note),
+            NoteSide::Top => self.top.add(note),
+            NoteSide::Bottom => self.bottom.add(note),
+        }
+
+        self
+    }
+
+    fn center_width(&self) -> u16 {
+        self.top
+            .width()
+            .max(self.wrapped.width())
+            .max(self.bottom.width())
+    }
+}
+
+impl AsciiElement for AsciiNoteDecorator {
+    fn draw(&mut self, grid: &mut AsciiGrid) {
+        let initial_position: Position = self.position();
+        let center_width: u16 = self.center_width();
+        let wrapped_width: u16 = self.wrapped.width();
+        let wrapped_height: u16 = self.wrapped.height();
+
+        let center_x: i32 =
+            initial_position.x + connected(self.left.width(), HORIZONTAL_CONNECTOR_LENGTH) as i32;
+        let wrapped_x: i32 = center_x + ((center_width - wrapped_width) / 2) as i32;
+        let wrapped_y: i32 =
+            initial_position.y + connected(self.top.height(), VERTICAL_CONNECTOR_LENGTH) as i32;
+        let wrapped_bottom: i32 = wrapped_y + wrapped_height as i32;
+        let right_x: i32 = center_x + (center_width + HORIZONTAL_CONNECTOR_LENGTH) as i32;
+        let middle_x: i32 = wrapped_x + (wrapped_width / 2) as i32;
+
+        self.wrapped.r#move(Position::new(wrapped_x, wrapped_y));
+        self.wrapped.draw(grid);
+
+        if self.left.width() > 0 {
+            let y: i32 = wrapped_y + (wrapped_height.min(self.left.height()) / 2) as i32;
+            let x: i32 = initial_position.x + self.left.width() as i32;
+
+            self.left
+                .r#move(Position::new(initial_position.x, wrapped_y));
+            self.left.draw(grid);
+            (x..wrapped_x).for_each(|x| grid.put_char(HORIZONTAL_CONNECTOR, &Position::new(x, y)));
+        }
+
+        if self.right.width() > 0 {
+            let y: i32 = wrapped_y + (wrapped_height.min(self.right.height()) / 2) as i32;
+
+            self.right.r#move(Position::new(right_x, wrapped_y));
+            self.right.draw(grid);
+            (wrapped_x + wrapped_width as i32..right_x)
+                .for_each(|x| grid.put_char(HORIZONTAL_CONNECTOR, &Position::new(x, y)));
+        }
+
+        if self.top.width() > 0 {
+            let x: i32 = center_x + ((center_width - self.top.width()) / 2) as i32;
+
+            self.top.r#move(Position::new(x, initial_position.y));
+            self.top.draw(grid);
+            (initial_position.y + self.top.height() as i32..wrapped_y)
+                .for_each(|y| grid.put_char(VERTICAL_CONNECTOR, &Position::new(middle_x, y)));
+        }
+
+        if self.bottom.width() > 0 {
+            let x: i32 = center_x + ((center_width - self.bottom.width()) / 2) as i32;
+            let y: i32 = wrapped_bottom + VERTICAL_CONNECTOR_LENGTH as i32;
+
+            self.bottom.r#move(Position::new(x, y));
+            self.bottom.draw(grid);
+            (wrapped_bottom..y)
+                .for_each(|y| grid.put_char(VERTICAL_CONNECTOR, &Position::new(middle_x, y)));
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position.clone()
+    }
+
+    fn width(&self) -> u16 {
+        connected(self.left.width(), HORIZONTAL_CONNECTOR_LENGTH)
+            + self.center_width()
+            + connected(self.right.width(), HORIZONTAL_CONNECTOR_LENGTH)
+    }
+
+    fn height(&self) -> u16 {
+        let top: u16 = connected(self.top.height(), VERTICAL_CONNECTOR_LENGTH);
+        let bottom: u16 = connected(self.bottom.height(), VERTICAL_CONNECTOR_LENGTH);
+        let sides: u16 = self.left.height().max(self.right.height());
+
+        // Side notes may run past the wrapped element and its bottom notes
+        (top + self.wrapped.height() + bottom).max(top + sides)
+    }
+
+    fn r#move(&mut self, new_position: Position) {
+        self.position = new_position;
+    }
+}
+
+// Room taken by the notes of one side, connector included, if there are any
+fn connected(size: u16, connector_length: u16) -> u16 {
+    match size {
+        0 => 0,
+        size => size + connector_length,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use crate::adapters::models::ascii_text::AsciiText;
+
+    use super::*;
+
+    fn text(content: &str) -> Box<dyn AsciiElement> {
+        Box::new(AsciiText::new(Position::default(), content.to_string()))
+    }
+
+    #[test]
+    fn notes_on_every_side() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut decorator: AsciiNoteDecorator =
+            AsciiNoteDecorator::new(text("abc\ndef\nghi"), Position::default())
+                .with_note(&NoteSide::Left, text("L"))
+                .with_note(&NoteSide::Right, text("R"))
+                .with_note(&NoteSide::Top, text("T"))
+                .with_note(&NoteSide::Bottom, text("B"));
+
+        decorator.draw(&mut grid);
+
+        assert_eq!(decorator.width(), 9);
+        assert_eq!(decorator.height(), 7);
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![' ', ' ', ' ', ' ', 'T'],
+                    vec![' ', ' ', ' ', ' ', '┆'],
+                    vec!['L', '┄', '┄', 'a', 'b', 'c', '┄', '┄', 'R'],
+                    vec![' ', ' ', ' ', 'd', 'e', 'f'],
+                    vec![' ', ' ', ' ', 'g', 'h', 'i'],
+                    vec![' ', ' ', ' ', ' ', '┆'],
+                    vec![' ', ' ', ' ', ' ', 'B'],
+                ]
+            )
+            .to_string()
+        )
+    }
+}
@@ -0,0 +1,105 @@
+use crate::adapters::models::{
+    ascii_element::AsciiElement, ascii_grid::AsciiGrid, position::Position,
+};
+
+/// Lays its elements out from left to right, each one vertically centered.
+/// Empty elements are skipped so they do not leave extra spacing behind.
+pub(crate) struct AsciiRow {
+    elements: Vec<Box<dyn AsciiElement>>,
+    position: Position,
+    spacing: u16,
+}
+
+impl AsciiRow {
+    pub(crate) fn new(
+        elements: Vec<Box<dyn AsciiElement>>,
+        position: Position,
+        spacing: u16,
+    ) -> Self {
+        Self {
+            elements: elements.into_iter().filter(|e| e.width() > 0).collect(),
+            position,
+            spacing,
+        }
+    }
+
+    pub(crate) fn add(&mut self, element: Box<dyn AsciiElement>) {
+        if element.width() > 0 {
+            self.elements.push(element);
+        }
+    }
+}
+
+impl AsciiElement for AsciiRow {
+    fn draw(&mut self, grid: &mut AsciiGrid) {
+        let initial_position: Position = self.position();
+        let height: u16 = self.height();
+        let mut x: i32 = initial_position.x;
+
+        for element in self.elements.iter_mut() {
+            let offset: i32 = ((height - element.height()) / 2) as i32;
+
+            element.r#move(Position::new(x, initial_position.y + offset));
+            element.draw(grid);
+
+            x += (element.width() + self.spacing) as i32;
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position.clone()
+    }
+
+    fn width(&self) -> u16 {
+        let spacing: u16 = self.spacing * (self.elements.len().max(1) - 1) as u16;
+
+        self.elements.iter().map(|e| e.width()).sum::<u16>() + spacing
+    }
+
+    fn height(&self) -> u16 {
+        self.elements.iter().map(|e| e.height()).max().unwrap_or(0)
+    }
+
+    fn r#move(&mut self, new_position: Position) {
+        self.position = new_position;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use crate::adapters::models::ascii_text::AsciiText;
+
+    use super::*;
+
+    #[test]
+    fn elements_side_by_side_and_centered() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut row: AsciiRow = AsciiRow::new(
+            vec![
+                Box::new(AsciiText::new(Position::default(), "a".to_string())),
+                Box::new(AsciiText::new(Position::default(), "".to_string())),
+                Box::new(AsciiText::new(Position::default(), "b\nc\nd".to_string())),
+            ],
+            Position::default(),
+            2,
+        );
+
+        row.draw(&mut grid);
+
+        assert_eq!(row.width(), 4);
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![' ', ' ', ' ', 'b'],
+                    vec!['a', ' ', ' ', 'c'],
+                    vec![' ', ' ', ' ', 'd'],
+                ]
+            )
+            .to_string()
+        )
+    }
+}
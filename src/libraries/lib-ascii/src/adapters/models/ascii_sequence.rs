@@ -0,0 +1,580 @@
+use std::collections::HashMap;
+
+use lib_core::domain::entities::sequence::{Message, MessageKind, SequenceEvent};
+
+use crate::adapters::models::{
+    ascii_element::AsciiElement, ascii_grid::AsciiGrid, ascii_text::AsciiText, position::Position,
+};
+
+// Least room between two lifelines
+const LIFELINE_SPACING: i32 = 4;
+// Room between a fragment border and the lifelines or borders it surrounds
+const FRAGMENT_MARGIN: i32 = 2;
+// ──┐ from the lifeline to the label of a message sent to itself
+const SELF_MESSAGE_WIDTH: i32 = 5;
+
+/// Draws the participants side by side with their lifeline going down below them,
+/// then every event on rows of its own: messages as arrows between lifelines, fragments
+/// as frames around the rows they hold, dividers and delays across the whole sequence.
+pub(crate) struct AsciiSequence {
+    // Id, Head
+    participants: Vec<(String, Box<dyn AsciiElement>)>,
+    events: Vec<SequenceEvent>,
+    position: Position,
+}
+
+impl AsciiSequence {
+    pub(crate) fn new(position: Position) -> Self {
+        Self {
+            participants: Vec::new(),
+            events: Vec::new(),
+            position,
+        }
+    }
+
+    pub(crate) fn with_participant(mut self, id: String, head: Box<dyn AsciiElement>) -> Self {
+        self.participants.push((id, head));
+        self
+    }
+
+    pub(crate) fn with_event(mut self, event: SequenceEvent) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    fn indexes(&self) -> HashMap<&str, usize> {
+        self.participants
+            .iter()
+            .enumerate()
+            .map(|(i, (id, _))| (id.as_str(), i))
+            .collect()
+    }
+
+    // Indexes of the sender and the receiver, if both take part in the sequence
+    fn ends(&self, indexes: &HashMap<&str, usize>, message: &Message) -> Option<(usize, usize)> {
+        Some((
+            *indexes.get(message.from_id.as_str())?,
+            *indexes.get(message.to_id.as_str())?,
+        ))
+    }
+
+    fn rows(&self, event: &SequenceEvent) -> i32 {
+        match event {
+            // A free row above the arrow holds its label
+            SequenceEvent::Message(message) => label_height(&message.label).max(1) + 1,
+            SequenceEvent::Activate(_) | SequenceEvent::Deactivate(_) => 0,
+            SequenceEvent::Destroy(_)
+            | SequenceEvent::FragmentStart(..)
+            | SequenceEvent::FragmentElse(_)
+            | SequenceEvent::FragmentEnd
+            | SequenceEvent::Divider(_)
+            | SequenceEvent::Delay(_) => 1,
+        }
+    }
+
+    fn layout(&self) -> SequenceLayout {
+        let indexes: HashMap<&str, usize> = self.indexes();
+        let widths: Vec<i32> = self
+            .participants
+            .iter()
+            .map(|(_, head)| head.width() as i32)
+            .collect();
+
+        let mut depth: i32 = 0;
+        let mut open: i32 = 0;
+        for event in self.events.iter() {
+            match event {
+                SequenceEvent::FragmentStart(..) => {
+                    open += 1;
+                    depth = depth.max(open);
+                }
+                SequenceEvent::FragmentEnd => open = (open - 1).max(0),
+                _ => {}
+            }
+        }
+
+        // Lifelines are spread from left to right, each far enough from the previous ones
+        // for the heads not to touch and for the labels of the messages between them to fit
+        let mut lifelines: Vec<i32> = Vec::new();
+        for (i, width) in widths.iter().enumerate() {
+            let lifeline: i32 = match i.checked_sub(1) {
+                None => (width / 2).max(FRAGMENT_MARGIN * depth),
+                Some(previous) => {
+                    let heads: i32 =
+                        lifelines[previous] + (widths[previous] + 1) / 2 + 1 + width / 2;
+                    let labels: i32 = self
+                        .events
+                        .iter()
+                        .filter_map(|event| match event {
+                            SequenceEvent::Message(message) => self
+                                .ends(&indexes, message)
+                                .map(|ends| (ends, label_width(&message.label))),
+                            _ => None,
+                        })
+                        .filter_map(|((from, to), label)| match (from.min(to), from.max(to)) {
+                            (left, right) if left == right && right == previous => {
+                                Some(lifelines[left] + SELF_MESSAGE_WIDTH + label + 2)
+                            }
+                            (left, right) if left != right && right == i => {
+                                Some(lifelines[left] + label + LIFELINE_SPACING)
+                            }
+                            _ => None,
+                        })
+                        .max()
+                        .unwrap_or(0);
+
+                    heads
+                        .max(labels)
+                        .max(lifelines[previous] + LIFELINE_SPACING)
+                }
+            };
+
+            lifelines.push(lifeline);
+        }
+
+        // Fragments surround every lifeline, and the messages the last one sends to itself
+        let first: i32 = lifelines
+            .first()
+            .copied()
+            .unwrap_or(FRAGMENT_MARGIN * depth);
+        let mut inner_right: i32 = lifelines.last().copied().unwrap_or(first);
+        let mut open: i32 = 0;
+        for event in self.events.iter() {
+            match event {
+                SequenceEvent::Message(message) => {
+                    if let Some((from, to)) = self.ends(&indexes, message)
+                        && from == to
+                        && from + 1 == lifelines.len()
+                    {
+                        inner_right = inner_right.max(
+                            lifelines[from] + SELF_MESSAGE_WIDTH + label_width(&message.label),
+                        );
+                    }
+                }
+                SequenceEvent::FragmentStart(kind, label) => {
+                    let title: i32 = text_width(&fragment_title(kind.keyword(), label));
+                    let margin: i32 = FRAGMENT_MARGIN * (depth - open);
+
+                    inner_right = inner_right.max(first - 2 * margin + title + 2);
+                    open += 1;
+                }
+                SequenceEvent::FragmentEnd => open = (open - 1).max(0),
+                _ => {}
+            }
+        }
+
+        let heads_right: i32 = lifelines
+            .iter()
+            .zip(widths.iter())
+            .map(|(lifeline, width)| lifeline - width / 2 + width)
+            .max()
+            .unwrap_or(0);
+        let dividers: i32 = self
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                SequenceEvent::Divider(label) => Some(text_width(label) + 4),
+                SequenceEvent::Delay(label) => Some(label_width(label)),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
+        let heads_height: i32 = self
+            .participants
+            .iter()
+            .map(|(_, head)| head.height() as i32)
+            .max()
+            .unwrap_or(0);
+        let rows: i32 = self.events.iter().map(|event| self.rows(event)).sum();
+
+        SequenceLayout {
+            lifelines,
+            heads_height,
+            depth,
+            inner_right,
+            width: heads_right
+                .max(inner_right + FRAGMENT_MARGIN * depth + 1)
+                .max(dividers),
+            // Lifelines go one row past the last event
+            height: match self.participants.is_empty() {
+                true => 0,
+                false => heads_height + rows + 1,
+            },
+        }
+    }
+
+    fn draw_message(
+        &self,
+        grid: &mut AsciiGrid,
+        layout: &SequenceLayout,
+        (from, to): (usize, usize),
+        message: &Message,
+        top: i32,
+    ) {
+        let line: char = match message.kind {
+            MessageKind::Return => '┄',
+            MessageKind::Sync | MessageKind::Async => '─',
+        };
+        let (right_head, left_head) = match message.kind {
+            MessageKind::Async => ('▷', '◁'),
+            MessageKind::Sync | MessageKind::Return => ('▶', '◀'),
+        };
+        let (start, end) = (layout.lifelines[from], layout.lifelines[to]);
+        let arrow: i32 = top + self.rows(&SequenceEvent::Message(message.clone())) - 1;
+        let x: i32 = self.position.x;
+
+        if from == to {
+            // ──┐ label
+            // ◀─┘
+            [(1, line), (2, line), (3, '┐')]
+                .iter()
+                .for_each(|(dx, ch)| put(grid, *ch, x + start + dx, arrow - 1));
+            [(1, left_head), (2, line), (3, '┘')]
+                .iter()
+                .for_each(|(dx, ch)| put(grid, *ch, x + start + dx, arrow));
+            draw_label(
+                grid,
+                &message.label,
+                Position::new(x + start + SELF_MESSAGE_WIDTH, arrow - 1),
+            );
+            return;
+        }
+
+        let direction: i32 = (end - start).signum();
+        let mut current: i32 = start + direction;
+        while current != end - direction {
+            put(grid, line, x + current, arrow);
+            current += direction;
+        }
+        put(
+            grid,
+            if direction > 0 { right_head } else { left_head },
+            x + end - direction,
+            arrow,
+        );
+
+        let label_top: i32 = arrow - label_height(&message.label);
+        draw_label(
+            grid,
+            &message.label,
+            Position::new(x + start.min(end) + 2, label_top),
+        );
+    }
+}
+
+impl AsciiElement for AsciiSequence {
+    fn draw(&mut self, grid: &mut AsciiGrid) {
+        let layout: SequenceLayout = self.layout();
+        let (x, mut y) = (self.position.x, self.position.y);
+
+        // Heads share their bottom row so the lifelines start together
+        for ((_, head), lifeline) in self.participants.iter_mut().zip(layout.lifelines.iter()) {
+            let position: Position = Position::new(
+                x + lifeline - head.width() as i32 / 2,
+                y + layout.heads_height - head.height() as i32,
+            );
+
+            head.r#move(position);
+            head.draw(grid);
+        }
+        y += layout.heads_height;
+
+        let indexes: HashMap<&str, usize> = self.indexes();
+
+        let mut activations: Vec<i32> = vec![0; self.participants.len()];
+        let mut alive: Vec<bool> = vec![true; self.participants.len()];
+        // Left and right borders of the open fragments, outermost first
+        let mut frames: Vec<(i32, i32)> = Vec::new();
+
+        for event in self.events.iter() {
+            let rows: i32 = self.rows(event);
+
+            for row in y..y + rows {
+                for (i, lifeline) in layout.lifelines.iter().enumerate() {
+                    let ch: char = match (event, activations[i] > 0) {
+                        (SequenceEvent::Delay(_), _) => '┆',
+                        (_, true) => '┃',
+                        (_, false) => '│',
+                    };
+
+                    if alive[i] {
+                        put(grid, ch, x + lifeline, row);
+                    }
+                }
+                for (left, right) in frames.iter() {
+                    put(grid, '│', x + left, row);
+                    put(grid, '│', x + right, row);
+                }
+            }
+
+            match event {
+                SequenceEvent::Message(message) => {
+                    if let Some(ends) = self.ends(&indexes, message) {
+                        self.draw_message(grid, &layout, ends, message, y);
+                    }
+                }
+                SequenceEvent::Activate(id) => {
+                    if let Some(&i) = indexes.get(id.as_str()) {
+                        activations[i] += 1;
+                    }
+                }
+                SequenceEvent::Deactivate(id) => {
+                    if let Some(&i) = indexes.get(id.as_str()) {
+                        activations[i] = (activations[i] - 1).max(0);
+                    }
+                }
+                SequenceEvent::Destroy(id) => {
+                    if let Some(&i) = indexes.get(id.as_str()) {
+                        put(grid, '╳', x + layout.lifelines[i], y);
+                        alive[i] = false;
+                    }
+                }
+                SequenceEvent::FragmentStart(kind, label) => {
+                    let margin: i32 = FRAGMENT_MARGIN * (layout.depth - frames.len() as i32);
+                    let first: i32 = layout.lifelines.first().copied().unwrap_or(margin);
+                    let (left, right) = (first - margin, layout.inner_right + margin);
+
+                    border(grid, ('┌', '─', '┐'), x + left, x + right, y);
+                    AsciiText::new(
+                        Position::new(x + left + 1, y),
+                        fragment_title(kind.keyword(), label),
+                    )
+                    .draw(grid);
+                    frames.push((left, right));
+                }
+                SequenceEvent::FragmentElse(label) => {
+                    if let Some((left, right)) = frames.last() {
+                        border(grid, ('├', '┄', '┤'), x + left, x + right, y);
+                        draw_label(
+                            grid,
+                            &label.as_ref().map(|l| format!(" [{}] ", l)),
+                            Position::new(x + left + 1, y),
+                        );
+                    }
+                }
+                SequenceEvent::FragmentEnd => {
+                    if let Some((left, right)) = frames.pop() {
+                        border(grid, ('└', '─', '┘'), x + left, x + right, y);
+                    }
+                }
+                SequenceEvent::Divider(label) => {
+                    (0..layout.width).for_each(|column| put(grid, '═', x + column, y));
+                    // An empty divider, "== ==", is a plain line
+                    if !label.is_empty() {
+                        let text: String = format!(" {} ", label);
+                        AsciiText::new(
+                            Position::new(x + (layout.width - text_width(&text)) / 2, y),
+                            text,
+                        )
+                        .draw(grid);
+                    }
+                }
+                SequenceEvent::Delay(label) => {
+                    let width: i32 = label_width(label);
+                    let start: i32 = x + (layout.width - width) / 2;
+
+                    // Lifelines behind the label are left out so they do not show through
+                    // its spaces
+                    (start..start + width).for_each(|column| put(grid, ' ', column, y));
+                    draw_label(grid, label, Position::new(start, y));
+                }
+            }
+
+            y += rows;
+        }
+
+        for (i, lifeline) in layout.lifelines.iter().enumerate() {
+            if alive[i] {
+                put(grid, '│', x + lifeline, y);
+            }
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position.clone()
+    }
+
+    fn width(&self) -> u16 {
+        self.layout().width as u16
+    }
+
+    fn height(&self) -> u16 {
+        self.layout().height as u16
+    }
+
+    fn r#move(&mut self, new_position: Position) {
+        self.position = new_position;
+    }
+}
+
+struct SequenceLayout {
+    // Column of the lifeline of every participant
+    lifelines: Vec<i32>,
+    heads_height: i32,
+    // How deep fragments are nested
+    depth: i32,
+    // Rightmost column drawn inside the innermost fragment
+    inner_right: i32,
+    width: i32,
+    height: i32,
+}
+
+// " alt [label] ", written over the top border of a fragment
+fn fragment_title(keyword: &str, label: &Option<String>) -> String {
+    match label {
+        Some(label) => format!(" {} [{}] ", keyword, label),
+        None => format!(" {} ", keyword),
+    }
+}
+
+fn border(
+    grid: &mut AsciiGrid,
+    (start, fill, end): (char, char, char),
+    left: i32,
+    right: i32,
+    y: i32,
+) {
+    put(grid, start, left, y);
+    (left + 1..right).for_each(|x| put(grid, fill, x, y));
+    put(grid, end, right, y);
+}
+
+fn draw_label(grid: &mut AsciiGrid, label: &Option<String>, position: Position) {
+    if let Some(label) = label {
+        AsciiText::new(position, label.clone()).draw(grid);
+    }
+}
+
+fn text_width(text: &str) -> i32 {
+    AsciiText::new(Position::default(), text.to_string()).width() as i32
+}
+
+fn label_width(label: &Option<String>) -> i32 {
+    label.as_deref().map_or(0, text_width)
+}
+
+fn label_height(label: &Option<String>) -> i32 {
+    label.as_ref().map_or(0, |label| {
+        AsciiText::new(Position::default(), label.clone()).height() as i32
+    })
+}
+
+fn put(grid: &mut AsciiGrid, ch: char, x: i32, y: i32) {
+    grid.put_char(ch, &Position::new(x, y));
+}
+
+#[cfg(test)]
+mod test {
+    use lib_core::domain::entities::sequence::FragmentKind;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn text(content: &str) -> Box<dyn AsciiElement> {
+        Box::new(AsciiText::new(Position::default(), content.to_string()))
+    }
+
+    fn message(from: &str, to: &str, kind: MessageKind, label: Option<&str>) -> SequenceEvent {
+        SequenceEvent::Message(Message::new(
+            from.to_string(),
+            to.to_string(),
+            kind,
+            label.map(String::from),
+        ))
+    }
+
+    #[test]
+    fn messages_between_lifelines() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut sequence: AsciiSequence = AsciiSequence::new(Position::default())
+            .with_participant("a".to_string(), text("A"))
+            .with_participant("b".to_string(), text("B"))
+            .with_event(message("a", "b", MessageKind::Sync, Some("hi")))
+            .with_event(SequenceEvent::Activate("b".to_string()))
+            .with_event(message("b", "b", MessageKind::Async, None))
+            .with_event(SequenceEvent::Deactivate("b".to_string()))
+            .with_event(message("b", "a", MessageKind::Return, None));
+
+        sequence.draw(&mut grid);
+
+        assert_eq!(sequence.height(), 8);
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['A', ' ', ' ', ' ', ' ', ' ', 'B'],
+                    vec!['│', ' ', 'h', 'i', ' ', ' ', '│'],
+                    vec!['│', '─', '─', '─', '─', '▶', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', '┃', '─', '─', '┐'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', '┃', '◁', '─', '┘'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', '◀', '┄', '┄', '┄', '┄', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', '│'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn delays_hide_the_lifelines_behind_their_label() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut sequence: AsciiSequence = AsciiSequence::new(Position::default())
+            .with_participant("a".to_string(), text("A"))
+            .with_participant("b".to_string(), text("B"))
+            .with_participant("c".to_string(), text("C"))
+            .with_event(message("a", "c", MessageKind::Sync, Some("hello")))
+            .with_event(SequenceEvent::Delay(Some("one day".to_string())));
+
+        sequence.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['A', ' ', ' ', ' ', 'B', ' ', ' ', ' ', ' ', 'C'],
+                    vec!['│', ' ', 'h', 'e', 'l', 'l', 'o', ' ', ' ', '│'],
+                    vec!['│', '─', '─', '─', '─', '─', '─', '─', '▶', '│'],
+                    vec!['┆', 'o', 'n', 'e', ' ', 'd', 'a', 'y', ' ', '┆'],
+                    vec!['│', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', '│'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn fragments_and_dividers() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut sequence: AsciiSequence = AsciiSequence::new(Position::default())
+            .with_participant("a".to_string(), text("A"))
+            .with_participant("b".to_string(), text("B"))
+            .with_event(SequenceEvent::FragmentStart(FragmentKind::Alt, None))
+            .with_event(message("a", "b", MessageKind::Sync, None))
+            .with_event(SequenceEvent::FragmentElse(None))
+            .with_event(SequenceEvent::FragmentEnd)
+            .with_event(SequenceEvent::Divider("x".to_string()));
+
+        sequence.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![' ', ' ', 'A', ' ', ' ', ' ', 'B'],
+                    vec!['┌', ' ', 'a', 'l', 't', ' ', '─', '─', '┐'],
+                    vec!['│', ' ', '│', ' ', ' ', ' ', '│', ' ', '│'],
+                    vec!['│', ' ', '│', '─', '─', '▶', '│', ' ', '│'],
+                    vec!['├', '┄', '┄', '┄', '┄', '┄', '┄', '┄', '┤'],
+                    vec!['└', '─', '─', '─', '─', '─', '─', '─', '┘'],
+                    vec!['═', '═', '═', ' ', 'x', ' ', '═', '═', '═'],
+                    vec![' ', ' ', '│', ' ', ' ', ' ', '│'],
+                ]
+            )
+            .to_string()
+        )
+    }
+}
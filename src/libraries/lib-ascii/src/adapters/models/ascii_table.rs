@@ -0,0 +1,190 @@
+use crate::adapters::models::{
+    ascii_element::AsciiElement, ascii_grid::AsciiGrid, ascii_text::AsciiText, position::Position,
+};
+
+/// A box with a centered title above a two-column table, e.g., the fields of an object
+/// and their values. The value column grows when the title is wider than the table.
+pub(crate) struct AsciiTable {
+    title: Box<dyn AsciiElement>,
+    rows: Vec<(AsciiText, AsciiText)>,
+    position: Position,
+}
+
+impl AsciiTable {
+    pub(crate) fn new(position: Position, title: Box<dyn AsciiElement>) -> Self {
+        Self {
+            title,
+            rows: Vec::new(),
+            position,
+        }
+    }
+
+    pub(crate) fn with_row(mut self, key: String, value: String) -> Self {
+        self.rows.push((
+            AsciiText::new(Position::default(), key),
+            AsciiText::new(Position::default(), value),
+        ));
+        self
+    }
+
+    fn key_width(&self) -> u16 {
+        self.rows
+            .iter()
+            .map(|(key, _)| key.width())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn value_width(&self) -> u16 {
+        self.rows
+            .iter()
+            .map(|(_, value)| value.width())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl AsciiElement for AsciiTable {
+    fn draw(&mut self, grid: &mut AsciiGrid) {
+        let Position { x: left, y: top } = self.position();
+        let width: u16 = self.width();
+        let right: i32 = left + width as i32 - 1;
+        let bottom: i32 = top + self.height() as i32 - 1;
+        // Column between the keys and the values, absent without rows
+        let divider: Option<i32> =
+            (!self.rows.is_empty()).then(|| left + self.key_width() as i32 + 3);
+        let rule = |grid: &mut AsciiGrid, y: i32, ends: [char; 3]| {
+            grid.put_char(ends[0], &Position::new(left, y));
+            for x in (left + 1)..right {
+                let c: char = match Some(x) == divider {
+                    true => ends[1],
+                    false => '─',
+                };
+                grid.put_char(c, &Position::new(x, y));
+            }
+            grid.put_char(ends[2], &Position::new(right, y));
+        };
+
+        rule(grid, top, ['┌', '─', '┐']);
+        for y in (top + 1)..bottom {
+            grid.put_char('│', &Position::new(left, y));
+            for x in (left + 1)..right {
+                grid.put_char(' ', &Position::new(x, y));
+            }
+            grid.put_char('│', &Position::new(right, y));
+        }
+        rule(grid, bottom, ['└', '┴', '┘']);
+
+        let title_offset: u16 = (width - 2 - self.title.width()) / 2;
+        self.title
+            .r#move(Position::new(left + 1 + title_offset as i32, top + 1));
+        self.title.draw(grid);
+
+        let Some(divider) = divider else {
+            return;
+        };
+
+        let mut y: i32 = top + 1 + self.title.height() as i32;
+        rule(grid, y, ['├', '┬', '┤']);
+
+        for (key, value) in self.rows.iter_mut() {
+            y += 1;
+
+            key.r#move(Position::new(left + 2, y));
+            key.draw(grid);
+            grid.put_char('│', &Position::new(divider, y));
+            value.r#move(Position::new(divider + 2, y));
+            value.draw(grid);
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position.clone()
+    }
+
+    // │ key │ value │
+    fn width(&self) -> u16 {
+        let columns: u16 = match self.rows.is_empty() {
+            true => 0,
+            false => self.key_width() + self.value_width() + 5,
+        };
+
+        columns.max(self.title.width() + 2) + 2
+    }
+
+    fn height(&self) -> u16 {
+        let rows: u16 = match self.rows.is_empty() {
+            true => 0,
+            false => self.rows.len() as u16 + 1,
+        };
+
+        self.title.height() + rows + 2
+    }
+
+    fn r#move(&mut self, new_position: Position) {
+        self.position = new_position;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn title(content: &str) -> Box<dyn AsciiElement> {
+        Box::new(AsciiText::new(Position::default(), content.to_string()))
+    }
+
+    #[test]
+    fn rows_split_into_keys_and_values() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut table: AsciiTable = AsciiTable::new(Position::default(), title("host"))
+            .with_row("id".to_string(), "7".to_string())
+            .with_row("os".to_string(), "bsd".to_string());
+
+        table.draw(&mut grid);
+
+        assert_eq!(table.width(), 12);
+        assert_eq!(table.height(), 6);
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['┌', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┐'],
+                    vec!['│', ' ', ' ', ' ', 'h', 'o', 's', 't', ' ', ' ', ' ', '│'],
+                    vec!['├', '─', '─', '─', '─', '┬', '─', '─', '─', '─', '─', '┤'],
+                    vec!['│', ' ', 'i', 'd', ' ', '│', ' ', '7', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', 'o', 's', ' ', '│', ' ', 'b', 's', 'd', ' ', '│'],
+                    vec!['└', '─', '─', '─', '─', '┴', '─', '─', '─', '─', '─', '┘'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn value_column_grows_to_fit_the_title() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut table: AsciiTable = AsciiTable::new(Position::default(), title("settings"))
+            .with_row("a".to_string(), "1".to_string());
+
+        table.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['┌', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┐'],
+                    vec!['│', ' ', 's', 'e', 't', 't', 'i', 'n', 'g', 's', ' ', '│'],
+                    vec!['├', '─', '─', '─', '┬', '─', '─', '─', '─', '─', '─', '┤'],
+                    vec!['│', ' ', 'a', ' ', '│', ' ', '1', ' ', ' ', ' ', ' ', '│'],
+                    vec!['└', '─', '─', '─', '┴', '─', '─', '─', '─', '─', '─', '┘'],
+                ]
+            )
+            .to_string()
+        )
+    }
+}
@@ -0,0 +1,479 @@
+use lib_core::domain::entities::timing::{Clock, TimeConstraint, TimingKind, TimingParticipant};
+
+use crate::adapters::models::{
+    ascii_element::AsciiElement, ascii_grid::AsciiGrid, ascii_text::AsciiText, position::Position,
+};
+
+const MAX_CLOCK_PERIODS: usize = 100;
+
+/// Draws a timing diagram as waveforms, one row per participant with time going from left
+/// to right. Each time a state changes at gets a column, wide enough for the states starting
+/// there, so the scale is not linear. Concise participants are drawn as a band holding
+/// their states, the others as a line going up and down between their levels, e.g.,
+///
+/// ```text
+/// WB Busy     ┌───────┐
+///    Idle ────┘       └───
+/// ```
+///
+/// Constraints are drawn as arrows below their participant, e.g., "<──────> 50 ms".
+/// Clocks are drawn over `MAX_CLOCK_PERIODS` periods at most, each edge taking a column.
+pub(crate) struct AsciiTiming {
+    participants: Vec<TimingParticipant>,
+    constraints: Vec<TimeConstraint>,
+    position: Position,
+}
+
+impl AsciiTiming {
+    pub(crate) fn new(position: Position) -> Self {
+        Self {
+            participants: Vec::new(),
+            constraints: Vec::new(),
+            position,
+        }
+    }
+
+    pub(crate) fn with_participant(mut self, participant: TimingParticipant) -> Self {
+        self.participants.push(participant);
+        self
+    }
+
+    pub(crate) fn with_constraint(mut self, constraint: TimeConstraint) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    // Every time something happens at, clock edges included, from 0
+    fn times(&self) -> Vec<u32> {
+        let mut times: Vec<u32> = vec![0];
+        times.extend(
+            self.participants
+                .iter()
+                .flat_map(|p| p.changes.iter().map(|change| change.time)),
+        );
+        times.extend(self.constraints.iter().flat_map(|c| [c.start, c.end]));
+
+        // Clocks run until the last change, or for two periods when nothing else happens
+        let clocks: Vec<&Clock> = self.clocks().collect();
+        let end: u32 = match times.iter().max() {
+            Some(end) if *end > 0 => *end,
+            _ => clocks
+                .iter()
+                .map(|clock| clock.offset.saturating_add(clock.period.saturating_mul(2)))
+                .max()
+                .unwrap_or(0),
+        };
+
+        for clock in clocks.iter().filter(|clock| clock.period > 0) {
+            let rises =
+                std::iter::successors(Some(clock.offset), |rise| rise.checked_add(clock.period))
+                    .take_while(|rise| *rise <= end)
+                    .take(MAX_CLOCK_PERIODS);
+
+            for rise in rises {
+                times.push(rise);
+                if let Some(fall) = rise.checked_add(clock.pulse).filter(|fall| *fall <= end) {
+                    times.push(fall);
+                }
+            }
+        }
+
+        times.sort();
+        times.dedup();
+        times
+    }
+
+    fn clocks(&self) -> impl Iterator<Item = &Clock> {
+        self.participants.iter().filter_map(|p| match &p.kind {
+            TimingKind::Clock(clock) => Some(clock),
+            _ => None,
+        })
+    }
+
+    fn lines(&self) -> Vec<String> {
+        let times: Vec<u32> = self.times();
+        let name_width: usize = self
+            .participants
+            .iter()
+            .map(|p| p.name.chars().count() + 1)
+            .max()
+            .unwrap_or(0);
+        let label_width: usize = self
+            .participants
+            .iter()
+            .filter(|p| p.kind == TimingKind::Robust)
+            .flat_map(|p| p.changes.iter().map(|c| c.state.chars().count() + 1))
+            .max()
+            .unwrap_or(0);
+        // Where each time is drawn, then where the last column ends
+        let mut columns: Vec<usize> = Vec::new();
+        let mut x: usize = name_width + label_width;
+        for time in times.iter() {
+            columns.push(x);
+            x += self.column_width(*time);
+        }
+        let column = |time: u32| {
+            times
+                .iter()
+                .position(|t| *t == time)
+                .map_or(x, |index| columns[index])
+        };
+
+        let mut canvas: Canvas = Canvas::default();
+        let mut y: usize = 0;
+
+        for (index, participant) in self.participants.iter().enumerate() {
+            if index > 0 {
+                y += 1;
+            }
+
+            // The state at each time, from the first change on
+            let states: Vec<Option<&str>> = times
+                .iter()
+                .map(|time| state_at(participant, *time))
+                .collect();
+            let changes: Vec<bool> = times
+                .iter()
+                .map(|time| participant.changes.iter().any(|c| c.time == *time))
+                .collect();
+
+            let height: usize = match &participant.kind {
+                TimingKind::Concise => {
+                    canvas.text(0, y + 1, &participant.name);
+                    draw_band(&mut canvas, y, &columns, x, &states, &changes);
+                    3
+                }
+                kind => {
+                    let levels: Vec<String> = levels(participant);
+                    let height: usize = levels.len().max(1);
+                    let level_at = |index: usize| -> Option<usize> {
+                        match kind {
+                            TimingKind::Clock(clock) => Some(clock_level(clock, times[index])),
+                            _ => states[index].and_then(|s| levels.iter().position(|l| l == s)),
+                        }
+                    };
+
+                    canvas.text(0, y, &participant.name);
+                    if *kind == TimingKind::Robust {
+                        for (level, label) in levels.iter().enumerate() {
+                            canvas.text(name_width, y + height - 1 - level, label);
+                        }
+                    }
+
+                    let levels: Vec<Option<usize>> = (0..times.len()).map(level_at).collect();
+                    draw_levels(&mut canvas, y, height, &columns, x, &levels);
+                    height
+                }
+            };
+            y += height;
+
+            for constraint in self
+                .constraints
+                .iter()
+                .filter(|c| c.participant_id == participant.id)
+            {
+                let (start, end) = (column(constraint.start), column(constraint.end));
+
+                canvas.put(start, y, '<');
+                for x in (start + 1)..end {
+                    canvas.put(x, y, '─');
+                }
+                canvas.put(end, y, '>');
+                if let Some(label) = &constraint.label {
+                    canvas.text(end + 2, y, label);
+                }
+                y += 1;
+            }
+        }
+
+        // The axis, e.g., "┬────┬──────" above "0    100"
+        if !self.participants.is_empty() {
+            y += 1;
+            for x in (name_width + label_width)..x {
+                canvas.put(x, y, '─');
+            }
+            for (time, x) in times.iter().zip(columns.iter()) {
+                canvas.put(*x, y, '┬');
+                canvas.text(*x, y + 1, &time.to_string());
+            }
+        }
+
+        canvas.lines()
+    }
+
+    // Room for the time below the axis and for the states of concise participants
+    fn column_width(&self, time: u32) -> usize {
+        let states: usize = self
+            .participants
+            .iter()
+            .filter(|p| p.kind == TimingKind::Concise)
+            .flat_map(|p| p.changes.iter().filter(|c| c.time == time))
+            .map(|c| c.state.chars().count() + 3)
+            .max()
+            .unwrap_or(0);
+
+        states.max(time.to_string().len() + 1).max(4)
+    }
+}
+
+impl AsciiElement for AsciiTiming {
+    fn draw(&mut self, grid: &mut AsciiGrid) {
+        let Position { x, y } = self.position();
+
+        for (index, line) in self.lines().into_iter().enumerate() {
+            AsciiText::new(Position::new(x, y + index as i32), line).draw(grid);
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position.clone()
+    }
+
+    fn width(&self) -> u16 {
+        self.lines()
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0) as u16
+    }
+
+    fn height(&self) -> u16 {
+        self.lines().len() as u16
+    }
+
+    fn r#move(&mut self, new_position: Position) {
+        self.position = new_position;
+    }
+}
+
+// Characters drawn so far, rows growing as needed
+#[derive(Default)]
+struct Canvas {
+    rows: Vec<Vec<char>>,
+}
+
+impl Canvas {
+    fn put(&mut self, x: usize, y: usize, c: char) {
+        if self.rows.len() <= y {
+            self.rows.resize(y + 1, Vec::new());
+        }
+        if self.rows[y].len() <= x {
+            self.rows[y].resize(x + 1, ' ');
+        }
+        self.rows[y][x] = c;
+    }
+
+    fn text(&mut self, x: usize, y: usize, text: &str) {
+        for (offset, c) in text.chars().enumerate() {
+            self.put(x + offset, y, c);
+        }
+    }
+
+    fn lines(self) -> Vec<String> {
+        self.rows.into_iter().map(String::from_iter).collect()
+    }
+}
+
+fn state_at(participant: &TimingParticipant, time: u32) -> Option<&str> {
+    participant
+        .changes
+        .iter()
+        .rfind(|change| change.time <= time)
+        .map(|change| change.state.as_str())
+}
+
+// From the lowest one: binary participants are either low or high, robust ones have
+// a level for each of their states, the first one given being the lowest
+fn levels(participant: &TimingParticipant) -> Vec<String> {
+    match participant.kind {
+        TimingKind::Robust => {
+            let mut levels: Vec<String> = Vec::new();
+            for change in participant.changes.iter() {
+                if !levels.contains(&change.state) {
+                    levels.push(change.state.clone());
+                }
+            }
+            levels
+        }
+        _ => vec!["low".to_string(), "high".to_string()],
+    }
+}
+
+fn clock_level(clock: &Clock, time: u32) -> usize {
+    match time >= clock.offset && clock.period > 0 {
+        true => ((time - clock.offset) % clock.period < clock.pulse) as usize,
+        false => 0,
+    }
+}
+
+// A line at the level of each column, going up or down where it changes
+fn draw_levels(
+    canvas: &mut Canvas,
+    top: usize,
+    height: usize,
+    columns: &[usize],
+    end: usize,
+    levels: &[Option<usize>],
+) {
+    let y = |level: usize| top + height - 1 - level;
+
+    for (index, level) in levels.iter().enumerate() {
+        let Some(level) = *level else {
+            continue;
+        };
+        let x: usize = columns[index];
+        let next: usize = columns.get(index + 1).copied().unwrap_or(end);
+
+        match index.checked_sub(1).and_then(|previous| levels[previous]) {
+            Some(previous) if previous < level => {
+                canvas.put(x, y(previous), '┘');
+                for between in (y(level) + 1)..y(previous) {
+                    canvas.put(x, between, '│');
+                }
+                canvas.put(x, y(level), '┌');
+            }
+            Some(previous) if previous > level => {
+                canvas.put(x, y(previous), '┐');
+                for between in (y(previous) + 1)..y(level) {
+                    canvas.put(x, between, '│');
+                }
+                canvas.put(x, y(level), '└');
+            }
+            _ => canvas.put(x, y(level), '─'),
+        }
+
+        for x in (x + 1)..next {
+            canvas.put(x, y(level), '─');
+        }
+    }
+}
+
+// A band split where the state changes, with the state written in it
+fn draw_band(
+    canvas: &mut Canvas,
+    top: usize,
+    columns: &[usize],
+    end: usize,
+    states: &[Option<&str>],
+    changes: &[bool],
+) {
+    for (index, state) in states.iter().enumerate() {
+        let Some(state) = state else {
+            continue;
+        };
+        let x: usize = columns[index];
+        let next: usize = columns.get(index + 1).copied().unwrap_or(end);
+        let first: bool = index == 0 || states[index - 1].is_none();
+
+        let edges: [char; 3] = match (first, changes[index]) {
+            (true, _) => ['┌', '│', '└'],
+            (false, true) => ['┬', '│', '┴'],
+            (false, false) => ['─', ' ', '─'],
+        };
+        canvas.put(x, top, edges[0]);
+        canvas.put(x, top + 1, edges[1]);
+        canvas.put(x, top + 2, edges[2]);
+        for x in (x + 1)..next {
+            canvas.put(x, top, '─');
+            canvas.put(x, top + 2, '─');
+        }
+
+        if first || changes[index] {
+            canvas.text(x + 2, top + 1, state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use lib_core::domain::entities::timing::StateChange;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn participant(name: &str, kind: TimingKind, changes: &[(u32, &str)]) -> TimingParticipant {
+        TimingParticipant {
+            changes: changes
+                .iter()
+                .map(|(time, state)| StateChange::new(*time, state.to_string()))
+                .collect(),
+            ..TimingParticipant::new(name.to_string(), name.to_string(), kind)
+        }
+    }
+
+    #[test]
+    fn robust_states_each_have_a_level() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut timing: AsciiTiming = AsciiTiming::new(Position::default())
+            .with_participant(participant(
+                "WB",
+                TimingKind::Robust,
+                &[(0, "Idle"), (10, "Busy"), (20, "Idle")],
+            ))
+            .with_constraint(TimeConstraint::new(
+                "WB".to_string(),
+                10,
+                20,
+                Some("lag".to_string()),
+            ));
+
+        timing.draw(&mut grid);
+
+        assert_eq!(timing.height(), 6);
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![
+                        'W', 'B', ' ', 'B', 'u', 's', 'y', ' ', ' ', ' ', ' ', ' ', '┌', '─', '─',
+                        '─', '┐'
+                    ],
+                    vec![
+                        ' ', ' ', ' ', 'I', 'd', 'l', 'e', ' ', '─', '─', '─', '─', '┘', ' ', ' ',
+                        ' ', '└', '─', '─', '─'
+                    ],
+                    vec![
+                        ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '<', '─', '─',
+                        '─', '>', ' ', 'l', 'a', 'g'
+                    ],
+                    vec![],
+                    vec![
+                        ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '┬', '─', '─', '─', '┬', '─', '─',
+                        '─', '┬', '─', '─', '─'
+                    ],
+                    vec![
+                        ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '0', ' ', ' ', ' ', '1', '0', ' ',
+                        ' ', '2', '0'
+                    ],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn clocks_with_a_huge_period_stop_at_the_last_time() {
+        let timing: AsciiTiming = AsciiTiming::new(Position::default()).with_participant(
+            participant("clk", TimingKind::Clock(Clock::new(4_000_000_000)), &[]),
+        );
+
+        assert_eq!(timing.times(), vec![0, 2_000_000_000, 4_000_000_000]);
+    }
+
+    #[test]
+    fn clocks_are_drawn_over_a_few_periods_before_a_far_away_change() {
+        let timing: AsciiTiming = AsciiTiming::new(Position::default())
+            .with_participant(participant("clk", TimingKind::Clock(Clock::new(2)), &[]))
+            .with_participant(participant(
+                "WB",
+                TimingKind::Robust,
+                &[(4_000_000_000, "Idle")],
+            ));
+        let times: Vec<u32> = timing.times();
+
+        assert_eq!(times.len(), 2 * MAX_CLOCK_PERIODS + 1);
+        assert_eq!(times.last(), Some(&4_000_000_000));
+        assert_eq!(timing.height(), 7);
+    }
+}
@@ -0,0 +1,240 @@
+use lib_core::domain::entities::tree::{TreeKind, TreeNode, TreeSide};
+
+use crate::adapters::models::{
+    ascii_element::AsciiElement, ascii_grid::AsciiGrid, ascii_text::AsciiText, position::Position,
+};
+
+/// Draws a mind map or a work breakdown structure as an indented tree, each node below its
+/// parent behind "├──" or "└──". The root of a mind map branches off on both sides: its right
+/// children hang from under its last character and its left ones, drawn mirrored, e.g.,
+/// "Docs ──┤", from under its first character. Several roots are drawn one below the other.
+pub(crate) struct AsciiTree {
+    kind: TreeKind,
+    roots: Vec<TreeNode>,
+    position: Position,
+}
+
+impl AsciiTree {
+    pub(crate) fn new(position: Position, kind: TreeKind) -> Self {
+        Self {
+            kind,
+            roots: Vec::new(),
+            position,
+        }
+    }
+
+    pub(crate) fn with_root(mut self, root: TreeNode) -> Self {
+        self.roots.push(root);
+        self
+    }
+
+    // Each row of text with where it starts, relative to the first character of its root
+    fn lines(&self) -> Vec<(i32, i32, String)> {
+        let mut lines: Vec<(i32, i32, String)> = Vec::new();
+        let mut y: i32 = 0;
+
+        for (index, root) in self.roots.iter().enumerate() {
+            if index > 0 {
+                y += 1;
+            }
+
+            let root_width: i32 = root.text.split('\n').map(text_width).max().unwrap_or(0);
+            for line in root.text.split('\n') {
+                lines.push((0, y, line.to_string()));
+                y += 1;
+            }
+
+            let (left, right): (Vec<&TreeNode>, Vec<&TreeNode>) = match self.kind {
+                TreeKind::MindMap => root
+                    .children
+                    .iter()
+                    .partition(|child| child.side == TreeSide::Left),
+                TreeKind::Wbs => (Vec::new(), root.children.iter().collect()),
+            };
+            // Sides are kept a column apart under a short root
+            let trunk: i32 = match left.is_empty() {
+                true => 0,
+                false => (root_width - 1).max(2),
+            };
+            let right: Vec<(String, String)> = rows(&right, "");
+            let left: Vec<(String, String)> = rows(&left, "");
+
+            for (row, (connectors, text)) in right.iter().enumerate() {
+                lines.push((trunk, y + row as i32, format!("{}{}", connectors, text)));
+            }
+            for (row, (connectors, text)) in left.iter().enumerate() {
+                let line: String = format!("{}{}", text, mirrored(connectors));
+
+                lines.push((1 - text_width(&line), y + row as i32, line));
+            }
+
+            y += right.len().max(left.len()) as i32;
+        }
+
+        lines
+    }
+}
+
+impl AsciiElement for AsciiTree {
+    fn draw(&mut self, grid: &mut AsciiGrid) {
+        let Position { x, y } = self.position();
+        let lines: Vec<(i32, i32, String)> = self.lines();
+        let left: i32 = lines.iter().map(|(x, _, _)| *x).min().unwrap_or(0);
+
+        for (line_x, line_y, line) in lines {
+            AsciiText::new(Position::new(x + line_x - left, y + line_y), line).draw(grid);
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position.clone()
+    }
+
+    fn width(&self) -> u16 {
+        let lines: Vec<(i32, i32, String)> = self.lines();
+        let left: i32 = lines.iter().map(|(x, _, _)| *x).min().unwrap_or(0);
+        let right: i32 = lines
+            .iter()
+            .map(|(x, _, line)| x + text_width(line))
+            .max()
+            .unwrap_or(0);
+
+        (right - left) as u16
+    }
+
+    fn height(&self) -> u16 {
+        self.lines()
+            .iter()
+            .map(|(_, y, _)| y + 1)
+            .max()
+            .unwrap_or(0) as u16
+    }
+
+    fn r#move(&mut self, new_position: Position) {
+        self.position = new_position;
+    }
+}
+
+// Connectors and text of each row below a node, e.g., ("│   └── ", "UI").
+// The lines of a multi-line text after the first one are lined up with it.
+fn rows(children: &[&TreeNode], prefix: &str) -> Vec<(String, String)> {
+    let mut rows: Vec<(String, String)> = Vec::new();
+
+    for (index, child) in children.iter().enumerate() {
+        let (connector, continuation) = match index + 1 == children.len() {
+            true => ("└── ", "    "),
+            false => ("├── ", "│   "),
+        };
+        let nested: String = format!("{}{}", prefix, continuation);
+
+        for (line_index, line) in child.text.split('\n').enumerate() {
+            let connectors: String = match line_index {
+                0 => format!("{}{}", prefix, connector),
+                _ => nested.clone(),
+            };
+
+            rows.push((connectors, line.to_string()));
+        }
+
+        let grandchildren: Vec<&TreeNode> = child.children.iter().collect();
+        rows.extend(self::rows(&grandchildren, &nested));
+    }
+
+    rows
+}
+
+// "├── " becomes " ──┤" for a node on the left side
+fn mirrored(connectors: &str) -> String {
+    connectors
+        .chars()
+        .rev()
+        .map(|c| match c {
+            '├' => '┤',
+            '└' => '┘',
+            c => c,
+        })
+        .collect()
+}
+
+fn text_width(text: &str) -> i32 {
+    AsciiText::new(Position::default(), text.to_string()).width() as i32
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn node(text: &str, children: Vec<TreeNode>) -> TreeNode {
+        TreeNode::new(text.to_string(), children)
+    }
+
+    fn left(text: &str, children: Vec<TreeNode>) -> TreeNode {
+        TreeNode {
+            side: TreeSide::Left,
+            ..node(text, children)
+        }
+    }
+
+    #[test]
+    fn children_are_indented_below_their_parent() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut tree: AsciiTree =
+            AsciiTree::new(Position::default(), TreeKind::Wbs).with_root(node(
+                "App",
+                vec![
+                    node("Design", vec![node("UI", vec![])]),
+                    node("Build", vec![]),
+                ],
+            ));
+
+        tree.draw(&mut grid);
+
+        assert_eq!(tree.width(), 10);
+        assert_eq!(tree.height(), 4);
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec!['A', 'p', 'p'],
+                    vec!['├', '─', '─', ' ', 'D', 'e', 's', 'i', 'g', 'n'],
+                    vec!['│', ' ', ' ', ' ', '└', '─', '─', ' ', 'U', 'I'],
+                    vec!['└', '─', '─', ' ', 'B', 'u', 'i', 'l', 'd'],
+                ]
+            )
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn mind_map_branches_off_on_both_sides() {
+        let mut grid: AsciiGrid = AsciiGrid::empty(' ');
+        let mut tree: AsciiTree =
+            AsciiTree::new(Position::default(), TreeKind::MindMap).with_root(node(
+                "Home",
+                vec![node("Run", vec![]), left("Doc", vec![left("FAQ", vec![])])],
+            ));
+
+        tree.draw(&mut grid);
+
+        assert_eq!(
+            grid.to_string(),
+            AsciiGrid::new(
+                ' ',
+                vec![
+                    vec![
+                        ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', 'H', 'o', 'm', 'e'
+                    ],
+                    vec![
+                        ' ', ' ', ' ', ' ', 'D', 'o', 'c', ' ', '─', '─', '┘', ' ', ' ', '└', '─',
+                        '─', ' ', 'R', 'u', 'n'
+                    ],
+                    vec!['F', 'A', 'Q', ' ', '─', '─', '┘', ' ', ' ', ' ', ' '],
+                ]
+            )
+            .to_string()
+        )
+    }
+}
@@ -1,10 +1,20 @@
-use lib_core::domain::entities::diagram::{Diagram, Node, NodeType};
+use lib_core::domain::entities::{
+    class::{Classifier, ClassifierKind, Member},
+    diagram::{Diagram, DiagramKind, Edge, EdgeStyle, Group, GroupType, Node, NodeType, Note},
+    object::Object,
+    sequence::{Participant, ParticipantType},
+    state::{State, StateKind},
+};
 
 use crate::adapters::{
     models::{
-        ascii_border_decorator::AsciiBorderDecorator, ascii_composite::AsciiComposite,
-        ascii_element::AsciiElement, ascii_grid::AsciiGrid, ascii_text::AsciiText,
-        position::Position,
+        ascii_activity::AsciiActivity, ascii_border::AsciiBorder,
+        ascii_border_decorator::AsciiBorderDecorator, ascii_column::AsciiColumn,
+        ascii_compartments::AsciiCompartments, ascii_data::AsciiData, ascii_element::AsciiElement,
+        ascii_gantt::AsciiGantt, ascii_graph::AsciiGraph, ascii_grid::AsciiGrid,
+        ascii_icon_decorator::AsciiIconDecorator, ascii_note_decorator::AsciiNoteDecorator,
+        ascii_sequence::AsciiSequence, ascii_table::AsciiTable, ascii_text::AsciiText,
+        ascii_timing::AsciiTiming, ascii_tree::AsciiTree, position::Position,
     },
     view_models::ascii_grid_view_model::AsciiGridViewModel,
 };
@@ -29,26 +39,513 @@ impl AsciiPresenter {
 
 impl From<&Diagram> for Box<dyn AsciiElement> {
     fn from(value: &Diagram) -> Self {
-        Box::new(AsciiComposite::new(
-            value
-                .nodes
-                .iter()
-                .map(Box::<dyn AsciiElement>::from)
-                .collect(),
+        let body: Box<dyn AsciiElement> = match &value.kind {
+            DiagramKind::Graph => graph(value, None),
+            DiagramKind::Sequence(sequence) => Box::new(
+                sequence.events.iter().fold(
+                    sequence
+                        .participants
+                        .iter()
+                        .fold(AsciiSequence::new(Position::default()), |s, p| {
+                            s.with_participant(p.id.clone(), Box::<dyn AsciiElement>::from(p))
+                        }),
+                    |s, event| s.with_event(event.clone()),
+                ),
+            ),
+            DiagramKind::Activity(activity) => Box::new(
+                activity
+                    .steps
+                    .iter()
+                    .fold(AsciiActivity::new(Position::default()), |a, step| {
+                        a.with_step(step.clone())
+                    }),
+            ),
+            DiagramKind::Tree(tree) => Box::new(tree.roots.iter().fold(
+                AsciiTree::new(Position::default(), tree.kind.clone()),
+                |t, root| t.with_root(root.clone()),
+            )),
+            DiagramKind::Data(data) => {
+                Box::new(AsciiData::new(Position::default(), data.root.clone()))
+            }
+            DiagramKind::Timing(timing) => Box::new(
+                timing.constraints.iter().fold(
+                    timing
+                        .participants
+                        .iter()
+                        .fold(AsciiTiming::new(Position::default()), |t, p| {
+                            t.with_participant(p.clone())
+                        }),
+                    |t, constraint| t.with_constraint(constraint.clone()),
+                ),
+            ),
+            DiagramKind::Gantt(gantt) => {
+                Box::new(AsciiGantt::new(Position::default(), gantt.clone()))
+            }
+        };
+        let legend: Box<dyn AsciiElement> = match &value.legend {
+            Some(legend) => Box::new(
+                AsciiBorderDecorator::new(text(Some(legend)), Position::default(), 1, 0, ' ')
+                    .with_border(AsciiBorder::square()),
+            ),
+            None => text(None),
+        };
+
+        // Missing parts are empty and take no room in the column
+        Box::new(AsciiColumn::new(
+            vec![
+                text(value.header.as_ref()),
+                text(value.title.as_ref()),
+                body,
+                text(value.caption.as_ref()),
+                legend,
+                text(value.footer.as_ref()),
+            ],
+            Position::default(),
+            1,
         ))
     }
 }
 
+// Floating notes are laid out along with the nodes so edges may reach them.
+// Composite states and groups lay out their members in a graph of their own, drawn inside
+// their box, so the graph of `parent_id` only holds the nodes and groups directly inside it.
+fn graph(diagram: &Diagram, parent_id: Option<&str>) -> Box<dyn AsciiElement> {
+    let graph: AsciiGraph = diagram
+        .nodes
+        .iter()
+        .filter(|node| enclosing(diagram, node.parent_id.as_deref()) == parent_id)
+        .fold(
+            AsciiGraph::new(diagram.direction.clone(), Position::default()),
+            |graph, node| {
+                node.ports.iter().fold(
+                    graph.with_node(
+                        node.id.clone(),
+                        with_notes(node_element(diagram, node), &node.id, &diagram.notes),
+                    ),
+                    |graph, port| graph.with_port(node.id.clone(), port.clone()),
+                )
+            },
+        );
+    let graph: AsciiGraph = diagram
+        .groups
+        .iter()
+        .filter(|group| enclosing(diagram, group.parent_id.as_deref()) == parent_id)
+        .fold(graph, |graph, group| {
+            graph.with_node(
+                group.id.clone(),
+                with_notes(group_frame(diagram, group), &group.id, &diagram.notes),
+            )
+        });
+    let graph: AsciiGraph = floating_notes(diagram)
+        .filter(|_| parent_id.is_none())
+        .fold(graph, |graph, n| {
+            graph.with_node(n.id.clone().unwrap_or_default(), note(n))
+        });
+
+    Box::new(
+        diagram
+            .edges
+            .iter()
+            .filter_map(|edge| edge_in(diagram, edge, parent_id))
+            .fold(graph, |graph, edge| graph.with_edge(edge)),
+    )
+}
+
+fn node_element(diagram: &Diagram, node: &Node) -> Box<dyn AsciiElement> {
+    match &node.r#type {
+        NodeType::State(state) if diagram.nodes_in(Some(&node.id)).next().is_some() => state_box(
+            state,
+            &node.stereotypes,
+            Some(graph(diagram, Some(&node.id))),
+        ),
+        // The lollipop on the border of the providing component already draws its circle
+        NodeType::Interface(_) if provided(diagram, &node.id) => {
+            Box::new(AsciiText::new(Position::default(), node_label(node)))
+        }
+        _ => Box::<dyn AsciiElement>::from(node),
+    }
+}
+
+fn provided(diagram: &Diagram, interface_id: &str) -> bool {
+    diagram.edges.iter().any(|edge| {
+        (edge.to_id == interface_id && edge.style_from == EdgeStyle::Lollipop)
+            || (edge.from_id == interface_id && edge.style_to == EdgeStyle::Lollipop)
+    })
+}
+
+// The composite state or group given as the parent of a node or group, if it exists
+fn enclosing<'a>(diagram: &Diagram, parent_id: Option<&'a str>) -> Option<&'a str> {
+    parent_id.filter(|parent_id| {
+        diagram.nodes.iter().any(|node| node.id == *parent_id)
+            || diagram.groups.iter().any(|group| group.id == *parent_id)
+    })
+}
+
+// An edge is drawn in the innermost graph holding both of its ends; an end nested deeper
+// is reached through the composite state or group holding it.
+fn edge_in(diagram: &Diagram, edge: &Edge, parent_id: Option<&str>) -> Option<Edge> {
+    let from_id: String = visible_in(diagram, &edge.from_id, parent_id)?;
+    let to_id: String = visible_in(diagram, &edge.to_id, parent_id)?;
+
+    if from_id == to_id && edge.from_id != edge.to_id {
+        return None;
+    }
+
+    // Ports belong to the node itself, not to the composite state drawn in its place
+    Some(Edge {
+        from_port: edge.from_port.clone().filter(|_| from_id == edge.from_id),
+        to_port: edge.to_port.clone().filter(|_| to_id == edge.to_id),
+        from_id,
+        to_id,
+        label: edge_label(edge),
+        ..edge.clone()
+    })
+}
+
+// Stereotypes such as "«include»" go before the label, on the same line
+fn edge_label(edge: &Edge) -> Option<String> {
+    let parts: Vec<String> = edge
+        .stereotypes
+        .iter()
+        .map(|stereotype| format!("«{}»", stereotype))
+        .chain(edge.label.clone())
+        .collect();
+
+    Some(parts.join(" ")).filter(|label| !label.is_empty())
+}
+
+// The node or group itself, or the composite state or group holding it, among the
+// elements of the graph
+fn visible_in(diagram: &Diagram, id: &str, parent_id: Option<&str>) -> Option<String> {
+    let mut current: &str = id;
+
+    loop {
+        let node_parent = diagram.nodes.iter().find(|node| node.id == current);
+        let group_parent = diagram.groups.iter().find(|group| group.id == current);
+        let own_parent: Option<&str> = match (node_parent, group_parent) {
+            (Some(node), _) => node.parent_id.as_deref(),
+            (None, Some(group)) => group.parent_id.as_deref(),
+            // Floating notes are only laid out in the top graph
+            (None, None) => return parent_id.is_none().then(|| current.to_string()),
+        };
+        let enclosing: Option<&str> = enclosing(diagram, own_parent);
+
+        if enclosing == parent_id {
+            return Some(current.to_string());
+        }
+        current = enclosing?;
+    }
+}
+
+fn with_notes(element: Box<dyn AsciiElement>, id: &str, notes: &[Note]) -> Box<dyn AsciiElement> {
+    let anchored: Vec<&Note> = notes
+        .iter()
+        .filter(|n| n.anchor_id.as_deref() == Some(id))
+        .collect();
+
+    if anchored.is_empty() {
+        return element;
+    }
+
+    Box::new(anchored.into_iter().fold(
+        AsciiNoteDecorator::new(element, Position::default()),
+        |decorator, n| decorator.with_note(&n.side, note(n)),
+    ))
+}
+
+// Notes without an anchor, or anchored to an element that does not exist, stand on their own
+fn floating_notes(diagram: &Diagram) -> impl Iterator<Item = &Note> {
+    diagram.notes.iter().filter(|n| match &n.anchor_id {
+        Some(anchor_id) => {
+            !diagram.nodes.iter().any(|node| &node.id == anchor_id)
+                && !diagram.groups.iter().any(|group| &group.id == anchor_id)
+        }
+        None => true,
+    })
+}
+
+// ┌──────╲
+// │ text │
+// └──────┘
+fn note(note: &Note) -> Box<dyn AsciiElement> {
+    tight(text(Some(&note.text)), AsciiBorder::folded())
+}
+
+fn text(content: Option<&String>) -> Box<dyn AsciiElement> {
+    Box::new(AsciiText::new(
+        Position::default(),
+        content.cloned().unwrap_or_default(),
+    ))
+}
+
+const ACTOR_ICON: &str = " O \n/|\\\n/ \\";
+const CIRCLE_ICON: &str = "╭─╮\n╰─╯";
+const INTERFACE_ICON: &str = "○";
+const BOUNDARY_ICON: &str = "├─○";
+const CONTROL_ICON: &str = "╭<╮\n╰─╯";
+const ENTITY_ICON: &str = "╭─╮\n╰─╯\n───";
+const INITIAL_STATE_ICON: &str = "●";
+const FINAL_STATE_ICON: &str = "◉";
+const CHOICE_ICON: &str = "◇";
+const BAR_ICON: &str = "━━━━━━━";
+
 impl From<&Node> for Box<dyn AsciiElement> {
     fn from(value: &Node) -> Self {
-        Box::new(match &value.r#type {
-            NodeType::Component(name) => AsciiBorderDecorator::new(
-                Box::new(AsciiText::new(Position::default(), name.to_string())),
+        let label: Box<dyn AsciiElement> =
+            Box::new(AsciiText::new(Position::default(), node_label(value)));
+
+        match &value.r#type {
+            NodeType::Component(_) => bordered(label, AsciiBorder::rounded()),
+            NodeType::Rectangle(_) => bordered(label, AsciiBorder::square()),
+            NodeType::Database(_) => bordered(label, AsciiBorder::cylinder()),
+            NodeType::Queue(_) => bordered(label, AsciiBorder::tube()),
+            NodeType::Artifact(_) => bordered(label, AsciiBorder::folded()),
+            NodeType::Storage(_) => bordered(label, AsciiBorder::double()),
+            NodeType::UseCase(_) => bordered(label, AsciiBorder::ellipse()),
+            NodeType::Node(_) => bordered(label, AsciiBorder::heavy()),
+            NodeType::Cloud(_) => bordered(label, AsciiBorder::cloud()),
+            NodeType::Actor(_) => with_icon(label, ACTOR_ICON),
+            NodeType::Circle(_) => with_icon(label, CIRCLE_ICON),
+            NodeType::Interface(_) => with_icon(label, INTERFACE_ICON),
+            NodeType::Boundary(_) => with_icon(label, BOUNDARY_ICON),
+            NodeType::Class(classifier) => class_box(classifier, &value.stereotypes),
+            NodeType::Object(object) => object_table(object, &value.stereotypes),
+            NodeType::State(state) => state_box(state, &value.stereotypes, None),
+        }
+    }
+}
+
+// Heads sit side by side above the lifelines, so they are kept tighter than nodes
+impl From<&Participant> for Box<dyn AsciiElement> {
+    fn from(value: &Participant) -> Self {
+        let label: Box<dyn AsciiElement> = Box::new(AsciiText::new(
+            Position::default(),
+            stereotyped_label(value.r#type.name(), &value.stereotypes),
+        ));
+
+        match &value.r#type {
+            ParticipantType::Participant(_) => tight(label, AsciiBorder::square()),
+            ParticipantType::Collections(_) => tight(label, AsciiBorder::double()),
+            ParticipantType::Database(_) => tight(label, AsciiBorder::cylinder()),
+            ParticipantType::Queue(_) => tight(label, AsciiBorder::tube()),
+            ParticipantType::Actor(_) => with_icon(label, ACTOR_ICON),
+            ParticipantType::Boundary(_) => with_icon(label, BOUNDARY_ICON),
+            ParticipantType::Control(_) => with_icon(label, CONTROL_ICON),
+            ParticipantType::Entity(_) => with_icon(label, ENTITY_ICON),
+        }
+    }
+}
+
+// ┌─────────────┐
+// │ «interface» │
+// │ Repository  │
+// ├─────────────┤
+// │ +find(id)   │
+// └─────────────┘
+// Empty compartments are left out rather than drawn as blank rows
+fn class_box(classifier: &Classifier, stereotypes: &[String]) -> Box<dyn AsciiElement> {
+    let keyword: Option<&str> = match classifier.kind {
+        ClassifierKind::Class => None,
+        ClassifierKind::AbstractClass => Some("abstract"),
+        ClassifierKind::Interface => Some("interface"),
+        ClassifierKind::Enum => Some("enumeration"),
+    };
+    let name: String = match &classifier.generics {
+        Some(generics) => format!("{}<{}>", classifier.name, generics),
+        None => classifier.name.clone(),
+    };
+    let stereotypes: Vec<String> = keyword
+        .map(String::from)
+        .into_iter()
+        .chain(stereotypes.iter().cloned())
+        .collect();
+    let members = |members: Vec<&Member>| -> String {
+        members
+            .into_iter()
+            .map(member_line)
+            .collect::<Vec<String>>()
+            .join("\n")
+    };
+
+    let header: Box<dyn AsciiElement> = Box::new(AsciiText::new(
+        Position::default(),
+        stereotyped_label(&name, &stereotypes),
+    ));
+
+    Box::new(
+        [
+            members(classifier.fields().collect()),
+            members(classifier.methods().collect()),
+        ]
+        .into_iter()
+        .filter(|compartment| !compartment.is_empty())
+        .fold(
+            AsciiCompartments::new(Position::default(), 1)
+                .with_border(AsciiBorder::square())
+                .with_compartment(header),
+            |compartments, compartment| {
+                compartments
+                    .with_compartment(Box::new(AsciiText::new(Position::default(), compartment)))
+            },
+        ),
+    )
+}
+
+// ┌─────────────┐
+// │   server    │
+// ├──────┬──────┤
+// │ host │ db   │
+// │ port │ 5432 │
+// └──────┴──────┘
+// Fields without a value leave their cell empty
+fn object_table(object: &Object, stereotypes: &[String]) -> Box<dyn AsciiElement> {
+    let title: Box<dyn AsciiElement> = Box::new(AsciiText::new(
+        Position::default(),
+        stereotyped_label(&object.name, stereotypes),
+    ));
+
+    Box::new(object.fields.iter().fold(
+        AsciiTable::new(Position::default(), title),
+        |table, field| table.with_row(field.name.clone(), field.value.clone().unwrap_or_default()),
+    ))
+}
+
+// ╭────────────╮
+// │    Idle    │
+// ├────────────┤
+// │ waiting    │
+// ╰────────────╯
+// A composite state draws its substates in a compartment of their own
+fn state_box(
+    state: &State,
+    stereotypes: &[String],
+    substates: Option<Box<dyn AsciiElement>>,
+) -> Box<dyn AsciiElement> {
+    let icon: &str = match state.kind {
+        StateKind::Initial => INITIAL_STATE_ICON,
+        StateKind::Final => FINAL_STATE_ICON,
+        StateKind::Choice => CHOICE_ICON,
+        StateKind::Fork | StateKind::Join => BAR_ICON,
+        StateKind::Simple => {
+            let header: Box<dyn AsciiElement> = Box::new(AsciiText::new(
                 Position::default(),
-                2,
-                2,
-                ' ',
-            ),
-        })
+                stereotyped_label(&state.name, stereotypes),
+            ));
+
+            return Box::new(
+                [
+                    Some(state.description.join("\n"))
+                        .filter(|description| !description.is_empty())
+                        .map(|description| text(Some(&description))),
+                    substates,
+                ]
+                .into_iter()
+                .flatten()
+                .fold(
+                    AsciiCompartments::new(Position::default(), 1).with_compartment(header),
+                    |compartments, compartment| compartments.with_compartment(compartment),
+                ),
+            );
+        }
+    };
+
+    text(Some(&icon.to_string()))
+}
+
+// ┏━━━━━━━━━━━━━━━━━━━━━━━┓
+// ┃        Server         ┃
+// ├───────────────────────┤
+// ┃ ╭───────╮  ╭────────╮ ┃
+// ┃ │  app  │  │   db   │ ┃
+// ┃ ╰───────╯  ╰────────╯ ┃
+// ┗━━━━━━━━━━━━━━━━━━━━━━━┛
+// A group frames the graph of its members, with the border of the matching element
+fn group_frame(diagram: &Diagram, group: &Group) -> Box<dyn AsciiElement> {
+    let border: AsciiBorder = match &group.r#type {
+        GroupType::Package(_) | GroupType::Frame(_) | GroupType::Rectangle(_) => {
+            AsciiBorder::square()
+        }
+        GroupType::Node(_) => AsciiBorder::heavy(),
+        GroupType::Folder(_) | GroupType::Artifact(_) => AsciiBorder::folded(),
+        GroupType::Cloud(_) => AsciiBorder::cloud(),
+        GroupType::Component(_) => AsciiBorder::rounded(),
+        GroupType::Database(_) => AsciiBorder::cylinder(),
+        GroupType::Storage(_) => AsciiBorder::double(),
+    };
+
+    Box::new(
+        AsciiCompartments::new(Position::default(), 1)
+            .with_border(border)
+            .with_compartment(text(Some(&group.r#type.name().to_string())))
+            .with_compartment(graph(diagram, Some(&group.id))),
+    )
+}
+
+// Modifiers come first as in PlantUML, e.g., "{static} +count() : int"
+fn member_line(member: &Member) -> String {
+    let mut line: String = String::new();
+
+    if member.is_static {
+        line.push_str("{static} ");
+    }
+    if member.is_abstract {
+        line.push_str("{abstract} ");
+    }
+    if let Some(visibility) = &member.visibility {
+        line.push(visibility.symbol());
+    }
+    line.push_str(&member.text);
+
+    line
+}
+
+fn node_label(node: &Node) -> String {
+    stereotyped_label(node.r#type.name(), &node.stereotypes)
+}
+
+// Stereotypes are shown on a line of their own above the name, e.g., «lib»
+fn stereotyped_label(name: &str, stereotypes: &[String]) -> String {
+    // Quoted names may span several lines through "\n"
+    let mut lines: Vec<String> = name.lines().map(String::from).collect();
+
+    if !stereotypes.is_empty() {
+        let stereotypes: String = stereotypes
+            .iter()
+            .map(|stereotype| format!("«{}»", stereotype))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        lines.insert(0, stereotypes);
     }
+
+    centered_lines(&lines)
+}
+
+fn centered_lines(lines: &[String]) -> String {
+    let width: usize = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|line| {
+            let offset: usize = (width - line.chars().count()) / 2;
+            format!("{}{}", " ".repeat(offset), line)
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn bordered(label: Box<dyn AsciiElement>, border: AsciiBorder) -> Box<dyn AsciiElement> {
+    Box::new(AsciiBorderDecorator::new(label, Position::default(), 2, 2, ' ').with_border(border))
+}
+
+fn tight(label: Box<dyn AsciiElement>, border: AsciiBorder) -> Box<dyn AsciiElement> {
+    Box::new(AsciiBorderDecorator::new(label, Position::default(), 1, 0, ' ').with_border(border))
+}
+
+fn with_icon(label: Box<dyn AsciiElement>, icon: &str) -> Box<dyn AsciiElement> {
+    Box::new(AsciiIconDecorator::new(label, Position::default(), icon))
 }
@@ -11,7 +11,21 @@ mod test {
         ascii_grid_model,
     };
 
-    use lib_core::domain::entities::diagram::{Diagram, Node, NodeType};
+    use lib_core::domain::entities::{
+        activity::{Activity, ActivityStep, Branch},
+        class::{Classifier, ClassifierKind, Member, MemberKind, Visibility},
+        data::{Data, DataEntry, DataKind, DataValue},
+        diagram::{
+            Diagram, DiagramKind, Edge, EdgeStyle, Group, GroupType, LayoutDirection, LineStyle,
+            Node, NodeType, Note,
+        },
+        gantt::{Gantt, GanttTask, TaskAnchor, TaskStart},
+        object::{Field, Object, ObjectKind},
+        sequence::{Message, MessageKind, Participant, ParticipantType, Sequence, SequenceEvent},
+        state::{State, StateKind},
+        timing::{StateChange, Timing, TimingKind, TimingParticipant},
+        tree::{Tree, TreeKind, TreeNode, TreeSide},
+    };
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -84,4 +98,927 @@ mod test {
             .to_string()
         )
     }
+
+    #[test]
+    fn one_actor() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let diagram: Diagram = Diagram::new(
+            None,
+            vec![Node::new(
+                "user".to_string(),
+                NodeType::Actor("User".to_string()),
+            )],
+            vec![],
+        );
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec![' ', 'O', ' '],
+                    vec!['/', '|', '\\'],
+                    vec!['/', ' ', '\\'],
+                    vec!['U', 's', 'e', 'r'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn one_database() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let diagram: Diagram = Diagram::new(
+            None,
+            vec![Node::new(
+                "db".to_string(),
+                NodeType::Database("DB".to_string()),
+            )],
+            vec![],
+        );
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec!['╭', '─', '─', '─', '─', '─', '─', '╮'],
+                    vec!['├', '─', '─', '─', '─', '─', '─', '┤'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', 'D', 'B', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['╰', '─', '─', '─', '─', '─', '─', '╯'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn one_component_with_stereotype() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let diagram: Diagram = Diagram::new(
+            None,
+            vec![Node {
+                stereotypes: vec!["lib".to_string()],
+                ..Node::new(
+                    "uml".to_string(),
+                    NodeType::Component("uml_core".to_string()),
+                )
+            }],
+            vec![],
+        );
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec!['╭', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '╮'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', '«', 'l', 'i', 'b', '»', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', 'u', 'm', 'l', '_', 'c', 'o', 'r', 'e', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['╰', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '╯'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn one_rectangle_with_multi_line_name() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let diagram: Diagram = Diagram::new(
+            None,
+            vec![Node::new(
+                "use_case_interactor".to_string(),
+                NodeType::Rectangle("Use Case\nInteractor".to_string()),
+            )],
+            vec![],
+        );
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec!['┌', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┐'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', 'U', 's', 'e', ' ', 'C', 'a', 's', 'e', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', 'I', 'n', 't', 'e', 'r', 'a', 'c', 't', 'o', 'r', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['└', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┘'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn title_above_and_caption_and_legend_below() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let diagram: Diagram = Diagram {
+            caption: Some("Fig 1".to_string()),
+            legend: Some("Legend".to_string()),
+            ..Diagram::new(
+                Some("Overview".to_string()),
+                vec![Node::new(
+                    "a".to_string(),
+                    NodeType::Component("A".to_string()),
+                )],
+                vec![],
+            )
+        };
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec![' ', 'O', 'v', 'e', 'r', 'v', 'i', 'e', 'w'],
+                    vec![],
+                    vec![' ', '╭', '─', '─', '─', '─', '─', '╮'],
+                    vec![' ', '│', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', '│', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', '│', ' ', ' ', 'A', ' ', ' ', '│'],
+                    vec![' ', '│', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', '│', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', '╰', '─', '─', '─', '─', '─', '╯'],
+                    vec![],
+                    vec![' ', ' ', 'F', 'i', 'g', ' ', '1'],
+                    vec![],
+                    vec!['┌', '─', '─', '─', '─', '─', '─', '─', '─', '┐'],
+                    vec!['│', ' ', 'L', 'e', 'g', 'e', 'n', 'd', ' ', '│'],
+                    vec!['└', '─', '─', '─', '─', '─', '─', '─', '─', '┘'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn anchored_and_floating_notes() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let diagram: Diagram = Diagram {
+            notes: vec![
+                Note {
+                    anchor_id: Some("a".to_string()),
+                    ..Note::new("Hi".to_string())
+                },
+                Note::new("N".to_string()),
+            ],
+            ..Diagram::new(
+                None,
+                vec![Node::new(
+                    "a".to_string(),
+                    NodeType::Rectangle("A".to_string()),
+                )],
+                vec![],
+            )
+        };
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec!['┌', '─', '─', '─', '─', '─', '┐', ' ', ' ', '┌', '─', '─', '─', '─', '╲'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', '│', '┄', '┄', '│', ' ', 'H', 'i', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', '└', '─', '─', '─', '─', '┘', ' ', ' ', '┌', '─', '─', '─', '╲'],
+                    vec!['│', ' ', ' ', 'A', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', 'N', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '└', '─', '─', '─', '┘'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['└', '─', '─', '─', '─', '─', '┘'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn left_to_right_edge_between_rectangles() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let diagram: Diagram = Diagram {
+            direction: LayoutDirection::LeftToRight,
+            ..Diagram::new(
+                None,
+                vec![
+                    Node::new("a".to_string(), NodeType::Rectangle("A".to_string())),
+                    Node::new("b".to_string(), NodeType::Rectangle("B".to_string())),
+                ],
+                vec![Edge::new(
+                    "a".to_string(),
+                    "b".to_string(),
+                    EdgeStyle::Solid,
+                    EdgeStyle::Arrow,
+                    Some("uses".to_string()),
+                )],
+            )
+        };
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec!['┌', '─', '─', '─', '─', '─', '┐', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '┌', '─', '─', '─', '─', '─', '┐'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', 'A', ' ', ' ', '│', '─', '─', '─', '─', '─', '─', '▶', '│', ' ', ' ', 'B', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', '│', ' ', 'u', 's', 'e', 's', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['└', '─', '─', '─', '─', '─', '┘', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '└', '─', '─', '─', '─', '─', '┘'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn groups_framing_their_members() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let diagram: Diagram = Diagram {
+            groups: vec![
+                Group::new(
+                    "Outer".to_string(),
+                    GroupType::Component("Outer".to_string()),
+                    None,
+                ),
+                Group::new(
+                    "Server".to_string(),
+                    GroupType::Node("Server".to_string()),
+                    Some("Outer".to_string()),
+                ),
+            ],
+            ..Diagram::new(
+                None,
+                vec![
+                    Node::new("y".to_string(), NodeType::Rectangle("Y".to_string())),
+                    Node {
+                        parent_id: Some("Server".to_string()),
+                        ..Node::new("app".to_string(), NodeType::Rectangle("app".to_string()))
+                    },
+                ],
+                vec![Edge::new(
+                    "y".to_string(),
+                    "Outer".to_string(),
+                    EdgeStyle::Solid,
+                    EdgeStyle::Arrow,
+                    None,
+                )],
+            )
+        };
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec![' ', ' ', ' ', ' ', ' ', '┌', '─', '─', '─', '─', '─', '┐'],
+                    vec![' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', 'Y', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', '└', '─', '─', '─', '─', '─', '┘'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '▼'],
+                    vec!['╭', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '╮'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', 'O', 'u', 't', 'e', 'r', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['├', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┤'],
+                    vec!['│', ' ', '┏', '━', '━', '━', '━', '━', '━', '━', '━', '━', '━', '━', '┓', ' ', '│'],
+                    vec!['│', ' ', '┃', ' ', ' ', 'S', 'e', 'r', 'v', 'e', 'r', ' ', ' ', ' ', '┃', ' ', '│'],
+                    vec!['│', ' ', '┠', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┨', ' ', '│'],
+                    vec!['│', ' ', '┃', ' ', '┌', '─', '─', '─', '─', '─', '─', '─', '┐', ' ', '┃', ' ', '│'],
+                    vec!['│', ' ', '┃', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', '┃', ' ', '│'],
+                    vec!['│', ' ', '┃', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', '┃', ' ', '│'],
+                    vec!['│', ' ', '┃', ' ', '│', ' ', ' ', 'a', 'p', 'p', ' ', ' ', '│', ' ', '┃', ' ', '│'],
+                    vec!['│', ' ', '┃', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', '┃', ' ', '│'],
+                    vec!['│', ' ', '┃', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', '┃', ' ', '│'],
+                    vec!['│', ' ', '┃', ' ', '└', '─', '─', '─', '─', '─', '─', '─', '┘', ' ', '┃', ' ', '│'],
+                    vec!['│', ' ', '┗', '━', '━', '━', '━', '━', '━', '━', '━', '━', '━', '━', '┛', ' ', '│'],
+                    vec!['╰', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '╯'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn use_cases_with_include() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let diagram: Diagram = Diagram::new(
+            None,
+            vec![
+                Node::new("User".to_string(), NodeType::Actor("User".to_string())),
+                Node::new(
+                    "Checkout".to_string(),
+                    NodeType::UseCase("Checkout".to_string()),
+                ),
+                Node::new("Pay".to_string(), NodeType::UseCase("Pay".to_string())),
+            ],
+            vec![
+                Edge::new(
+                    "User".to_string(),
+                    "Checkout".to_string(),
+                    EdgeStyle::Solid,
+                    EdgeStyle::Arrow,
+                    None,
+                ),
+                Edge {
+                    line_style: LineStyle::Dotted,
+                    stereotypes: vec!["include".to_string()],
+                    ..Edge::new(
+                        "Checkout".to_string(),
+                        "Pay".to_string(),
+                        EdgeStyle::Solid,
+                        EdgeStyle::Arrow,
+                        None,
+                    )
+                },
+            ],
+        );
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', 'O', ' '],
+                    vec![' ', ' ', ' ', ' ', ' ', '/', '|', '\\'],
+                    vec![' ', ' ', ' ', ' ', ' ', '/', ' ', '\\'],
+                    vec![' ', ' ', ' ', ' ', ' ', 'U', 's', 'e', 'r'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '▼'],
+                    vec!['╭', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '╮'],
+                    vec!['(', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ')'],
+                    vec!['(', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ')'],
+                    vec!['(', ' ', ' ', 'C', 'h', 'e', 'c', 'k', 'o', 'u', 't', ' ', ' ', ')'],
+                    vec!['(', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ')'],
+                    vec!['(', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ')'],
+                    vec!['╰', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '╯'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '┆', ' ', '«', 'i', 'n', 'c', 'l', 'u', 'd', 'e', '»'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', '┌', '┘'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', '▼'],
+                    vec![' ', ' ', '╭', '─', '─', '─', '─', '─', '─', '─', '╮'],
+                    vec![' ', ' ', '(', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ')'],
+                    vec![' ', ' ', '(', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ')'],
+                    vec![' ', ' ', '(', ' ', ' ', 'P', 'a', 'y', ' ', ' ', ')'],
+                    vec![' ', ' ', '(', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ')'],
+                    vec![' ', ' ', '(', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ')'],
+                    vec![' ', ' ', '╰', '─', '─', '─', '─', '─', '─', '─', '╯'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn sequence_of_messages() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let diagram: Diagram = Diagram {
+            kind: DiagramKind::Sequence(Sequence::new(
+                vec![
+                    Participant::new(
+                        "a".to_string(),
+                        ParticipantType::Participant("A".to_string()),
+                    ),
+                    Participant::new(
+                        "b".to_string(),
+                        ParticipantType::Participant("B".to_string()),
+                    ),
+                ],
+                vec![SequenceEvent::Message(Message::new(
+                    "a".to_string(),
+                    "b".to_string(),
+                    MessageKind::Sync,
+                    Some("ping".to_string()),
+                ))],
+            )),
+            ..Diagram::new(None, vec![], vec![])
+        };
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec!['┌', '─', '─', '─', '┐', ' ', ' ', ' ', '┌', '─', '─', '─', '┐'],
+                    vec!['│', ' ', 'A', ' ', '│', ' ', ' ', ' ', '│', ' ', 'B', ' ', '│'],
+                    vec!['└', '─', '─', '─', '┘', ' ', ' ', ' ', '└', '─', '─', '─', '┘'],
+                    vec![' ', ' ', '│', ' ', 'p', 'i', 'n', 'g', ' ', ' ', '│'],
+                    vec![' ', ' ', '│', '─', '─', '─', '─', '─', '─', '▶', '│'],
+                    vec![' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn class_boxes_with_composition() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let diagram: Diagram = Diagram::new(
+            None,
+            vec![
+                Node::new(
+                    "repo".to_string(),
+                    NodeType::Class(Classifier {
+                        members: vec![Member {
+                            visibility: Some(Visibility::Public),
+                            ..Member::new(MemberKind::Method, "find(id)".to_string())
+                        }],
+                        ..Classifier::new(ClassifierKind::Interface, "Repository".to_string())
+                    }),
+                ),
+                Node::new(
+                    "user".to_string(),
+                    NodeType::Class(Classifier {
+                        generics: Some("T".to_string()),
+                        members: vec![
+                            Member {
+                                visibility: Some(Visibility::Private),
+                                ..Member::new(MemberKind::Field, "id : int".to_string())
+                            },
+                            Member {
+                                is_static: true,
+                                ..Member::new(MemberKind::Method, "count()".to_string())
+                            },
+                        ],
+                        ..Classifier::new(ClassifierKind::Class, "User".to_string())
+                    }),
+                ),
+            ],
+            vec![Edge {
+                from_multiplicity: Some("1".to_string()),
+                to_multiplicity: Some("*".to_string()),
+                ..Edge::new(
+                    "repo".to_string(),
+                    "user".to_string(),
+                    EdgeStyle::Composition,
+                    EdgeStyle::Solid,
+                    None,
+                )
+            }],
+        );
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec![' ', ' ', '┌', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┐'],
+                    vec![' ', ' ', '│', ' ', '«', 'i', 'n', 't', 'e', 'r', 'f', 'a', 'c', 'e', '»', ' ', '│'],
+                    vec![' ', ' ', '│', ' ', 'R', 'e', 'p', 'o', 's', 'i', 't', 'o', 'r', 'y', ' ', ' ', '│'],
+                    vec![' ', ' ', '├', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┤'],
+                    vec![' ', ' ', '│', ' ', '+', 'f', 'i', 'n', 'd', '(', 'i', 'd', ')', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', '└', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┘'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '1', ' ', '◆'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '└', '┐'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '*', ' ', '│'],
+                    vec!['┌', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┐'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', 'U', 's', 'e', 'r', '<', 'T', '>', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['├', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┤'],
+                    vec!['│', ' ', '-', 'i', 'd', ' ', ':', ' ', 'i', 'n', 't', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['├', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┤'],
+                    vec!['│', ' ', '{', 's', 't', 'a', 't', 'i', 'c', '}', ' ', 'c', 'o', 'u', 'n', 't', '(', ')', ' ', '│'],
+                    vec!['└', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┘'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn class_with_several_relationships_into_it() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let class = |id: &str, name: &str| {
+            Node::new(
+                id.to_string(),
+                NodeType::Class(Classifier::new(ClassifierKind::Class, name.to_string())),
+            )
+        };
+        let edge = |to: &str, style: EdgeStyle| {
+            Edge::new(
+                "shape".to_string(),
+                to.to_string(),
+                style,
+                EdgeStyle::Solid,
+                None,
+            )
+        };
+        let diagram: Diagram = Diagram::new(
+            None,
+            vec![
+                class("shape", "Shape"),
+                class("circle", "Circle"),
+                class("square", "Square"),
+            ],
+            vec![
+                edge("circle", EdgeStyle::Extension),
+                edge("square", EdgeStyle::Extension),
+                edge("square", EdgeStyle::Aggregation),
+            ],
+        );
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', '┌', '─', '─', '─', '─', '─', '─', '─', '┐'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', 'S', 'h', 'a', 'p', 'e', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', '└', '─', '─', '─', '─', '─', '─', '─', '┘'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '△', ' ', '△', ' ', '◇'],
+                    vec![' ', ' ', ' ', ' ', ' ', '┌', '─', '─', '┘', ' ', '└', '─', '┴', '─', '─', '┬', '─', '─', '┐'],
+                    vec![' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', '│'],
+                    vec!['┌', '─', '─', '─', '─', '─', '─', '─', '─', '┐', ' ', ' ', '┌', '─', '─', '─', '─', '─', '─', '─', '─', '┐'],
+                    vec!['│', ' ', 'C', 'i', 'r', 'c', 'l', 'e', ' ', '│', ' ', ' ', '│', ' ', 'S', 'q', 'u', 'a', 'r', 'e', ' ', '│'],
+                    vec!['└', '─', '─', '─', '─', '─', '─', '─', '─', '┘', ' ', ' ', '└', '─', '─', '─', '─', '─', '─', '─', '─', '┘'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn objects_as_key_value_tables() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let field = |name: &str, value: &str| Field::new(name.to_string(), Some(value.to_string()));
+        let diagram: Diagram = Diagram::new(
+            None,
+            vec![
+                Node::new(
+                    "server".to_string(),
+                    NodeType::Object(Object {
+                        fields: vec![field("host", "db.local"), field("port", "5432")],
+                        ..Object::new(ObjectKind::Object, "server".to_string())
+                    }),
+                ),
+                Node::new(
+                    "ports".to_string(),
+                    NodeType::Object(Object {
+                        fields: vec![field("http", "80"), field("https", "443")],
+                        ..Object::new(ObjectKind::Map, "ports".to_string())
+                    }),
+                ),
+            ],
+            vec![Edge::new(
+                "server".to_string(),
+                "ports".to_string(),
+                EdgeStyle::Solid,
+                EdgeStyle::Arrow,
+                Some("listens".to_string()),
+            )],
+        );
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec!['┌', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┐'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', 's', 'e', 'r', 'v', 'e', 'r', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['├', '─', '─', '─', '─', '─', '─', '┬', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┤'],
+                    vec!['│', ' ', 'h', 'o', 's', 't', ' ', '│', ' ', 'd', 'b', '.', 'l', 'o', 'c', 'a', 'l', ' ', '│'],
+                    vec!['│', ' ', 'p', 'o', 'r', 't', ' ', '│', ' ', '5', '4', '3', '2', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['└', '─', '─', '─', '─', '─', '─', '┴', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┘'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', 'l', 'i', 's', 't', 'e', 'n', 's'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '▼'],
+                    vec![' ', ' ', '┌', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┐'],
+                    vec![' ', ' ', '│', ' ', ' ', ' ', ' ', 'p', 'o', 'r', 't', 's', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', '├', '─', '─', '─', '─', '─', '─', '─', '┬', '─', '─', '─', '─', '─', '┤'],
+                    vec![' ', ' ', '│', ' ', 'h', 't', 't', 'p', ' ', ' ', '│', ' ', '8', '0', ' ', ' ', '│'],
+                    vec![' ', ' ', '│', ' ', 'h', 't', 't', 'p', 's', ' ', '│', ' ', '4', '4', '3', ' ', '│'],
+                    vec![' ', ' ', '└', '─', '─', '─', '─', '─', '─', '─', '┴', '─', '─', '─', '─', '─', '┘'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn state_machine_with_composite_state() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let state = |id: &str, kind: StateKind| {
+            Node::new(
+                id.to_string(),
+                NodeType::State(State::new(kind, id.to_string())),
+            )
+        };
+        let transition = |from: &str, to: &str| {
+            Edge::new(
+                from.to_string(),
+                to.to_string(),
+                EdgeStyle::Solid,
+                EdgeStyle::Arrow,
+                None,
+            )
+        };
+        let diagram: Diagram = Diagram::new(
+            None,
+            vec![
+                state("start", StateKind::Initial),
+                Node::new(
+                    "Idle".to_string(),
+                    NodeType::State(State {
+                        description: vec!["waiting".to_string()],
+                        ..State::new(StateKind::Simple, "Idle".to_string())
+                    }),
+                ),
+                state("Active", StateKind::Simple),
+                Node {
+                    parent_id: Some("Active".to_string()),
+                    ..state("Loading", StateKind::Simple)
+                },
+                Node {
+                    parent_id: Some("Active".to_string()),
+                    ..state("end", StateKind::Final)
+                },
+            ],
+            vec![
+                transition("start", "Idle"),
+                transition("Idle", "Active"),
+                transition("Loading", "end"),
+                transition("Active", "Idle"),
+            ],
+        );
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '●'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', '▼'],
+                    vec![' ', ' ', '╭', '─', '─', '─', '─', '─', '─', '─', '─', '─', '╮'],
+                    vec![' ', ' ', '│', ' ', ' ', 'I', 'd', 'l', 'e', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', '├', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┤'],
+                    vec![' ', ' ', '│', ' ', 'w', 'a', 'i', 't', 'i', 'n', 'g', ' ', '│'],
+                    vec![' ', ' ', '╰', '─', '─', '─', '─', '─', '─', '─', '─', '─', '╯'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', '▲'],
+                    vec![' ', ' ', ' ', ' ', ' ', '┌', '┘', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', '▼', ' ', ' ', ' ', '│'],
+                    vec!['╭', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '╮'],
+                    vec!['│', ' ', ' ', ' ', 'A', 'c', 't', 'i', 'v', 'e', ' ', ' ', ' ', ' ', '│'],
+                    vec!['├', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┤'],
+                    vec!['│', ' ', '╭', '─', '─', '─', '─', '─', '─', '─', '─', '─', '╮', ' ', '│'],
+                    vec!['│', ' ', '│', ' ', 'L', 'o', 'a', 'd', 'i', 'n', 'g', ' ', '│', ' ', '│'],
+                    vec!['│', ' ', '╰', '─', '─', '─', '─', '─', '─', '─', '─', '─', '╯', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', '▼', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', ' ', ' ', ' ', ' ', ' ', '◉', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['╰', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '╯'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn activity_with_decision() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let diagram: Diagram = Diagram {
+            kind: DiagramKind::Activity(Activity::new(vec![
+                ActivityStep::Start,
+                ActivityStep::Action("Read".to_string()),
+                ActivityStep::If(vec![
+                    Branch::new(
+                        Some("valid?".to_string()),
+                        Some("yes".to_string()),
+                        vec![ActivityStep::Action("Save".to_string())],
+                    ),
+                    Branch::new(None, Some("no".to_string()), vec![]),
+                ]),
+                ActivityStep::Stop,
+            ])),
+            ..Diagram::default()
+        };
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '●'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '▼'],
+                    vec![' ', ' ', ' ', ' ', '╭', '─', '─', '─', '─', '─', '─', '╮'],
+                    vec![' ', ' ', ' ', ' ', '│', ' ', 'R', 'e', 'a', 'd', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', '╰', '─', '─', '─', '─', '─', '─', '╯'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '▼'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '◇', ' ', 'v', 'a', 'l', 'i', 'd', '?'],
+                    vec![' ', ' ', ' ', ' ', '┌', '─', '─', '─', '┴', '─', '─', '─', '┐'],
+                    vec![' ', ' ', ' ', ' ', '│', ' ', 'y', 'e', 's', ' ', ' ', ' ', '│', ' ', 'n', 'o'],
+                    vec![' ', ' ', ' ', ' ', '▼', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['╭', '─', '─', '─', '─', '─', '─', '╮', ' ', ' ', ' ', ' ', '│'],
+                    vec!['│', ' ', 'S', 'a', 'v', 'e', ' ', '│', ' ', ' ', ' ', ' ', '│'],
+                    vec!['╰', '─', '─', '─', '─', '─', '─', '╯', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', '│', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', '└', '─', '─', '─', '◇', '─', '─', '─', '┘'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '▼'],
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '◉'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn mind_map_with_both_sides() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let left = |text: &str, children: Vec<TreeNode>| TreeNode {
+            side: TreeSide::Left,
+            ..TreeNode::new(text.to_string(), children)
+        };
+        let diagram: Diagram = Diagram {
+            kind: DiagramKind::Tree(Tree::new(
+                TreeKind::MindMap,
+                vec![TreeNode::new(
+                    "Trip".to_string(),
+                    vec![
+                        TreeNode::new(
+                            "Flights".to_string(),
+                            vec![TreeNode::new("Book".to_string(), vec![])],
+                        ),
+                        left("Hotel", vec![left("Pay", vec![])]),
+                    ],
+                )],
+            )),
+            ..Diagram::default()
+        };
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', 'T', 'r', 'i', 'p'],
+                    vec![' ', ' ', 'H', 'o', 't', 'e', 'l', ' ', '─', '─', '┘', ' ', ' ', '└', '─', '─', ' ', 'F', 'l', 'i', 'g', 'h', 't', 's'],
+                    vec!['P', 'a', 'y', ' ', '─', '─', '┘', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '└', '─', '─', ' ', 'B', 'o', 'o', 'k'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn json_with_highlighted_path() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let highlighted = |key: &str, value: DataValue| DataEntry {
+            highlighted: true,
+            ..DataEntry::new(key.to_string(), value)
+        };
+        let diagram: Diagram = Diagram {
+            kind: DiagramKind::Data(Data::new(
+                DataKind::Json,
+                DataValue::Object(vec![
+                    DataEntry::new("id".to_string(), DataValue::Number("7".to_string())),
+                    highlighted(
+                        "user",
+                        DataValue::Object(vec![highlighted(
+                            "name",
+                            DataValue::Text("A".to_string()),
+                        )]),
+                    ),
+                ]),
+            )),
+            ..Diagram::default()
+        };
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec!['┌', '─', '─', '─', '─', '─', '─', '┬', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┐'],
+                    vec!['│', ' ', 'i', 'd', ' ', ' ', ' ', '│', ' ', '7', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '│'],
+                    vec!['├', '─', '─', '─', '─', '─', '─', '┼', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┤'],
+                    vec!['┃', ' ', 'u', 's', 'e', 'r', ' ', '┃', ' ', '┌', '─', '─', '─', '─', '─', '─', '┬', '─', '─', '─', '┐', ' ', '┃'],
+                    vec!['┃', ' ', ' ', ' ', ' ', ' ', ' ', '┃', ' ', '┃', ' ', 'n', 'a', 'm', 'e', ' ', '┃', ' ', 'A', ' ', '┃', ' ', '┃'],
+                    vec!['┃', ' ', ' ', ' ', ' ', ' ', ' ', '┃', ' ', '└', '─', '─', '─', '─', '─', '─', '┴', '─', '─', '─', '┘', ' ', '┃'],
+                    vec!['└', '─', '─', '─', '─', '─', '─', '┴', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '─', '┘'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn timing_waveforms() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let participant =
+            |name: &str, kind: TimingKind, changes: Vec<StateChange>| TimingParticipant {
+                changes,
+                ..TimingParticipant::new(name.to_string(), name.to_string(), kind)
+            };
+        let diagram: Diagram = Diagram {
+            kind: DiagramKind::Timing(Timing::new(
+                vec![
+                    participant(
+                        "WU",
+                        TimingKind::Concise,
+                        vec![
+                            StateChange::new(0, "Idle".to_string()),
+                            StateChange::new(10, "Busy".to_string()),
+                        ],
+                    ),
+                    participant(
+                        "EN",
+                        TimingKind::Binary,
+                        vec![
+                            StateChange::new(0, "low".to_string()),
+                            StateChange::new(10, "high".to_string()),
+                        ],
+                    ),
+                ],
+                vec![],
+            )),
+            ..Diagram::default()
+        };
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec![' ', ' ', ' ', '┌', '─', '─', '─', '─', '─', '─', '┬', '─', '─', '─', '─', '─', '─'],
+                    vec!['W', 'U', ' ', '│', ' ', 'I', 'd', 'l', 'e', ' ', '│', ' ', 'B', 'u', 's', 'y'],
+                    vec![' ', ' ', ' ', '└', '─', '─', '─', '─', '─', '─', '┴', '─', '─', '─', '─', '─', '─'],
+                    vec![],
+                    vec!['E', 'N', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '┌', '─', '─', '─', '─', '─', '─'],
+                    vec![' ', ' ', ' ', '─', '─', '─', '─', '─', '─', '─', '┘'],
+                    vec![],
+                    vec![' ', ' ', ' ', '┬', '─', '─', '─', '─', '─', '─', '┬', '─', '─', '─', '─', '─', '─'],
+                    vec![' ', ' ', ' ', '0', ' ', ' ', ' ', ' ', ' ', ' ', '1', '0'],
+                ]
+            }
+            .to_string()
+        )
+    }
+
+    #[test]
+    fn gantt_with_numbered_days() {
+        let mut presenter: AsciiPresenter = AsciiPresenter::new();
+        let diagram: Diagram = Diagram {
+            kind: DiagramKind::Gantt(Gantt::new(
+                None,
+                vec![
+                    GanttTask::new("Plan".to_string(), "Plan".to_string(), 2),
+                    GanttTask {
+                        start: TaskStart::After("Plan".to_string(), TaskAnchor::End, 0),
+                        ..GanttTask::new("Code".to_string(), "Code".to_string(), 3)
+                    },
+                ],
+            )),
+            ..Diagram::default()
+        };
+
+        let result: AsciiGridViewModel = presenter.process_diagram(&diagram);
+
+        assert_eq!(
+            result.to_string(),
+            ascii_grid_model! {
+                vec![
+                    vec![' ', ' ', ' ', ' ', ' ', ' ', '1', ' ', ' ', '2', ' ', ' ', '3', ' ', ' ', '4', ' ', ' ', '5', ' '],
+                    vec!['P', 'l', 'a', 'n', ' ', '█', '█', '█', '█', '█', '█', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' '],
+                    vec!['C', 'o', 'd', 'e', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '█', '█', '█', '█', '█', '█', '█', '█', '█'],
+                ]
+            }
+            .to_string()
+        )
+    }
 }
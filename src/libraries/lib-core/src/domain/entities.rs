@@ -1 +1,11 @@
+pub mod activity;
+pub mod class;
+pub mod data;
+pub mod diagnostic;
 pub mod diagram;
+pub mod gantt;
+pub mod object;
+pub mod sequence;
+pub mod state;
+pub mod timing;
+pub mod tree;
@@ -0,0 +1,70 @@
+/// A flow of steps run from top to bottom, branching, looping and forking into nested flows
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Activity {
+    pub steps: Vec<ActivityStep>,
+}
+
+impl Activity {
+    pub fn new(steps: Vec<ActivityStep>) -> Self {
+        Self { steps }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActivityStep {
+    Start, // start
+    Stop,  // stop
+    End,   // end
+    // Text
+    Action(String),
+    // Lane name, the following steps take place in that lane
+    Swimlane(String),
+    // Branches in order, the "else" one last if any
+    If(Vec<Branch>),
+    While(Loop),
+    Repeat(Loop),
+    // Flows run in parallel
+    Fork(Vec<Vec<ActivityStep>>),
+}
+
+/// A flow taken when its condition holds, or in any other case when it has none
+#[derive(Debug, Clone, PartialEq)]
+pub struct Branch {
+    pub condition: Option<String>,
+    // e.g., "yes" in "if (found?) then (yes)"
+    pub label: Option<String>,
+    pub steps: Vec<ActivityStep>,
+}
+
+impl Branch {
+    pub fn new(condition: Option<String>, label: Option<String>, steps: Vec<ActivityStep>) -> Self {
+        Self {
+            condition,
+            label,
+            steps,
+        }
+    }
+}
+
+/// A flow run again as long as its condition holds, checked before each run by "while"
+/// and after each run by "repeat"
+#[derive(Debug, Clone, PartialEq)]
+pub struct Loop {
+    pub condition: String,
+    // e.g., "yes" in "while (more?) is (yes)"
+    pub label: Option<String>,
+    // e.g., "no" in "endwhile (no)"
+    pub exit_label: Option<String>,
+    pub steps: Vec<ActivityStep>,
+}
+
+impl Loop {
+    pub fn new(condition: String, steps: Vec<ActivityStep>) -> Self {
+        Self {
+            condition,
+            label: None,
+            exit_label: None,
+            steps,
+        }
+    }
+}
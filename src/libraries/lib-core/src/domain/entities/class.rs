@@ -0,0 +1,88 @@
+/// A class, interface or enum, drawn with its fields and methods
+#[derive(Debug, Clone, PartialEq)]
+pub struct Classifier {
+    pub kind: ClassifierKind,
+    pub name: String,
+    // Type parameters, e.g., "K, V" for "class Map<K, V>"
+    pub generics: Option<String>,
+    pub members: Vec<Member>,
+}
+
+impl Classifier {
+    pub fn new(kind: ClassifierKind, name: String) -> Self {
+        Self {
+            kind,
+            name,
+            generics: None,
+            members: Vec::new(),
+        }
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = &Member> {
+        self.members
+            .iter()
+            .filter(|member| member.kind == MemberKind::Field)
+    }
+
+    pub fn methods(&self) -> impl Iterator<Item = &Member> {
+        self.members
+            .iter()
+            .filter(|member| member.kind == MemberKind::Method)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClassifierKind {
+    Class,
+    AbstractClass,
+    Interface,
+    Enum,
+}
+
+/// A field or method, e.g., "+ {static} count() : int"
+#[derive(Debug, Clone, PartialEq)]
+pub struct Member {
+    pub kind: MemberKind,
+    // Everything but the visibility and modifiers, e.g., "count() : int"
+    pub text: String,
+    pub visibility: Option<Visibility>,
+    pub is_static: bool,
+    pub is_abstract: bool,
+}
+
+impl Member {
+    pub fn new(kind: MemberKind, text: String) -> Self {
+        Self {
+            kind,
+            text,
+            visibility: None,
+            is_static: false,
+            is_abstract: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MemberKind {
+    Field,
+    Method,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Visibility {
+    Public,         // +
+    Private,        // -
+    Protected,      // #
+    PackagePrivate, // ~
+}
+
+impl Visibility {
+    pub fn symbol(&self) -> char {
+        match self {
+            Visibility::Public => '+',
+            Visibility::Private => '-',
+            Visibility::Protected => '#',
+            Visibility::PackagePrivate => '~',
+        }
+    }
+}
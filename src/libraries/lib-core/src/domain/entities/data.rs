@@ -0,0 +1,48 @@
+/// JSON or YAML data, drawn as nested tables of keys and values
+#[derive(Debug, Clone, PartialEq)]
+pub struct Data {
+    pub kind: DataKind,
+    pub root: DataValue,
+}
+
+impl Data {
+    pub fn new(kind: DataKind, root: DataValue) -> Self {
+        Self { kind, root }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataKind {
+    Json, // @startjson
+    Yaml, // @startyaml
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataValue {
+    Null,
+    Boolean(bool),
+    Number(String),
+    Text(String),
+    // Each item keyed by its index, e.g., "0"
+    Array(Vec<DataEntry>),
+    Object(Vec<DataEntry>),
+}
+
+/// A key of an object or an item of an array, with its value
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataEntry {
+    pub key: String,
+    pub value: DataValue,
+    // Lies on a path given by "#highlight"
+    pub highlighted: bool,
+}
+
+impl DataEntry {
+    pub fn new(key: String, value: DataValue) -> Self {
+        Self {
+            key,
+            value,
+            highlighted: false,
+        }
+    }
+}
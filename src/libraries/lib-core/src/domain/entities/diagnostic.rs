@@ -0,0 +1,152 @@
+use std::{fmt::Display, path::PathBuf, sync::Arc};
+
+/// A problem found in a diagram source, located precisely enough for editors to underline it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub span: Span,
+    // File the line belongs to when the source pulls in others through "!include",
+    // shared with every other diagnostic and source line of that file
+    pub file: Option<Arc<PathBuf>>,
+    // 1-based, as shown by editors
+    pub line: usize,
+    pub column: usize,
+    pub expected: Vec<String>,
+    pub source_line: String,
+}
+
+impl Diagnostic {
+    pub fn new(message: String, span: Span, line: usize, column: usize) -> Self {
+        Self {
+            severity: Severity::Error,
+            message,
+            span,
+            file: None,
+            line,
+            column,
+            expected: Vec::new(),
+            source_line: String::new(),
+        }
+    }
+
+    /// The offending source line with a caret under the failing token, e.g.
+    ///
+    /// ```text
+    /// component A -> > B
+    ///                ^
+    /// ```
+    pub fn snippet(&self) -> String {
+        let offset: usize = self.column.saturating_sub(1);
+        let available: usize = self.source_line.chars().count().saturating_sub(offset);
+        let length: usize = self.span.len().min(available).max(1);
+
+        format!(
+            "{}\n{}{}",
+            self.source_line,
+            " ".repeat(offset),
+            "^".repeat(length)
+        )
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+
+        write!(
+            f,
+            "{}:{}: {}\n{}",
+            self.line,
+            self.column,
+            self.message,
+            self.snippet()
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Severity {
+    // The statement could not be read and is missing from the diagram
+    #[default]
+    Error,
+    // The diagram could be read completely but something looks wrong
+    Warning,
+}
+
+/// Byte offsets into the diagram source, end excluded
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    pub fn len(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn snippet_should_underline_span() {
+        let diagnostic: Diagnostic = Diagnostic {
+            source_line: "a --> b : uses".to_owned(),
+            ..Diagnostic::new("unexpected arrow".to_owned(), Span::new(12, 15), 2, 3)
+        };
+
+        assert_eq!(diagnostic.snippet(), "a --> b : uses\n  ^^^");
+    }
+
+    #[test]
+    fn snippet_should_underline_at_least_one_character() {
+        let diagnostic: Diagnostic = Diagnostic {
+            source_line: "component".to_owned(),
+            ..Diagnostic::new("expected a name".to_owned(), Span::new(9, 9), 1, 10)
+        };
+
+        assert_eq!(diagnostic.snippet(), "component\n         ^");
+    }
+
+    #[test]
+    fn display_should_prefix_line_and_column() {
+        let diagnostic: Diagnostic = Diagnostic {
+            source_line: "@enduml".to_owned(),
+            ..Diagnostic::new("expected @startuml".to_owned(), Span::new(0, 0), 1, 1)
+        };
+
+        assert_eq!(
+            diagnostic.to_string(),
+            "1:1: expected @startuml\n@enduml\n^"
+        );
+    }
+
+    #[test]
+    fn display_should_prefix_file_when_known() {
+        let diagnostic: Diagnostic = Diagnostic {
+            file: Some(Arc::new(PathBuf::from("styles/common.puml"))),
+            source_line: "!foo".to_owned(),
+            ..Diagnostic::new("unknown directive".to_owned(), Span::new(0, 4), 3, 1)
+        };
+
+        assert_eq!(
+            diagnostic.to_string(),
+            "styles/common.puml:3:1: unknown directive\n!foo\n^^^^"
+        );
+    }
+}
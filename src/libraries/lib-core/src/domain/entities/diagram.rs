@@ -1,29 +1,116 @@
+use crate::domain::entities::{
+    activity::Activity, class::Classifier, data::Data, gantt::Gantt, object::Object,
+    sequence::Sequence, state::State, timing::Timing, tree::Tree,
+};
+
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct Diagram {
+    // Identifies the diagram among the others of the same file
+    pub name: Option<String>,
     pub title: Option<String>,
+    pub header: Option<String>,
+    pub footer: Option<String>,
+    pub caption: Option<String>,
+    pub legend: Option<String>,
     pub nodes: Vec<Node>,
     pub edges: Vec<Edge>,
+    pub groups: Vec<Group>,
+    pub notes: Vec<Note>,
+    pub direction: LayoutDirection,
+    pub kind: DiagramKind,
 }
 
 impl Diagram {
     pub fn new(title: Option<String>, nodes: Vec<Node>, edges: Vec<Edge>) -> Self {
         Self {
+            name: None,
             title,
+            header: None,
+            footer: None,
+            caption: None,
+            legend: None,
             nodes,
             edges,
+            groups: Vec::new(),
+            notes: Vec::new(),
+            direction: LayoutDirection::default(),
+            kind: DiagramKind::default(),
         }
     }
+
+    /// Nodes directly inside the given group, or at the top level when `None`
+    pub fn nodes_in<'a>(&'a self, group_id: Option<&'a str>) -> impl Iterator<Item = &'a Node> {
+        self.nodes
+            .iter()
+            .filter(move |node| node.parent_id.as_deref() == group_id)
+    }
+
+    /// Groups directly inside the given group, or at the top level when `None`
+    pub fn groups_in<'a>(&'a self, group_id: Option<&'a str>) -> impl Iterator<Item = &'a Group> {
+        self.groups
+            .iter()
+            .filter(move |group| group.parent_id.as_deref() == group_id)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Node {
     pub id: String,
     pub r#type: NodeType,
+    pub parent_id: Option<String>,
+    pub stereotypes: Vec<String>,
+    // On the border of a component, e.g., "component C { portin p1 }"
+    pub ports: Vec<Port>,
 }
 
 impl Node {
     pub fn new(id: String, r#type: NodeType) -> Self {
-        Self { id, r#type }
+        Self {
+            id,
+            r#type,
+            parent_id: None,
+            stereotypes: Vec::new(),
+            ports: Vec::new(),
+        }
+    }
+}
+
+/// A point of a component edges may be connected to rather than to the component itself
+#[derive(Debug, Clone, PartialEq)]
+pub struct Port {
+    pub id: String,
+    pub name: String,
+    pub kind: PortKind,
+}
+
+impl Port {
+    pub fn new(id: String, name: String, kind: PortKind) -> Self {
+        Self { id, name, kind }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PortKind {
+    InOut, // port
+    In,    // portin
+    Out,   // portout
+}
+
+/// A container (package, node, folder...) which may hold nodes and other groups
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    pub id: String,
+    pub r#type: GroupType,
+    pub parent_id: Option<String>,
+}
+
+impl Group {
+    pub fn new(id: String, r#type: GroupType, parent_id: Option<String>) -> Self {
+        Self {
+            id,
+            r#type,
+            parent_id,
+        }
     }
 }
 
@@ -34,17 +121,201 @@ pub struct Edge {
     pub style_from: EdgeStyle,
     pub style_to: EdgeStyle,
     pub label: Option<String>,
+    pub line_style: LineStyle,
+    pub stereotypes: Vec<String>,
+    // Where the target is placed relative to the source, e.g., "a -left-> b"
+    pub direction: Option<EdgeDirection>,
+    // Placed like any other edge but not drawn, e.g., "a -[hidden]-> b"
+    pub hidden: bool,
+    // Written next to each end, e.g., "1" and "many" for "a \"1\" *-- \"many\" b"
+    pub from_multiplicity: Option<String>,
+    pub to_multiplicity: Option<String>,
+    // Ids of the ports of the source and target nodes the edge is connected to
+    pub from_port: Option<String>,
+    pub to_port: Option<String>,
+}
+
+impl Edge {
+    pub fn new(
+        from_id: String,
+        to_id: String,
+        style_from: EdgeStyle,
+        style_to: EdgeStyle,
+        label: Option<String>,
+    ) -> Self {
+        Self {
+            from_id,
+            to_id,
+            style_from,
+            style_to,
+            label,
+            line_style: LineStyle::Solid,
+            stereotypes: Vec::new(),
+            direction: None,
+            hidden: false,
+            from_multiplicity: None,
+            to_multiplicity: None,
+            from_port: None,
+            to_port: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum NodeType {
     // Name
     Component(String),
+    Rectangle(String),
+    Circle(String),
+    Interface(String),
+    Actor(String),
+    Database(String),
+    Queue(String),
+    Artifact(String),
+    Storage(String),
+    UseCase(String),
+    Boundary(String),
+    // A deployment node, e.g., a server
+    Node(String),
+    Cloud(String),
+    Class(Classifier),
+    Object(Object),
+    // A composite state is the parent of its substates
+    State(State),
+}
+
+impl NodeType {
+    pub fn name(&self) -> &str {
+        match self {
+            NodeType::Component(name)
+            | NodeType::Rectangle(name)
+            | NodeType::Circle(name)
+            | NodeType::Interface(name)
+            | NodeType::Actor(name)
+            | NodeType::Database(name)
+            | NodeType::Queue(name)
+            | NodeType::Artifact(name)
+            | NodeType::Storage(name)
+            | NodeType::UseCase(name)
+            | NodeType::Boundary(name)
+            | NodeType::Node(name)
+            | NodeType::Cloud(name) => name,
+            NodeType::Class(classifier) => &classifier.name,
+            NodeType::Object(object) => &object.name,
+            NodeType::State(state) => &state.name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupType {
+    // Name
+    Package(String),
+    Node(String),
+    Folder(String),
+    Frame(String),
+    Cloud(String),
+    Component(String),
+    // A system boundary in use case diagrams
+    Rectangle(String),
+    Database(String),
+    Storage(String),
+    Artifact(String),
+}
+
+impl GroupType {
+    pub fn name(&self) -> &str {
+        match self {
+            GroupType::Package(name)
+            | GroupType::Node(name)
+            | GroupType::Folder(name)
+            | GroupType::Frame(name)
+            | GroupType::Cloud(name)
+            | GroupType::Component(name)
+            | GroupType::Rectangle(name)
+            | GroupType::Database(name)
+            | GroupType::Storage(name)
+            | GroupType::Artifact(name) => name,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum EdgeStyle {
-    Solid,     // ---
-    Arrow,     // -->
-    OpenArrow, // --<
+    Solid,       // ---
+    Arrow,       // -->
+    OpenArrow,   // --<
+    Extension,   // --|>
+    Composition, // --*
+    Aggregation, // --o
+    Lollipop,    // ○--, at a component providing an interface
+    Socket,      // --(, at a required interface
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum LineStyle {
+    #[default]
+    Solid, // --
+    Dotted, // ..
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EdgeDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// What the body of a diagram is made of, its title, notes and other directives aside
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum DiagramKind {
+    // Nodes linked by edges, possibly inside groups
+    #[default]
+    Graph,
+    Sequence(Sequence),
+    Activity(Activity),
+    Tree(Tree),
+    Data(Data),
+    Timing(Timing),
+    Gantt(Gantt),
+}
+
+/// How nodes linked by edges are ranked, e.g., "left to right direction"
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum LayoutDirection {
+    #[default]
+    TopToBottom,
+    LeftToRight,
+}
+
+/// A note, either attached to a node or floating on its own
+#[derive(Debug, Clone, PartialEq)]
+pub struct Note {
+    // Only floating notes declared with "as" have one, so edges may reference them
+    pub id: Option<String>,
+    pub text: String,
+    pub anchor_id: Option<String>,
+    pub side: NoteSide,
+}
+
+impl Note {
+    pub fn new(text: String) -> Self {
+        Self {
+            id: None,
+            text,
+            anchor_id: None,
+            side: NoteSide::default(),
+        }
+    }
+}
+
+/// Where a note sits relative to its anchor
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum NoteSide {
+    Left,
+    #[default]
+    Right,
+    Top,
+    Bottom,
 }
@@ -0,0 +1,401 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+/// Plans are followed over ten years at most, longer durations are not scheduled
+/// and days past them are not drawn
+pub const MAX_DAYS: u32 = 3660;
+
+/// A project plan, each task starting either on a given date or from another task.
+/// Dates are only known once scheduled, see `Gantt::schedule`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Gantt {
+    // Tasks without a start start here, the day numbers are shown when unknown
+    pub start: Option<Date>,
+    pub tasks: Vec<GanttTask>,
+    // Days on which no work is done, tasks last longer when going over them
+    pub closed_days: Vec<ClosedDays>,
+}
+
+impl Gantt {
+    pub fn new(start: Option<Date>, tasks: Vec<GanttTask>) -> Self {
+        Self {
+            start,
+            tasks,
+            closed_days: Vec::new(),
+        }
+    }
+
+    /// Dates of every task, in the same order. Fails when a task starts from one that
+    /// is not in the plan or, in the end, from itself, e.g., "[A] starts at [B]'s end"
+    /// along with "[B] starts at [A]'s end".
+    pub fn schedule(&self) -> Result<Vec<ScheduledTask>, ScheduleError> {
+        let mut scheduled: HashMap<&str, ScheduledTask> = HashMap::new();
+
+        for task in self.tasks.iter() {
+            self.schedule_task(task, &mut Vec::new(), &mut scheduled)?;
+        }
+
+        Ok(self
+            .tasks
+            .iter()
+            .filter_map(|task| scheduled.get(task.id.as_str()).cloned())
+            .collect())
+    }
+
+    /// Whether no work is done on the given day
+    pub fn is_closed(&self, date: &Date) -> bool {
+        self.closed_days.iter().any(|closed| match closed {
+            ClosedDays::Weekday(weekday) => date.weekday() == *weekday,
+            ClosedDays::Range(first, last) => first <= date && date <= last,
+        })
+    }
+
+    // The tasks being scheduled, from the first one asked for, are kept in `path`
+    fn schedule_task<'a>(
+        &'a self,
+        task: &'a GanttTask,
+        path: &mut Vec<&'a str>,
+        scheduled: &mut HashMap<&'a str, ScheduledTask>,
+    ) -> Result<ScheduledTask, ScheduleError> {
+        if let Some(done) = scheduled.get(task.id.as_str()) {
+            return Ok(done.clone());
+        }
+        if let Some(index) = path.iter().position(|id| *id == task.id) {
+            let mut cycle: Vec<String> = path[index..].iter().map(|id| id.to_string()).collect();
+            cycle.push(task.id.clone());
+
+            return Err(ScheduleError::Cycle(cycle));
+        }
+
+        path.push(&task.id);
+        let from: Date = match &task.start {
+            TaskStart::ProjectStart => self.start.unwrap_or_default(),
+            TaskStart::On(date) => *date,
+            TaskStart::After(id, anchor, delay) => {
+                let other: &GanttTask = self
+                    .tasks
+                    .iter()
+                    .find(|t| &t.id == id)
+                    .ok_or(ScheduleError::UnknownTask(id.clone()))?;
+                let other: ScheduledTask = self.schedule_task(other, path, scheduled)?;
+                let date: Date = match anchor {
+                    TaskAnchor::Start => other.start,
+                    // Milestones take no time, the next task may start on the same day
+                    TaskAnchor::End if other.milestone => other.end,
+                    TaskAnchor::End => other.end.add_days(1),
+                };
+
+                self.add_open_days(date, *delay)
+            }
+        };
+        path.pop();
+
+        let start: Date = self.next_open_day(from);
+        let end: Date = match task.milestone || task.duration == 0 {
+            true => start,
+            false => self.add_open_days(start, task.duration - 1),
+        };
+        let done: ScheduledTask = ScheduledTask {
+            id: task.id.clone(),
+            name: task.name.clone(),
+            milestone: task.milestone,
+            start,
+            end,
+        };
+
+        scheduled.insert(&task.id, done.clone());
+        Ok(done)
+    }
+
+    fn next_open_day(&self, date: Date) -> Date {
+        // Gives up after ten years, in case every day is closed
+        (0..MAX_DAYS)
+            .map(|days| date.add_days(days))
+            .find(|day| !self.is_closed(day))
+            .unwrap_or(date)
+    }
+
+    // The open day coming `days` open days after the given one
+    fn add_open_days(&self, date: Date, days: u32) -> Date {
+        let mut date: Date = self.next_open_day(date);
+
+        for _ in 0..days {
+            date = self.next_open_day(date.add_days(1));
+        }
+
+        date
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GanttTask {
+    pub id: String,
+    pub name: String,
+    // Happens on a single day and is drawn as a diamond
+    pub milestone: bool,
+    // In open days
+    pub duration: u32,
+    pub start: TaskStart,
+}
+
+impl GanttTask {
+    pub fn new(id: String, name: String, duration: u32) -> Self {
+        Self {
+            id,
+            name,
+            milestone: false,
+            duration,
+            start: TaskStart::ProjectStart,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum TaskStart {
+    #[default]
+    ProjectStart,
+    On(Date),
+    // Some open days after the start or the end of another task, by id
+    After(String, TaskAnchor, u32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskAnchor {
+    Start,
+    End,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClosedDays {
+    // Every week, e.g., "saturday are closed"
+    Weekday(Weekday),
+    // From the first day to the last one, both included
+    Range(Date, Date),
+}
+
+/// A task once scheduled, from its first day to its last one
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledTask {
+    pub id: String,
+    pub name: String,
+    pub milestone: bool,
+    pub start: Date,
+    pub end: Date,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScheduleError {
+    // Ids of the tasks starting from each other, the first one being repeated at the end
+    Cycle(Vec<String>),
+    UnknownTask(String),
+}
+
+impl Display for ScheduleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduleError::Cycle(ids) => {
+                let ids: Vec<String> = ids.iter().map(|id| format!("[{}]", id)).collect();
+
+                write!(f, "tasks start from each other: {}", ids.join(" -> "))
+            }
+            ScheduleError::UnknownTask(id) => write!(f, "unknown task: [{}]", id),
+        }
+    }
+}
+
+/// A day of the proleptic Gregorian calendar, 1970-01-01 by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Date {
+    // Since 1970-01-01
+    days: i64,
+}
+
+impl Date {
+    /// Out of range months and days carry over, e.g., 2026-01-32 is 2026-02-01
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        // Years start in March so that the leap day comes last
+        let (year, month) = (
+            year as i64 + (month as i64 - 1).div_euclid(12),
+            (month as i64 - 1).rem_euclid(12) + 1,
+        );
+        let year: i64 = if month <= 2 { year - 1 } else { year };
+        let era: i64 = year.div_euclid(400);
+        let year_of_era: i64 = year - era * 400;
+        let day_of_year: i64 = (153 * ((month + 9) % 12) + 2) / 5 + day as i64 - 1;
+        let day_of_era: i64 = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        Self {
+            days: era * 146097 + day_of_era - 719468,
+        }
+    }
+
+    pub fn year(&self) -> i32 {
+        self.civil().0
+    }
+
+    pub fn month(&self) -> u32 {
+        self.civil().1
+    }
+
+    pub fn day(&self) -> u32 {
+        self.civil().2
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        match (self.days + 3).rem_euclid(7) {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+
+    pub fn add_days(&self, days: u32) -> Self {
+        Self {
+            days: self.days + days as i64,
+        }
+    }
+
+    /// Negative when the other day comes first
+    pub fn days_until(&self, other: &Date) -> i64 {
+        other.days - self.days
+    }
+
+    fn civil(&self) -> (i32, u32, u32) {
+        let days: i64 = self.days + 719468;
+        let era: i64 = days.div_euclid(146097);
+        let day_of_era: i64 = days - era * 146097;
+        let year_of_era: i64 =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year: i64 =
+            day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month: i64 = (5 * day_of_year + 2) / 153;
+        let day: i64 = day_of_year - (153 * month + 2) / 5 + 1;
+        let month: i64 = if month < 10 { month + 3 } else { month - 9 };
+        let year: i64 = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        (year as i32, month as u32, day as u32)
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (year, month, day) = self.civil();
+
+        write!(f, "{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn task(id: &str, duration: u32, start: TaskStart) -> GanttTask {
+        GanttTask {
+            start,
+            ..GanttTask::new(id.to_owned(), id.to_owned(), duration)
+        }
+    }
+
+    fn after_end(id: &str) -> TaskStart {
+        TaskStart::After(id.to_owned(), TaskAnchor::End, 0)
+    }
+
+    #[test]
+    fn dates_should_round_trip() {
+        let date: Date = Date::new(2024, 2, 29);
+
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert_eq!(date.weekday(), Weekday::Thursday);
+        assert_eq!(date.add_days(1), Date::new(2024, 3, 1));
+        assert_eq!(Date::new(2026, 13, 1), Date::new(2027, 1, 1));
+        assert_eq!(Date::default().days_until(&Date::new(1969, 12, 31)), -1);
+    }
+
+    #[test]
+    fn schedule_should_skip_closed_days() {
+        // 2026-01-01 is a Thursday
+        let gantt: Gantt = Gantt {
+            closed_days: vec![
+                ClosedDays::Weekday(Weekday::Saturday),
+                ClosedDays::Weekday(Weekday::Sunday),
+                ClosedDays::Range(Date::new(2026, 1, 6), Date::new(2026, 1, 6)),
+            ],
+            ..Gantt::new(
+                Some(Date::new(2026, 1, 1)),
+                vec![
+                    task("B", 2, after_end("A")),
+                    task("A", 3, TaskStart::ProjectStart),
+                    GanttTask {
+                        milestone: true,
+                        ..task("M", 0, after_end("B"))
+                    },
+                ],
+            )
+        };
+
+        let result = gantt.schedule();
+
+        let scheduled = |id: &str, milestone: bool, start: Date, end: Date| ScheduledTask {
+            id: id.to_owned(),
+            name: id.to_owned(),
+            milestone,
+            start,
+            end,
+        };
+        assert_eq!(
+            result,
+            Ok(vec![
+                scheduled("B", false, Date::new(2026, 1, 7), Date::new(2026, 1, 8)),
+                scheduled("A", false, Date::new(2026, 1, 1), Date::new(2026, 1, 5)),
+                scheduled("M", true, Date::new(2026, 1, 9), Date::new(2026, 1, 9)),
+            ])
+        );
+    }
+
+    #[test]
+    fn schedule_should_report_cycles() {
+        let gantt: Gantt = Gantt::new(
+            None,
+            vec![
+                task("A", 1, TaskStart::ProjectStart),
+                task("B", 1, after_end("C")),
+                task("C", 1, after_end("B")),
+            ],
+        );
+
+        let result = gantt.schedule();
+
+        assert_eq!(
+            result,
+            Err(ScheduleError::Cycle(vec![
+                "B".to_owned(),
+                "C".to_owned(),
+                "B".to_owned()
+            ]))
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "tasks start from each other: [B] -> [C] -> [B]"
+        );
+    }
+}
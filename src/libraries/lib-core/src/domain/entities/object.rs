@@ -0,0 +1,36 @@
+/// An object or a map, drawn as a table of its fields and their values
+#[derive(Debug, Clone, PartialEq)]
+pub struct Object {
+    pub kind: ObjectKind,
+    pub name: String,
+    pub fields: Vec<Field>,
+}
+
+impl Object {
+    pub fn new(kind: ObjectKind, name: String) -> Self {
+        Self {
+            kind,
+            name,
+            fields: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObjectKind {
+    Object, // object A { id = 1 }
+    Map,    // map A { id => 1 }
+}
+
+/// A field of an object or an entry of a map
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub value: Option<String>,
+}
+
+impl Field {
+    pub fn new(name: String, value: Option<String>) -> Self {
+        Self { name, value }
+    }
+}
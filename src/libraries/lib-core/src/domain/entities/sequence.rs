@@ -0,0 +1,131 @@
+/// Participants exchanging messages over time, read from top to bottom
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Sequence {
+    pub participants: Vec<Participant>,
+    pub events: Vec<SequenceEvent>,
+}
+
+impl Sequence {
+    pub fn new(participants: Vec<Participant>, events: Vec<SequenceEvent>) -> Self {
+        Self {
+            participants,
+            events,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Participant {
+    pub id: String,
+    pub r#type: ParticipantType,
+    pub stereotypes: Vec<String>,
+}
+
+impl Participant {
+    pub fn new(id: String, r#type: ParticipantType) -> Self {
+        Self {
+            id,
+            r#type,
+            stereotypes: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParticipantType {
+    // Name
+    Participant(String),
+    Actor(String),
+    Boundary(String),
+    Control(String),
+    Entity(String),
+    Database(String),
+    Collections(String),
+    Queue(String),
+}
+
+impl ParticipantType {
+    pub fn name(&self) -> &str {
+        match self {
+            ParticipantType::Participant(name)
+            | ParticipantType::Actor(name)
+            | ParticipantType::Boundary(name)
+            | ParticipantType::Control(name)
+            | ParticipantType::Entity(name)
+            | ParticipantType::Database(name)
+            | ParticipantType::Collections(name)
+            | ParticipantType::Queue(name) => name,
+        }
+    }
+}
+
+/// Events are kept in the order they were written; fragments are delimited by their
+/// start, else and end events rather than nested, as PlantUML writes them.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SequenceEvent {
+    Message(Message),
+    // Participant id
+    Activate(String),
+    Deactivate(String),
+    Destroy(String),
+    // Kind, Label
+    FragmentStart(FragmentKind, Option<String>),
+    // Label
+    FragmentElse(Option<String>),
+    FragmentEnd,
+    // Label, e.g., "== Initialization =="
+    Divider(String),
+    // Label, e.g., "...5 minutes later..."
+    Delay(Option<String>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub from_id: String,
+    pub to_id: String,
+    pub kind: MessageKind,
+    pub label: Option<String>,
+}
+
+impl Message {
+    pub fn new(from_id: String, to_id: String, kind: MessageKind, label: Option<String>) -> Self {
+        Self {
+            from_id,
+            to_id,
+            kind,
+            label,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageKind {
+    Sync,   // ->
+    Async,  // ->>
+    Return, // -->
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FragmentKind {
+    Alt,
+    Opt,
+    Loop,
+    Par,
+    Break,
+    Critical,
+    Group,
+}
+
+impl FragmentKind {
+    pub fn keyword(&self) -> &str {
+        match self {
+            FragmentKind::Alt => "alt",
+            FragmentKind::Opt => "opt",
+            FragmentKind::Loop => "loop",
+            FragmentKind::Par => "par",
+            FragmentKind::Break => "break",
+            FragmentKind::Critical => "critical",
+            FragmentKind::Group => "group",
+        }
+    }
+}
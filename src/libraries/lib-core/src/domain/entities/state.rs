@@ -0,0 +1,28 @@
+/// A state of a state machine, or one of its pseudo-states
+#[derive(Debug, Clone, PartialEq)]
+pub struct State {
+    pub kind: StateKind,
+    pub name: String,
+    // One entry per line, e.g., "waiting for input" for "Idle : waiting for input"
+    pub description: Vec<String>,
+}
+
+impl State {
+    pub fn new(kind: StateKind, name: String) -> Self {
+        Self {
+            kind,
+            name,
+            description: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateKind {
+    Simple,
+    Initial, // [*] -->
+    Final,   // --> [*]
+    Fork,    // <<fork>>
+    Join,    // <<join>>
+    Choice,  // <<choice>>
+}
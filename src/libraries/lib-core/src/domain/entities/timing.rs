@@ -0,0 +1,97 @@
+/// Participants whose state changes over time, e.g., a signal going high at 100
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Timing {
+    pub participants: Vec<TimingParticipant>,
+    pub constraints: Vec<TimeConstraint>,
+}
+
+impl Timing {
+    pub fn new(participants: Vec<TimingParticipant>, constraints: Vec<TimeConstraint>) -> Self {
+        Self {
+            participants,
+            constraints,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimingParticipant {
+    pub id: String,
+    pub name: String,
+    pub kind: TimingKind,
+    // Ordered by time
+    pub changes: Vec<StateChange>,
+}
+
+impl TimingParticipant {
+    pub fn new(id: String, name: String, kind: TimingKind) -> Self {
+        Self {
+            id,
+            name,
+            kind,
+            changes: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimingKind {
+    // Each state on a level of its own
+    Robust,
+    // States one after the other on a single band
+    Concise,
+    // Goes high at every period from its offset, for as long as the pulse
+    Clock(Clock),
+    // Either "high" or "low"
+    Binary,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clock {
+    pub period: u32,
+    pub pulse: u32,
+    pub offset: u32,
+}
+
+impl Clock {
+    /// The pulse lasts half of the period unless told otherwise
+    pub fn new(period: u32) -> Self {
+        Self {
+            period,
+            pulse: period / 2,
+            offset: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateChange {
+    pub time: u32,
+    pub state: String,
+}
+
+impl StateChange {
+    pub fn new(time: u32, state: String) -> Self {
+        Self { time, state }
+    }
+}
+
+/// A duration between two times of a participant, e.g., "{50 ms lag}"
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeConstraint {
+    pub participant_id: String,
+    pub start: u32,
+    pub end: u32,
+    pub label: Option<String>,
+}
+
+impl TimeConstraint {
+    pub fn new(participant_id: String, start: u32, end: u32, label: Option<String>) -> Self {
+        Self {
+            participant_id,
+            start,
+            end,
+            label,
+        }
+    }
+}
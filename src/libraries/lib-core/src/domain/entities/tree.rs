@@ -0,0 +1,45 @@
+/// A mind map or a work breakdown structure, each node broken down into the nodes under it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tree {
+    pub kind: TreeKind,
+    // Usually a single one
+    pub roots: Vec<TreeNode>,
+}
+
+impl Tree {
+    pub fn new(kind: TreeKind, roots: Vec<TreeNode>) -> Self {
+        Self { kind, roots }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TreeKind {
+    MindMap, // @startmindmap
+    Wbs,     // @startwbs
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TreeNode {
+    // May span several lines
+    pub text: String,
+    // Where the node branches off its parent, only telling for the children of a mind map root
+    pub side: TreeSide,
+    pub children: Vec<TreeNode>,
+}
+
+impl TreeNode {
+    pub fn new(text: String, children: Vec<TreeNode>) -> Self {
+        Self {
+            text,
+            side: TreeSide::default(),
+            children,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum TreeSide {
+    #[default]
+    Right,
+    Left,
+}
@@ -1,10 +1,65 @@
-use crate::domain::entities::diagram::Diagram;
+use std::path::Path;
+
+use crate::domain::entities::{diagnostic::Diagnostic, diagram::Diagram};
 
 pub trait DiagramRepository {
+    /// The first diagram of the content
     fn parse_from_content(&self, content: &str) -> Result<Diagram, DiagramRepositoryError>;
+
+    /// Every diagram of the content, in order
+    fn parse_all_from_content(&self, content: &str)
+    -> Result<Vec<Diagram>, DiagramRepositoryError>;
+
+    /// Same as `parse_from_content` for content read from `file_path`, which files it
+    /// includes are resolved against
+    fn parse_from_file_content(
+        &self,
+        content: &str,
+        _file_path: &Path,
+    ) -> Result<Diagram, DiagramRepositoryError> {
+        self.parse_from_content(content)
+    }
+
+    /// Same as `parse_all_from_content` for content read from `file_path`
+    fn parse_all_from_file_content(
+        &self,
+        content: &str,
+        _file_path: &Path,
+    ) -> Result<Vec<Diagram>, DiagramRepositoryError> {
+        self.parse_all_from_content(content)
+    }
+
+    /// Never fails: unreadable statements are skipped and reported as diagnostics,
+    /// so a partial diagram can be shown while the source is being edited
+    fn parse_from_content_recovering(&self, content: &str) -> RecoveredDiagram;
+
+    /// Same as `parse_from_content_recovering` for content read from `file_path`
+    fn parse_from_file_content_recovering(
+        &self,
+        content: &str,
+        _file_path: &Path,
+    ) -> RecoveredDiagram {
+        self.parse_from_content_recovering(content)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RecoveredDiagram {
+    pub diagram: Diagram,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl RecoveredDiagram {
+    pub fn new(diagram: Diagram, diagnostics: Vec<Diagnostic>) -> Self {
+        Self {
+            diagram,
+            diagnostics,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DiagramRepositoryError {
     Unknown(String),
+    Syntax(Diagnostic),
 }
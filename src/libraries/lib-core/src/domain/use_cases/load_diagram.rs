@@ -1,7 +1,7 @@
 use std::{path::Path, sync::Arc};
 
 use crate::domain::{
-    entities::diagram::Diagram,
+    entities::{diagnostic::Diagnostic, diagram::Diagram},
     repositories::{
         diagram_repository::{DiagramRepository, DiagramRepositoryError},
         file_repository::{FileRepository, FileRepositoryError},
@@ -30,7 +30,17 @@ impl<T> LoadDiagramUseCase<T> {
     pub fn execute(&self, source: &str) -> T {
         let result: LoadDiagramResult = self
             .load_from_local_fs_file(source)
-            .and_then(|content: String| self.parse_content(content));
+            .and_then(|content: String| self.parse_content(content, source));
+
+        self.presenter.present(result)
+    }
+
+    /// Loads one of the diagrams of a file holding several "@startuml" blocks
+    pub fn execute_selecting(&self, source: &str, selector: &DiagramSelector) -> T {
+        let result: LoadDiagramResult = self
+            .load_from_local_fs_file(source)
+            .and_then(|content: String| self.parse_all_content(content, source))
+            .and_then(|diagrams: Vec<Diagram>| self.select_diagram(diagrams, selector));
 
         self.presenter.present(result)
     }
@@ -41,11 +51,37 @@ impl<T> LoadDiagramUseCase<T> {
             .map_err(LoadDiagramError::from)
     }
 
-    fn parse_content(&self, content: String) -> Result<Diagram, LoadDiagramError> {
+    fn parse_content(&self, content: String, source: &str) -> Result<Diagram, LoadDiagramError> {
         self.diagram_repository
-            .parse_from_content(&content)
+            .parse_from_file_content(&content, Path::new(source))
             .map_err(LoadDiagramError::from)
     }
+
+    fn parse_all_content(
+        &self,
+        content: String,
+        source: &str,
+    ) -> Result<Vec<Diagram>, LoadDiagramError> {
+        self.diagram_repository
+            .parse_all_from_file_content(&content, Path::new(source))
+            .map_err(LoadDiagramError::from)
+    }
+
+    fn select_diagram(
+        &self,
+        diagrams: Vec<Diagram>,
+        selector: &DiagramSelector,
+    ) -> Result<Diagram, LoadDiagramError> {
+        let mut diagrams = diagrams.into_iter();
+
+        match selector {
+            DiagramSelector::Index(index) => diagrams.nth(*index),
+            DiagramSelector::Name(name) => {
+                diagrams.find(|diagram| diagram.name.as_ref() == Some(name))
+            }
+        }
+        .ok_or(LoadDiagramError::DiagramNotFound(selector.clone()))
+    }
 }
 
 trait LoadDiagramPresenter<T> {
@@ -54,10 +90,18 @@ trait LoadDiagramPresenter<T> {
 
 type LoadDiagramResult = Result<Diagram, LoadDiagramError>;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DiagramSelector {
+    Index(usize), // 0-based, in order of appearance
+    Name(String), // From "@startuml(id=name)" or "@startuml name"
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum LoadDiagramError {
     FileLoadError(String),
     ParseError(String),
+    InvalidSyntax(Diagnostic),
+    DiagramNotFound(DiagramSelector),
 }
 
 impl From<FileRepositoryError> for LoadDiagramError {
@@ -75,6 +119,9 @@ impl From<DiagramRepositoryError> for LoadDiagramError {
     fn from(value: DiagramRepositoryError) -> Self {
         match value {
             DiagramRepositoryError::Unknown(msg) => LoadDiagramError::ParseError(msg),
+            DiagramRepositoryError::Syntax(diagnostic) => {
+                LoadDiagramError::InvalidSyntax(diagnostic)
+            }
         }
     }
 }
@@ -85,13 +132,17 @@ mod test {
     use std::sync::Arc;
 
     use crate::domain::{
-        entities::diagram::Diagram,
+        entities::{
+            diagnostic::{Diagnostic, Span},
+            diagram::Diagram,
+        },
         repositories::{
-            diagram_repository::{DiagramRepository, DiagramRepositoryError},
+            diagram_repository::{DiagramRepository, DiagramRepositoryError, RecoveredDiagram},
             file_repository::{FileRepository, FileRepositoryError},
         },
         use_cases::load_diagram::{
-            LoadDiagramError, LoadDiagramPresenter, LoadDiagramResult, LoadDiagramUseCase,
+            DiagramSelector, LoadDiagramError, LoadDiagramPresenter, LoadDiagramResult,
+            LoadDiagramUseCase,
         },
     };
     use pretty_assertions::assert_eq;
@@ -161,6 +212,34 @@ mod test {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn should_forward_syntax_diagnostic_if_unable_to_parse_file() {
+        let file_path: &'static str = "invalid_syntax_file.puml";
+        let diagnostic: Diagnostic = Diagnostic {
+            expected: vec!["an arrow".to_owned()],
+            source_line: "a b".to_owned(),
+            ..Diagnostic::new("expected an arrow".to_owned(), Span::new(12, 13), 2, 3)
+        };
+        let diagram_repository_result: Option<Result<Diagram, DiagramRepositoryError>> =
+            Some(Err(DiagramRepositoryError::Syntax(diagnostic.clone())));
+        let expected: LoadDiagramResult = Err(LoadDiagramError::InvalidSyntax(diagnostic));
+
+        let file_repository: Arc<dyn FileRepository> = Arc::new(FileRepositoryMockImpl::new(Ok(
+            "@startuml\na b\n@enduml".to_owned(),
+        )));
+        let diagram_repository: Arc<dyn DiagramRepository> =
+            Arc::new(DiagramRepositoryMockImpl::new(diagram_repository_result));
+        let no_op_presenter: Arc<LoadDiagramPresenterNoOpImpl> =
+            Arc::new(LoadDiagramPresenterNoOpImpl);
+
+        let use_case: LoadDiagramUseCase<LoadDiagramResult> =
+            LoadDiagramUseCase::new(file_repository, diagram_repository, no_op_presenter);
+
+        let result: LoadDiagramResult = use_case.execute(file_path);
+
+        assert_eq!(expected, result);
+    }
+
     #[test]
     fn should_succeed_if_able_to_load_and_parse_file() {
         let file_path: &'static str = "valid_content_file.puml";
@@ -185,6 +264,58 @@ mod test {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn should_select_one_of_several_diagrams() {
+        let first: Diagram = Diagram {
+            name: Some("first".to_owned()),
+            ..Diagram::default()
+        };
+        let second: Diagram = Diagram {
+            name: Some("second".to_owned()),
+            ..Diagram::default()
+        };
+        let test_cases: Vec<(DiagramSelector, LoadDiagramResult)> = vec![
+            (DiagramSelector::Index(0), Ok(first.clone())),
+            (DiagramSelector::Index(1), Ok(second.clone())),
+            (
+                DiagramSelector::Index(2),
+                Err(LoadDiagramError::DiagramNotFound(DiagramSelector::Index(2))),
+            ),
+            (
+                DiagramSelector::Name("second".to_owned()),
+                Ok(second.clone()),
+            ),
+            (
+                DiagramSelector::Name("third".to_owned()),
+                Err(LoadDiagramError::DiagramNotFound(DiagramSelector::Name(
+                    "third".to_owned(),
+                ))),
+            ),
+        ];
+
+        for (selector, expected) in test_cases {
+            let file_path: &'static str = "several_diagrams_file.puml";
+            let diagram_repository_result: Option<Result<Vec<Diagram>, DiagramRepositoryError>> =
+                Some(Ok(vec![first.clone(), second.clone()]));
+
+            let file_repository: Arc<dyn FileRepository> = Arc::new(FileRepositoryMockImpl::new(
+                Ok("Several diagrams".to_owned()),
+            ));
+            let diagram_repository: Arc<dyn DiagramRepository> = Arc::new(
+                DiagramRepositoryMockImpl::new_all(diagram_repository_result),
+            );
+            let no_op_presenter: Arc<LoadDiagramPresenterNoOpImpl> =
+                Arc::new(LoadDiagramPresenterNoOpImpl);
+
+            let use_case: LoadDiagramUseCase<LoadDiagramResult> =
+                LoadDiagramUseCase::new(file_repository, diagram_repository, no_op_presenter);
+
+            let result: LoadDiagramResult = use_case.execute_selecting(file_path, &selector);
+
+            assert_eq!(expected, result);
+        }
+    }
+
     struct FileRepositoryMockImpl {
         result: Result<String, FileRepositoryError>,
     }
@@ -207,11 +338,22 @@ mod test {
     #[derive(Default)]
     struct DiagramRepositoryMockImpl {
         result: Option<Result<Diagram, DiagramRepositoryError>>,
+        all_result: Option<Result<Vec<Diagram>, DiagramRepositoryError>>,
     }
 
     impl DiagramRepositoryMockImpl {
         fn new(result: Option<Result<Diagram, DiagramRepositoryError>>) -> Self {
-            Self { result }
+            Self {
+                result,
+                ..Self::default()
+            }
+        }
+
+        fn new_all(all_result: Option<Result<Vec<Diagram>, DiagramRepositoryError>>) -> Self {
+            Self {
+                all_result,
+                ..Self::default()
+            }
         }
     }
 
@@ -223,6 +365,21 @@ mod test {
 
             panic!("Unexpected call to MockDiagramRepository.parse_from_content")
         }
+
+        fn parse_all_from_content(
+            &self,
+            _content: &str,
+        ) -> Result<Vec<Diagram>, DiagramRepositoryError> {
+            if let Some(value) = self.all_result.as_ref() {
+                return value.clone();
+            }
+
+            panic!("Unexpected call to MockDiagramRepository.parse_all_from_content")
+        }
+
+        fn parse_from_content_recovering(&self, _content: &str) -> RecoveredDiagram {
+            panic!("Unexpected call to MockDiagramRepository.parse_from_content_recovering")
+        }
     }
 
     #[derive(Default)]
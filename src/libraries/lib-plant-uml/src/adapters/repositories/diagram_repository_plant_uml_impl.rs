@@ -1,41 +1,233 @@
-use std::sync::Arc;
+use std::{collections::HashMap, path::Path, sync::Arc};
 
 use lib_core::domain::{
-    entities::diagram::{Diagram, Node, NodeType},
-    repositories::diagram_repository::{DiagramRepository, DiagramRepositoryError},
+    entities::{
+        activity::{Activity, ActivityStep, Branch, Loop},
+        class::{Classifier, ClassifierKind, Member, MemberKind, Visibility},
+        data::{Data, DataEntry, DataKind, DataValue},
+        diagnostic::Diagnostic,
+        diagram::{
+            Diagram, DiagramKind, Edge, EdgeDirection, EdgeStyle, Group, GroupType,
+            LayoutDirection, LineStyle, Node, NodeType, Note, NoteSide, Port, PortKind,
+        },
+        gantt::{ClosedDays, Date, Gantt, GanttTask, TaskAnchor, TaskStart, Weekday},
+        object::{Field, Object, ObjectKind},
+        sequence::{
+            FragmentKind, Message, MessageKind, Participant, ParticipantType, Sequence,
+            SequenceEvent,
+        },
+        state::{State, StateKind},
+        timing::{Clock, StateChange, TimeConstraint, Timing, TimingKind, TimingParticipant},
+        tree::{Tree, TreeKind, TreeNode, TreeSide},
+    },
+    repositories::diagram_repository::{
+        DiagramRepository, DiagramRepositoryError, RecoveredDiagram,
+    },
 };
 
-use crate::infra::models::plant_uml_diagram::{PlantUmlDiagram, PlantUmlElement};
+use crate::infra::models::{
+    plant_uml_diagram::{
+        PlantUmlActivationKind, PlantUmlActivityStep, PlantUmlArrowDirection, PlantUmlArrowHead,
+        PlantUmlArrowLine, PlantUmlClock, PlantUmlContainerKind, PlantUmlDataValue, PlantUmlDate,
+        PlantUmlDeclaration, PlantUmlDiagram, PlantUmlDiagramKind, PlantUmlDirectiveKind,
+        PlantUmlElement, PlantUmlElementKind, PlantUmlField, PlantUmlFragmentKind,
+        PlantUmlGanttStatement, PlantUmlLayoutDirection, PlantUmlMember, PlantUmlNote,
+        PlantUmlNoteSide, PlantUmlRelationship, PlantUmlTaskAnchor, PlantUmlTaskConstraint,
+        PlantUmlTaskStart, PlantUmlTime, PlantUmlTimingKind, PlantUmlTimingStep, PlantUmlTreeSide,
+        PlantUmlVisibility, PlantUmlWeekday,
+    },
+    plant_uml_source::PlantUmlSource,
+};
 
 struct DiagramRepositoryPlantUmlImpl {
     parser: Arc<dyn PlantUmlParser>,
+    // Without one, "!" directives are left for the parser to reject
+    preprocessor: Option<Arc<dyn PlantUmlPreprocessor>>,
 }
 
 impl DiagramRepositoryPlantUmlImpl {
     fn new(parser: Arc<dyn PlantUmlParser>) -> Self {
-        Self { parser }
+        Self {
+            parser,
+            preprocessor: None,
+        }
+    }
+
+    fn with_preprocessor(self, preprocessor: Arc<dyn PlantUmlPreprocessor>) -> Self {
+        Self {
+            preprocessor: Some(preprocessor),
+            ..self
+        }
+    }
+
+    fn preprocess(
+        &self,
+        content: &str,
+        origin: Option<&Path>,
+    ) -> Result<PlantUmlSource, PlantUmlPreprocessorError> {
+        match &self.preprocessor {
+            Some(preprocessor) => preprocessor.preprocess(content, origin),
+            None => Ok(PlantUmlSource::new(content.to_string(), Vec::new())),
+        }
+    }
+
+    // Without a preprocessor, the content is left for the parser to recover from
+    fn preprocess_recovering(
+        &self,
+        content: &str,
+        origin: Option<&Path>,
+    ) -> (PlantUmlSource, Vec<Diagnostic>) {
+        match &self.preprocessor {
+            Some(preprocessor) => preprocessor.preprocess_recovering(content, origin),
+            None => (
+                PlantUmlSource::new(content.to_string(), Vec::new()),
+                Vec::new(),
+            ),
+        }
     }
 
-    fn parse_plant_uml_script(&self, content: &str) -> Result<Diagram, DiagramRepositoryError> {
+    fn parse_plant_uml_script(
+        &self,
+        content: &str,
+        origin: Option<&Path>,
+    ) -> Result<Diagram, DiagramRepositoryError> {
+        let source: PlantUmlSource = self.preprocess(content, origin)?;
+
         self.parser
-            .parse(content)
-            .map_err(DiagramRepositoryError::from)
+            .parse(&source.text)
+            .map_err(|e| located_error(e, &source))
             .map(Diagram::from)
     }
+
+    fn parse_all_plant_uml_scripts(
+        &self,
+        content: &str,
+        origin: Option<&Path>,
+    ) -> Result<Vec<Diagram>, DiagramRepositoryError> {
+        let source: PlantUmlSource = self.preprocess(content, origin)?;
+
+        self.parser
+            .parse_all(&source.text)
+            .map_err(|e| located_error(e, &source))
+            .map(|diagrams| diagrams.into_iter().map(Diagram::from).collect())
+    }
+
+    fn parse_plant_uml_script_recovering(
+        &self,
+        content: &str,
+        origin: Option<&Path>,
+    ) -> RecoveredDiagram {
+        let (source, mut diagnostics) = self.preprocess_recovering(content, origin);
+        let (plant_uml_diagram, parser_diagnostics) = self.parser.parse_recovering(&source.text);
+
+        diagnostics.extend(parser_diagnostics.into_iter().map(|d| source.locate(d)));
+
+        RecoveredDiagram::new(Diagram::from(plant_uml_diagram), diagnostics)
+    }
 }
 
 pub(crate) trait PlantUmlParser {
+    // The first diagram of the input
     fn parse(&self, input: &str) -> Result<PlantUmlDiagram, PlantUmlParserError>;
+    fn parse_all(&self, input: &str) -> Result<Vec<PlantUmlDiagram>, PlantUmlParserError>;
+    fn parse_recovering(&self, input: &str) -> (PlantUmlDiagram, Vec<Diagnostic>);
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum PlantUmlParserError {
     UnknownError(String),
+    SyntaxError(Diagnostic),
+}
+
+/// Resolves "!include", "!define", variables, conditions and procedures ahead of parsing
+pub(crate) trait PlantUmlPreprocessor {
+    // Included files are looked up relative to `origin`, or to the working directory
+    fn preprocess(
+        &self,
+        input: &str,
+        origin: Option<&Path>,
+    ) -> Result<PlantUmlSource, PlantUmlPreprocessorError>;
+
+    // Lines which cannot be preprocessed are left out and reported, the others are kept
+    fn preprocess_recovering(
+        &self,
+        input: &str,
+        origin: Option<&Path>,
+    ) -> (PlantUmlSource, Vec<Diagnostic>);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum PlantUmlPreprocessorError {
+    // Each one located at the offending directive
+    IncludeError(Diagnostic),
+    IncludeCycle(Diagnostic),
+    InvalidDirective(Diagnostic),
 }
 
 impl DiagramRepository for DiagramRepositoryPlantUmlImpl {
     fn parse_from_content(&self, content: &str) -> Result<Diagram, DiagramRepositoryError> {
-        self.parse_plant_uml_script(content)
+        self.parse_plant_uml_script(content, None)
+    }
+
+    fn parse_all_from_content(
+        &self,
+        content: &str,
+    ) -> Result<Vec<Diagram>, DiagramRepositoryError> {
+        self.parse_all_plant_uml_scripts(content, None)
+    }
+
+    fn parse_from_file_content(
+        &self,
+        content: &str,
+        file_path: &Path,
+    ) -> Result<Diagram, DiagramRepositoryError> {
+        self.parse_plant_uml_script(content, Some(file_path))
+    }
+
+    fn parse_all_from_file_content(
+        &self,
+        content: &str,
+        file_path: &Path,
+    ) -> Result<Vec<Diagram>, DiagramRepositoryError> {
+        self.parse_all_plant_uml_scripts(content, Some(file_path))
+    }
+
+    fn parse_from_content_recovering(&self, content: &str) -> RecoveredDiagram {
+        self.parse_plant_uml_script_recovering(content, None)
+    }
+
+    fn parse_from_file_content_recovering(
+        &self,
+        content: &str,
+        file_path: &Path,
+    ) -> RecoveredDiagram {
+        self.parse_plant_uml_script_recovering(content, Some(file_path))
+    }
+}
+
+// Syntax errors are reported against the files the preprocessed lines come from
+fn located_error(error: PlantUmlParserError, source: &PlantUmlSource) -> DiagramRepositoryError {
+    match error {
+        PlantUmlParserError::SyntaxError(diagnostic) => {
+            DiagramRepositoryError::Syntax(source.locate(diagnostic))
+        }
+        error => DiagramRepositoryError::from(error),
+    }
+}
+
+impl From<PlantUmlPreprocessorError> for DiagramRepositoryError {
+    fn from(value: PlantUmlPreprocessorError) -> Self {
+        DiagramRepositoryError::Syntax(Diagnostic::from(value))
+    }
+}
+
+impl From<PlantUmlPreprocessorError> for Diagnostic {
+    fn from(value: PlantUmlPreprocessorError) -> Self {
+        match value {
+            PlantUmlPreprocessorError::IncludeError(diagnostic)
+            | PlantUmlPreprocessorError::IncludeCycle(diagnostic)
+            | PlantUmlPreprocessorError::InvalidDirective(diagnostic) => diagnostic,
+        }
     }
 }
 
@@ -43,138 +235,3487 @@ impl From<PlantUmlParserError> for DiagramRepositoryError {
     fn from(value: PlantUmlParserError) -> Self {
         match value {
             PlantUmlParserError::UnknownError(msg) => DiagramRepositoryError::Unknown(msg),
+            PlantUmlParserError::SyntaxError(diagnostic) => {
+                DiagramRepositoryError::Syntax(diagnostic)
+            }
         }
     }
 }
 
 impl From<PlantUmlDiagram> for Diagram {
     fn from(value: PlantUmlDiagram) -> Self {
-        Self {
-            title: None,
-            nodes: value.elements.iter().map(Node::from).collect(),
-            edges: Vec::new(),
+        // Relationships may reference an element either by its alias or by its name,
+        // so names are resolved to the id given to the matching node or group.
+        let mut ids_by_name: HashMap<String, String> = HashMap::new();
+        collect_ids_by_name(&value.elements, &mut ids_by_name);
+
+        let mut diagram: Diagram = Diagram {
+            name: value.name.clone(),
+            ..Diagram::default()
+        };
+        let tree_kind: Option<TreeKind> = match value.kind {
+            PlantUmlDiagramKind::MindMap => Some(TreeKind::MindMap),
+            PlantUmlDiagramKind::Wbs => Some(TreeKind::Wbs),
+            _ => None,
+        };
+        let data_kind: Option<DataKind> = match value.kind {
+            PlantUmlDiagramKind::Json => Some(DataKind::Json),
+            PlantUmlDiagramKind::Yaml => Some(DataKind::Yaml),
+            _ => None,
+        };
+
+        if let Some(kind) = tree_kind {
+            diagram.kind = DiagramKind::Tree(Tree::new(kind, collect_tree(&value.elements)));
+        } else if let Some(kind) = data_kind {
+            diagram.kind = DiagramKind::Data(Data::new(kind, collect_data(&value.elements)));
+        } else if value.kind == PlantUmlDiagramKind::Gantt {
+            diagram.kind = DiagramKind::Gantt(collect_gantt(&value.elements));
+        } else if is_timing(&value.elements) {
+            diagram.kind = DiagramKind::Timing(collect_timing(&value.elements));
+        } else if is_activity(&value.elements) {
+            let mut blocks: Vec<(OpenBlock, Vec<ActivityStep>)> = vec![(OpenBlock::Root, vec![])];
+            collect_activity(&value.elements, &ids_by_name, &mut blocks, &mut diagram);
+            while blocks.len() > 1 {
+                close_block(&mut blocks);
+            }
+            diagram.kind =
+                DiagramKind::Activity(Activity::new(blocks.pop().map(|b| b.1).unwrap_or_default()));
+        } else if is_sequence(&value.elements) {
+            let mut sequence: Sequence = Sequence::default();
+            collect_sequence(&value.elements, &ids_by_name, &mut sequence, &mut diagram);
+            diagram.kind = DiagramKind::Sequence(sequence);
+        } else if is_state_diagram(&value.elements) {
+            collect_states(&value.elements, None, &ids_by_name, &mut diagram);
+        } else {
+            collect_elements(&value.elements, None, &ids_by_name, &mut diagram);
+            attach_edges_to_ports(&mut diagram);
+            push_referenced_nodes(&mut diagram, is_class_diagram(&value.elements));
         }
+
+        // An interface is drawn as a circle among components but as a box among classes
+        if is_class_diagram(&value.elements) {
+            for node in diagram.nodes.iter_mut() {
+                if let NodeType::Interface(name) = &node.r#type {
+                    node.r#type =
+                        NodeType::Class(Classifier::new(ClassifierKind::Interface, name.clone()));
+                }
+            }
+        }
+        connect_lollipops(&mut diagram);
+
+        diagram
     }
 }
 
-impl From<&PlantUmlElement> for Node {
-    fn from(value: &PlantUmlElement) -> Self {
-        match value {
-            PlantUmlElement::Component(name, alias) => Node::new(
-                alias.clone().unwrap_or(name.clone()),
-                NodeType::Component(name.clone()),
-            ),
+// Each node goes under the last one read that is less deep than it
+fn collect_tree(elements: &[PlantUmlElement]) -> Vec<TreeNode> {
+    // From the root down to the last node read, with their depth
+    let mut open: Vec<(usize, TreeNode)> = Vec::new();
+    let mut roots: Vec<TreeNode> = Vec::new();
+
+    for element in elements {
+        let PlantUmlElement::TreeNode(tree_node) = element else {
+            continue;
+        };
+
+        while open
+            .last()
+            .is_some_and(|(depth, _)| *depth >= tree_node.depth)
+        {
+            close_tree_node(&mut open, &mut roots);
+        }
+
+        // A "*" node stays on the side of its parent, the children of the root going right
+        let side: TreeSide = match &tree_node.side {
+            Some(PlantUmlTreeSide::Right) => TreeSide::Right,
+            Some(PlantUmlTreeSide::Left) => TreeSide::Left,
+            None if open.len() > 1 => open
+                .last()
+                .map(|(_, parent)| parent.side.clone())
+                .unwrap_or_default(),
+            None => TreeSide::Right,
+        };
+
+        open.push((
+            tree_node.depth,
+            TreeNode {
+                side,
+                ..TreeNode::new(tree_node.text.clone(), Vec::new())
+            },
+        ));
+    }
+
+    while !open.is_empty() {
+        close_tree_node(&mut open, &mut roots);
+    }
+
+    roots
+}
+
+fn close_tree_node(open: &mut Vec<(usize, TreeNode)>, roots: &mut Vec<TreeNode>) {
+    if let Some((_, node)) = open.pop() {
+        match open.last_mut() {
+            Some((_, parent)) => parent.children.push(node),
+            None => roots.push(node),
         }
     }
 }
 
-#[cfg(test)]
-mod test {
-    use std::sync::Arc;
+fn collect_data(elements: &[PlantUmlElement]) -> DataValue {
+    let highlights: Vec<&[String]> = elements
+        .iter()
+        .filter_map(|element| match element {
+            PlantUmlElement::Highlight(path) => Some(path.as_slice()),
+            _ => None,
+        })
+        .collect();
 
-    use crate::{
-        adapters::repositories::diagram_repository_plant_uml_impl::{
-            DiagramRepositoryPlantUmlImpl, PlantUmlParser, PlantUmlParserError,
-        },
-        infra::models::plant_uml_diagram::{PlantUmlDiagram, PlantUmlElement},
+    elements
+        .iter()
+        .find_map(|element| match element {
+            PlantUmlElement::Data(value) => Some(data_value(value, &highlights)),
+            _ => None,
+        })
+        .unwrap_or(DataValue::Null)
+}
+
+// Every entry along a highlighted path is highlighted, not only the last one
+fn data_value(value: &PlantUmlDataValue, highlights: &[&[String]]) -> DataValue {
+    let entry = |key: String, value: &PlantUmlDataValue| {
+        let rest: Vec<&[String]> = highlights
+            .iter()
+            .filter_map(|path| path.split_first())
+            .filter(|(first, _)| **first == key)
+            .map(|(_, rest)| rest)
+            .collect();
+
+        DataEntry {
+            highlighted: !rest.is_empty(),
+            ..DataEntry::new(key, data_value(value, &rest))
+        }
     };
-    use lib_core::domain::{
-        entities::diagram::{Diagram, Node, NodeType},
-        repositories::diagram_repository::{DiagramRepository, DiagramRepositoryError},
+
+    match value {
+        PlantUmlDataValue::Null => DataValue::Null,
+        PlantUmlDataValue::Boolean(value) => DataValue::Boolean(*value),
+        PlantUmlDataValue::Number(value) => DataValue::Number(value.clone()),
+        PlantUmlDataValue::Text(value) => DataValue::Text(value.clone()),
+        PlantUmlDataValue::Array(items) => DataValue::Array(
+            items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| entry(index.to_string(), item))
+                .collect(),
+        ),
+        PlantUmlDataValue::Object(entries) => DataValue::Object(
+            entries
+                .iter()
+                .map(|(key, value)| entry(key.clone(), value))
+                .collect(),
+        ),
+    }
+}
+
+// Several statements may be about the same task, e.g., "[A] lasts 2 days" then
+// "[A] starts 2026-01-05". Tasks are referenced by their name or their alias, and given
+// their name as id. A task given no duration lasts a day, milestones none.
+fn collect_gantt(elements: &[PlantUmlElement]) -> Gantt {
+    let mut gantt: Gantt = Gantt::default();
+    let mut names_by_alias: HashMap<String, String> = HashMap::new();
+    let mut previous: Option<String> = None;
+
+    for element in elements {
+        let PlantUmlElement::Gantt(statement) = element else {
+            continue;
+        };
+
+        let task = match statement {
+            PlantUmlGanttStatement::ProjectStart(date) => {
+                gantt.start = Some(date_from(date));
+                continue;
+            }
+            PlantUmlGanttStatement::ClosedWeekday(weekday) => {
+                gantt
+                    .closed_days
+                    .push(ClosedDays::Weekday(weekday_from(weekday)));
+                continue;
+            }
+            PlantUmlGanttStatement::ClosedDates(first, last) => {
+                gantt
+                    .closed_days
+                    .push(ClosedDays::Range(date_from(first), date_from(last)));
+                continue;
+            }
+            PlantUmlGanttStatement::Task(task) => task,
+        };
+
+        let name: String = names_by_alias.get(&task.name).unwrap_or(&task.name).clone();
+        if let Some(alias) = &task.alias {
+            names_by_alias.insert(alias.clone(), name.clone());
+        }
+        let index: usize = match gantt.tasks.iter().position(|t| t.id == name) {
+            Some(index) => index,
+            None => {
+                gantt
+                    .tasks
+                    .push(GanttTask::new(name.clone(), name.clone(), 1));
+                gantt.tasks.len() - 1
+            }
+        };
+        let resolve = |start: &PlantUmlTaskStart| match start {
+            PlantUmlTaskStart::On(date) => TaskStart::On(date_from(date)),
+            PlantUmlTaskStart::After(other, anchor, days) => TaskStart::After(
+                names_by_alias.get(other).unwrap_or(other).clone(),
+                match anchor {
+                    PlantUmlTaskAnchor::Start => TaskAnchor::Start,
+                    PlantUmlTaskAnchor::End => TaskAnchor::End,
+                },
+                *days,
+            ),
+        };
+        let gantt_task: &mut GanttTask = &mut gantt.tasks[index];
+
+        if let (true, Some(previous)) = (task.then, &previous) {
+            gantt_task.start = TaskStart::After(previous.clone(), TaskAnchor::End, 0);
+        }
+        for constraint in task.constraints.iter() {
+            match constraint {
+                PlantUmlTaskConstraint::Lasts(days) => gantt_task.duration = *days,
+                PlantUmlTaskConstraint::Starts(start) => gantt_task.start = resolve(start),
+                PlantUmlTaskConstraint::Happens(start) => {
+                    gantt_task.milestone = true;
+                    gantt_task.duration = 0;
+                    gantt_task.start = resolve(start);
+                }
+            }
+        }
+
+        previous = Some(name);
+    }
+
+    gantt
+}
+
+fn date_from(date: &PlantUmlDate) -> Date {
+    Date::new(date.year, date.month, date.day)
+}
+
+fn weekday_from(weekday: &PlantUmlWeekday) -> Weekday {
+    match weekday {
+        PlantUmlWeekday::Monday => Weekday::Monday,
+        PlantUmlWeekday::Tuesday => Weekday::Tuesday,
+        PlantUmlWeekday::Wednesday => Weekday::Wednesday,
+        PlantUmlWeekday::Thursday => Weekday::Thursday,
+        PlantUmlWeekday::Friday => Weekday::Friday,
+        PlantUmlWeekday::Saturday => Weekday::Saturday,
+        PlantUmlWeekday::Sunday => Weekday::Sunday,
+    }
+}
+
+// A participant must be declared, and nodes or edges keep the diagram a graph so none of
+// them is dropped
+fn is_timing(elements: &[PlantUmlElement]) -> bool {
+    elements.iter().any(|element| {
+        matches!(
+            element,
+            PlantUmlElement::Timing(PlantUmlTimingStep::Participant(_))
+        )
+    }) && !elements.iter().any(|element| {
+        matches!(
+            element,
+            PlantUmlElement::Element(_)
+                | PlantUmlElement::Relationship(_)
+                | PlantUmlElement::Container(..)
+        )
+    })
+}
+
+// Times are resolved as they are read: "@+50" is 50 after the current time and,
+// after "@WB", "+50 is Busy" is 50 after the previous change of that block.
+// Steps past the last time are reported by the parser and left out.
+fn collect_timing(elements: &[PlantUmlElement]) -> Timing {
+    let mut timing: Timing = Timing::default();
+    let mut ids_by_name: HashMap<String, String> = HashMap::new();
+    let mut time: u32 = 0;
+    // Set by "@WB"
+    let mut block_id: Option<String> = None;
+    let resolve = |from: u32, time: &PlantUmlTime| match time {
+        PlantUmlTime::Absolute(time) => Some(*time),
+        PlantUmlTime::Relative(delay) => from.checked_add(*delay),
     };
-    use pretty_assertions::assert_eq;
 
-    #[test]
-    fn parse_from_content_should_return_expected_error_given_parser_error() {
-        let test_cases: Vec<(PlantUmlParserError, DiagramRepositoryError)> = vec![(
-            PlantUmlParserError::UnknownError("Parser unknown error".to_string()),
-            DiagramRepositoryError::Unknown("Parser unknown error".to_string()),
-        )];
+    for element in elements {
+        let PlantUmlElement::Timing(step) = element else {
+            continue;
+        };
 
-        for (parser_error, expected_repository_error) in test_cases {
-            let parser_result: Result<PlantUmlDiagram, PlantUmlParserError> = Err(parser_error);
+        match step {
+            PlantUmlTimingStep::Participant(participant) => {
+                let id: String = participant
+                    .alias
+                    .clone()
+                    .unwrap_or(participant.name.clone());
+                let kind: TimingKind = match &participant.kind {
+                    PlantUmlTimingKind::Robust => TimingKind::Robust,
+                    PlantUmlTimingKind::Concise => TimingKind::Concise,
+                    PlantUmlTimingKind::Binary => TimingKind::Binary,
+                    PlantUmlTimingKind::Clock => {
+                        let clock: Option<&PlantUmlClock> = participant.clock.as_ref();
+                        let default: Clock = Clock::new(clock.map_or(0, |c| c.period));
 
-            let parser: Arc<dyn PlantUmlParser> =
-                Arc::new(PlantUmlParserMockImpl::new(parser_result));
-            let repository: DiagramRepositoryPlantUmlImpl =
-                DiagramRepositoryPlantUmlImpl::new(parser);
+                        TimingKind::Clock(Clock {
+                            pulse: clock.and_then(|c| c.pulse).unwrap_or(default.pulse),
+                            offset: clock.and_then(|c| c.offset).unwrap_or(default.offset),
+                            ..default
+                        })
+                    }
+                };
 
-            let result = repository.parse_from_content("Some dummy content");
+                ids_by_name.insert(participant.name.clone(), id.clone());
+                timing.participants.push(TimingParticipant::new(
+                    id,
+                    participant.name.clone(),
+                    kind,
+                ));
+            }
+            PlantUmlTimingStep::At(at) => {
+                let Some(at) = resolve(time, at) else {
+                    continue;
+                };
 
-            assert_eq!(Err(expected_repository_error), result)
+                time = at;
+                block_id = None;
+            }
+            PlantUmlTimingStep::AtParticipant(name) => {
+                time = 0;
+                block_id = Some(ids_by_name.get(name).unwrap_or(name).clone());
+            }
+            PlantUmlTimingStep::StateChange(name, state) => {
+                let id: &String = ids_by_name.get(name).unwrap_or(name);
+
+                if let Some(participant) = timing.participants.iter_mut().find(|p| &p.id == id) {
+                    participant
+                        .changes
+                        .push(StateChange::new(time, state.clone()));
+                }
+            }
+            PlantUmlTimingStep::TimedStateChange(at, state) => {
+                let Some(at) = resolve(time, at) else {
+                    continue;
+                };
+
+                time = at;
+
+                if let Some(participant) = timing
+                    .participants
+                    .iter_mut()
+                    .find(|p| Some(&p.id) == block_id.as_ref())
+                {
+                    participant
+                        .changes
+                        .push(StateChange::new(time, state.clone()));
+                }
+            }
+            PlantUmlTimingStep::Constraint(name, start, end, label) => {
+                let Some((start, end)) = resolve(time, start)
+                    .and_then(|start| resolve(start, end).map(|end| (start, end)))
+                else {
+                    continue;
+                };
+
+                timing.constraints.push(TimeConstraint::new(
+                    ids_by_name.get(name).unwrap_or(name).clone(),
+                    start,
+                    end,
+                    label.clone(),
+                ));
+            }
         }
     }
 
-    #[test]
-    fn parse_from_content_should_return_expected_diagram_given_parsed_plant_uml_diagram() {
-        let test_cases: Vec<(PlantUmlDiagram, Diagram)> = vec![
-            (
-                PlantUmlDiagram::new(Vec::new()),
-                Diagram::new(None, Vec::new(), Vec::new()),
-            ),
-            (
-                PlantUmlDiagram::new(vec![PlantUmlElement::Component(
-                    "Some Component".to_owned(),
-                    None,
-                )]),
-                Diagram::new(
-                    None,
-                    vec![Node::new(
-                        "Some Component".to_owned(),
-                        NodeType::Component("Some Component".to_owned()),
-                    )],
-                    vec![],
-                ),
-            ),
-            (
-                PlantUmlDiagram::new(vec![
-                    PlantUmlElement::Component("Component A".to_owned(), Some("A".to_owned())),
-                    PlantUmlElement::Component("Component B".to_owned(), None),
-                ]),
-                Diagram::new(
-                    None,
-                    vec![
-                        Node::new(
-                            "A".to_owned(),
-                            NodeType::Component("Component A".to_owned()),
-                        ),
-                        Node::new(
-                            "Component B".to_owned(),
-                            NodeType::Component("Component B".to_owned()),
-                        ),
-                    ],
-                    vec![],
-                ),
-            ),
+    // Changes may be given out of order, e.g., when grouped by participant
+    for participant in timing.participants.iter_mut() {
+        participant.changes.sort_by_key(|change| change.time);
+    }
+
+    timing
+}
+
+fn collect_ids_by_name(elements: &[PlantUmlElement], ids_by_name: &mut HashMap<String, String>) {
+    for element in elements {
+        match element {
+            // An implicit declaration must not hide the alias of an explicit one
+            PlantUmlElement::Element(declaration) if declaration.implicit => {
+                ids_by_name
+                    .entry(declaration.name.clone())
+                    .or_insert(declaration_id(declaration));
+            }
+            PlantUmlElement::Element(declaration) => {
+                ids_by_name.insert(declaration.name.clone(), declaration_id(declaration));
+            }
+            PlantUmlElement::Container(_, name, alias, children) => {
+                ids_by_name.insert(name.clone(), alias.clone().unwrap_or(name.clone()));
+                collect_ids_by_name(children, ids_by_name);
+            }
+            PlantUmlElement::Note(PlantUmlNote {
+                alias: Some(alias), ..
+            }) => {
+                ids_by_name.insert(alias.clone(), alias.clone());
+            }
+            PlantUmlElement::Relationship(_)
+            | PlantUmlElement::Comment(_)
+            | PlantUmlElement::Directive(..)
+            | PlantUmlElement::Note(_)
+            | PlantUmlElement::Direction(_)
+            | PlantUmlElement::Activation(..)
+            | PlantUmlElement::FragmentStart(..)
+            | PlantUmlElement::FragmentElse(_)
+            | PlantUmlElement::FragmentEnd
+            | PlantUmlElement::Divider(_)
+            | PlantUmlElement::Delay(_)
+            | PlantUmlElement::StateDescription(..)
+            | PlantUmlElement::Activity(_)
+            | PlantUmlElement::Timing(_)
+            | PlantUmlElement::TreeNode(_)
+            | PlantUmlElement::Highlight(_)
+            | PlantUmlElement::Data(_)
+            | PlantUmlElement::Gantt(_) => {}
+        }
+    }
+}
+
+fn is_sequence(elements: &[PlantUmlElement]) -> bool {
+    has_sequence_statements(elements) || exchanges_messages(elements)
+}
+
+// PlantUML tells a sequence diagram by its statements: participants, activations,
+// fragments, dividers, delays or asynchronous messages, none of which a graph uses.
+fn has_sequence_statements(elements: &[PlantUmlElement]) -> bool {
+    elements.iter().any(|element| match element {
+        PlantUmlElement::Element(declaration) => matches!(
+            declaration.kind,
+            PlantUmlElementKind::Participant
+                | PlantUmlElementKind::Control
+                | PlantUmlElementKind::Entity
+                | PlantUmlElementKind::Collections
+        ),
+        PlantUmlElement::Relationship(relationship) => {
+            relationship.arrow.head_left == Some(PlantUmlArrowHead::Open)
+                || relationship.arrow.head_right == Some(PlantUmlArrowHead::Open)
+        }
+        PlantUmlElement::Container(.., children) => has_sequence_statements(children),
+        PlantUmlElement::Activation(..)
+        | PlantUmlElement::FragmentStart(..)
+        | PlantUmlElement::FragmentElse(_)
+        | PlantUmlElement::FragmentEnd
+        | PlantUmlElement::Divider(_)
+        | PlantUmlElement::Delay(_) => true,
+        PlantUmlElement::Comment(_)
+        | PlantUmlElement::StateDescription(..)
+        | PlantUmlElement::Activity(_)
+        | PlantUmlElement::Timing(_)
+        | PlantUmlElement::TreeNode(_)
+        | PlantUmlElement::Highlight(_)
+        | PlantUmlElement::Data(_)
+        | PlantUmlElement::Gantt(_)
+        | PlantUmlElement::Directive(..)
+        | PlantUmlElement::Note(_)
+        | PlantUmlElement::Direction(_) => false,
+    })
+}
+
+// Messages between participants created as they are used, e.g., "Alice -> Bob: Hello",
+// or sent by an actor, e.g., "actor User" then "User --> DB : query". Anything else
+// declared, such as a component, a use case or a state, keeps the diagram a graph.
+fn exchanges_messages(elements: &[PlantUmlElement]) -> bool {
+    let is_message = |relationship: &PlantUmlRelationship| {
+        let heads = [
+            &relationship.arrow.head_left,
+            &relationship.arrow.head_right,
         ];
 
-        for (plant_uml_diagram, expected_diagram) in test_cases {
-            let parser_result: Result<PlantUmlDiagram, PlantUmlParserError> = Ok(plant_uml_diagram);
+        relationship.from != TERMINAL_STATE
+            && relationship.to != TERMINAL_STATE
+            && heads.iter().any(|head| head.is_some())
+            && heads.iter().all(|head| {
+                matches!(
+                    head,
+                    None | Some(PlantUmlArrowHead::Arrow | PlantUmlArrowHead::Open)
+                )
+            })
+    };
+    let only_messages: bool = elements.iter().all(|element| match element {
+        PlantUmlElement::Element(declaration) => {
+            !declaration.implicit
+                && declaration.members.is_empty()
+                && matches!(
+                    declaration.kind,
+                    PlantUmlElementKind::Participant
+                        | PlantUmlElementKind::Actor
+                        | PlantUmlElementKind::Boundary
+                        | PlantUmlElementKind::Control
+                        | PlantUmlElementKind::Entity
+                        | PlantUmlElementKind::Database
+                        | PlantUmlElementKind::Collections
+                        | PlantUmlElementKind::Queue
+                )
+        }
+        PlantUmlElement::Relationship(relationship) => is_message(relationship),
+        PlantUmlElement::Comment(_) | PlantUmlElement::Directive(..) | PlantUmlElement::Note(_) => {
+            true
+        }
+        _ => false,
+    });
+    let has_short_message: bool = elements.iter().any(|element| {
+        matches!(element, PlantUmlElement::Relationship(relationship) if relationship.arrow.short)
+    });
+    let has_actor: bool = elements.iter().any(|element| {
+        matches!(
+            element,
+            PlantUmlElement::Element(declaration) if declaration.kind == PlantUmlElementKind::Actor
+        )
+    });
+    let has_message: bool = elements
+        .iter()
+        .any(|element| matches!(element, PlantUmlElement::Relationship(_)));
 
-            let parser: Arc<dyn PlantUmlParser> =
-                Arc::new(PlantUmlParserMockImpl::new(parser_result));
-            let repository: DiagramRepositoryPlantUmlImpl =
-                DiagramRepositoryPlantUmlImpl::new(parser);
+    only_messages && has_message && (has_short_message || has_actor)
+}
 
-            let result = repository.parse_from_content("Some dummy content");
+// A class diagram is told by its classifiers, class bodies or class relationships
+fn is_class_diagram(elements: &[PlantUmlElement]) -> bool {
+    let is_class_head = |head: &Option<PlantUmlArrowHead>| {
+        matches!(
+            head,
+            Some(
+                PlantUmlArrowHead::Extension
+                    | PlantUmlArrowHead::Composition
+                    | PlantUmlArrowHead::Aggregation
+            )
+        )
+    };
 
-            assert_eq!(Ok(expected_diagram), result)
+    elements.iter().any(|element| match element {
+        PlantUmlElement::Element(declaration) => {
+            !declaration.members.is_empty()
+                || matches!(
+                    declaration.kind,
+                    PlantUmlElementKind::Class
+                        | PlantUmlElementKind::AbstractClass
+                        | PlantUmlElementKind::Enum
+                )
+        }
+        PlantUmlElement::Relationship(relationship) => {
+            is_class_head(&relationship.arrow.head_left)
+                || is_class_head(&relationship.arrow.head_right)
+        }
+        PlantUmlElement::Container(.., children) => is_class_diagram(children),
+        _ => false,
+    })
+}
+
+// Participants are declared explicitly or the first time a message references them.
+// Containers have no meaning in a sequence so their content is read as if at the top level.
+fn collect_sequence(
+    elements: &[PlantUmlElement],
+    ids_by_name: &HashMap<String, String>,
+    sequence: &mut Sequence,
+    diagram: &mut Diagram,
+) {
+    for element in elements {
+        match element {
+            PlantUmlElement::Element(declaration) => {
+                let participant: Participant = Participant {
+                    stereotypes: declaration.stereotypes.clone(),
+                    ..Participant::new(
+                        declaration_id(declaration),
+                        participant_type_from(&declaration.kind, declaration.name.clone()),
+                    )
+                };
+
+                match sequence
+                    .participants
+                    .iter_mut()
+                    .find(|p| p.id == participant.id)
+                {
+                    Some(existing) => *existing = participant,
+                    None => sequence.participants.push(participant),
+                }
+            }
+            PlantUmlElement::Relationship(relationship) => {
+                let message: Message = message_from(relationship, ids_by_name);
+
+                for (id, name) in [
+                    (&message.from_id, &relationship.from),
+                    (&message.to_id, &relationship.to),
+                ] {
+                    if !sequence.participants.iter().any(|p| &p.id == id) {
+                        sequence.participants.push(Participant::new(
+                            id.clone(),
+                            ParticipantType::Participant(name.clone()),
+                        ));
+                    }
+                }
+
+                sequence.events.push(SequenceEvent::Message(message));
+            }
+            PlantUmlElement::Container(.., children) => {
+                collect_sequence(children, ids_by_name, sequence, diagram)
+            }
+            PlantUmlElement::Activation(kind, name) => sequence.events.push(match kind {
+                PlantUmlActivationKind::Activate => {
+                    SequenceEvent::Activate(resolve(ids_by_name, name))
+                }
+                PlantUmlActivationKind::Deactivate => {
+                    SequenceEvent::Deactivate(resolve(ids_by_name, name))
+                }
+                PlantUmlActivationKind::Destroy => {
+                    SequenceEvent::Destroy(resolve(ids_by_name, name))
+                }
+            }),
+            PlantUmlElement::FragmentStart(kind, label) => sequence.events.push(
+                SequenceEvent::FragmentStart(FragmentKind::from(kind), label.clone()),
+            ),
+            PlantUmlElement::FragmentElse(label) => sequence
+                .events
+                .push(SequenceEvent::FragmentElse(label.clone())),
+            PlantUmlElement::FragmentEnd => sequence.events.push(SequenceEvent::FragmentEnd),
+            PlantUmlElement::Divider(label) => {
+                sequence.events.push(SequenceEvent::Divider(label.clone()))
+            }
+            PlantUmlElement::Delay(label) => {
+                sequence.events.push(SequenceEvent::Delay(label.clone()))
+            }
+            PlantUmlElement::StateDescription(..)
+            | PlantUmlElement::Activity(_)
+            | PlantUmlElement::Timing(_)
+            | PlantUmlElement::TreeNode(_)
+            | PlantUmlElement::Highlight(_)
+            | PlantUmlElement::Data(_)
+            | PlantUmlElement::Gantt(_) => {}
+            PlantUmlElement::Comment(_)
+            | PlantUmlElement::Directive(..)
+            | PlantUmlElement::Note(_)
+            | PlantUmlElement::Direction(_) => {
+                collect_elements(std::slice::from_ref(element), None, ids_by_name, diagram)
+            }
         }
     }
+}
 
-    struct PlantUmlParserMockImpl {
-        result: Result<PlantUmlDiagram, PlantUmlParserError>,
+// PlantUML tells an activity diagram by its actions, branches, loops and other flow steps
+fn is_activity(elements: &[PlantUmlElement]) -> bool {
+    elements.iter().any(|element| match element {
+        PlantUmlElement::Activity(_) => true,
+        PlantUmlElement::Container(.., children) => is_activity(children),
+        _ => false,
+    })
+}
+
+// A block of the flow being read, its steps are kept next to it until it ends
+enum OpenBlock {
+    Root,
+    // Branches read so far, then the condition and label of the current one
+    If(Vec<Branch>, Option<String>, Option<String>),
+    // The labels and the condition are only known once a "repeat" block ends
+    While(Loop),
+    Repeat(Loop),
+    // Flows read so far
+    Fork(Vec<Vec<ActivityStep>>),
+}
+
+// Each line opens, splits or ends the innermost block, a line ending another kind of block
+// is ignored. Blocks left open at the end of the diagram are ended there.
+// Containers have no meaning in an activity so their content is read as if at the top level.
+fn collect_activity(
+    elements: &[PlantUmlElement],
+    ids_by_name: &HashMap<String, String>,
+    blocks: &mut Vec<(OpenBlock, Vec<ActivityStep>)>,
+    diagram: &mut Diagram,
+) {
+    for element in elements {
+        let Some((block, steps)) = blocks.last_mut() else {
+            return;
+        };
+
+        match element {
+            PlantUmlElement::Activity(step) => match (step, block) {
+                (PlantUmlActivityStep::Start, _) => steps.push(ActivityStep::Start),
+                (PlantUmlActivityStep::Stop, _) => steps.push(ActivityStep::Stop),
+                (PlantUmlActivityStep::Action(text), _) => {
+                    steps.push(ActivityStep::Action(text.clone()))
+                }
+                (PlantUmlActivityStep::Swimlane(name), _) => {
+                    steps.push(ActivityStep::Swimlane(name.clone()))
+                }
+                (PlantUmlActivityStep::If(condition, label), _) => blocks.push((
+                    OpenBlock::If(Vec::new(), Some(condition.clone()), label.clone()),
+                    Vec::new(),
+                )),
+                (
+                    PlantUmlActivityStep::ElseIf(next_condition, next_label),
+                    OpenBlock::If(branches, condition, label),
+                ) => {
+                    branches.push(Branch::new(
+                        condition.take(),
+                        label.take(),
+                        std::mem::take(steps),
+                    ));
+                    *condition = Some(next_condition.clone());
+                    *label = next_label.clone();
+                }
+                (PlantUmlActivityStep::EndIf, OpenBlock::If(..)) => close_block(blocks),
+                (PlantUmlActivityStep::While(condition, label), _) => blocks.push((
+                    OpenBlock::While(Loop {
+                        label: label.clone(),
+                        ..Loop::new(condition.clone(), Vec::new())
+                    }),
+                    Vec::new(),
+                )),
+                (PlantUmlActivityStep::EndWhile(exit_label), OpenBlock::While(l)) => {
+                    l.exit_label = exit_label.clone();
+                    close_block(blocks);
+                }
+                (PlantUmlActivityStep::Repeat, _) => blocks.push((
+                    OpenBlock::Repeat(Loop::new(String::new(), Vec::new())),
+                    Vec::new(),
+                )),
+                (
+                    PlantUmlActivityStep::RepeatWhile(condition, label, exit_label),
+                    OpenBlock::Repeat(l),
+                ) => {
+                    l.condition = condition.clone();
+                    l.label = label.clone();
+                    l.exit_label = exit_label.clone();
+                    close_block(blocks);
+                }
+                (PlantUmlActivityStep::Fork, _) => {
+                    blocks.push((OpenBlock::Fork(Vec::new()), Vec::new()))
+                }
+                (PlantUmlActivityStep::ForkAgain, OpenBlock::Fork(flows)) => {
+                    flows.push(std::mem::take(steps))
+                }
+                (PlantUmlActivityStep::EndFork, OpenBlock::Fork(_)) => close_block(blocks),
+                _ => {}
+            },
+            // "else (no)" is read as the else of a fragment, label parentheses included
+            PlantUmlElement::FragmentElse(next_label) => {
+                if let OpenBlock::If(branches, condition, label) = block {
+                    branches.push(Branch::new(
+                        condition.take(),
+                        label.take(),
+                        std::mem::take(steps),
+                    ));
+                    *label = next_label.as_deref().map(|l| {
+                        l.strip_prefix('(')
+                            .and_then(|l| l.strip_suffix(')'))
+                            .unwrap_or(l)
+                            .trim()
+                            .to_string()
+                    });
+                }
+            }
+            PlantUmlElement::FragmentEnd => steps.push(ActivityStep::End),
+            PlantUmlElement::Container(.., children) => {
+                collect_activity(children, ids_by_name, blocks, diagram)
+            }
+            _ => collect_elements(std::slice::from_ref(element), None, ids_by_name, diagram),
+        }
     }
+}
 
-    impl PlantUmlParserMockImpl {
-        fn new(result: Result<PlantUmlDiagram, PlantUmlParserError>) -> Self {
-            Self { result }
+// Ends the innermost block, which becomes a step of the enclosing one
+fn close_block(blocks: &mut Vec<(OpenBlock, Vec<ActivityStep>)>) {
+    if blocks.len() < 2 {
+        return;
+    }
+    let Some((block, steps)) = blocks.pop() else {
+        return;
+    };
+
+    let step: ActivityStep = match block {
+        OpenBlock::Root => return,
+        OpenBlock::If(mut branches, condition, label) => {
+            branches.push(Branch::new(condition, label, steps));
+            ActivityStep::If(branches)
         }
+        OpenBlock::While(l) => ActivityStep::While(Loop { steps, ..l }),
+        OpenBlock::Repeat(l) => ActivityStep::Repeat(Loop { steps, ..l }),
+        OpenBlock::Fork(mut flows) => {
+            flows.push(steps);
+            ActivityStep::Fork(flows)
+        }
+    };
+
+    if let Some((_, steps)) = blocks.last_mut() {
+        steps.push(step);
     }
+}
 
-    impl PlantUmlParser for PlantUmlParserMockImpl {
-        fn parse(&self, _input: &str) -> Result<PlantUmlDiagram, PlantUmlParserError> {
-            self.result.clone()
+// PlantUML tells a state diagram by its states, descriptions or [*] pseudo-states
+fn is_state_diagram(elements: &[PlantUmlElement]) -> bool {
+    elements.iter().any(|element| match element {
+        PlantUmlElement::Element(declaration) => declaration.kind == PlantUmlElementKind::State,
+        PlantUmlElement::Container(PlantUmlContainerKind::State, ..)
+        | PlantUmlElement::StateDescription(..) => true,
+        PlantUmlElement::Container(.., children) => is_state_diagram(children),
+        PlantUmlElement::Relationship(relationship) => {
+            relationship.from == TERMINAL_STATE || relationship.to == TERMINAL_STATE
+        }
+        _ => false,
+    })
+}
+
+const TERMINAL_STATE: &str = "[*]";
+
+// Composite states become the parent of the states declared or referenced in their body.
+// Each [*] is the initial state of its composite when an arrow leaves it and the final one
+// when an arrow reaches it, so it becomes two distinct nodes per composite.
+fn collect_states(
+    elements: &[PlantUmlElement],
+    parent_id: Option<&String>,
+    ids_by_name: &HashMap<String, String>,
+    diagram: &mut Diagram,
+) {
+    for element in elements {
+        match element {
+            PlantUmlElement::Element(declaration)
+                if declaration.kind == PlantUmlElementKind::State =>
+            {
+                let id: String = declaration_id(declaration);
+                let kind: Option<StateKind> = declaration
+                    .stereotypes
+                    .iter()
+                    .find_map(|s| state_kind_from(s));
+                let mut description: Vec<String> = known_description(&id, diagram);
+                description.extend(declaration.description.iter().cloned());
+                let node: Node = Node {
+                    parent_id: parent_id.cloned(),
+                    stereotypes: declaration
+                        .stereotypes
+                        .iter()
+                        .filter(|s| state_kind_from(s).is_none())
+                        .cloned()
+                        .collect(),
+                    ..Node::new(
+                        id,
+                        NodeType::State(State {
+                            description,
+                            ..State::new(
+                                kind.unwrap_or(StateKind::Simple),
+                                declaration.name.clone(),
+                            )
+                        }),
+                    )
+                };
+
+                push_node(node, false, diagram);
+            }
+            PlantUmlElement::Container(PlantUmlContainerKind::State, name, alias, children) => {
+                let id: String = alias.clone().unwrap_or(name.clone());
+
+                push_node(
+                    Node {
+                        parent_id: parent_id.cloned(),
+                        ..Node::new(
+                            id.clone(),
+                            NodeType::State(State {
+                                description: known_description(&id, diagram),
+                                ..State::new(StateKind::Simple, name.clone())
+                            }),
+                        )
+                    },
+                    false,
+                    diagram,
+                );
+                collect_states(children, Some(&id), ids_by_name, diagram);
+            }
+            PlantUmlElement::Relationship(relationship) => {
+                let mut edge: Edge = edge_from(relationship, ids_by_name);
+                // "a <-- [*]" starts from [*] just like "[*] --> a"
+                let reversed: bool = relationship.arrow.head_left.is_some()
+                    && relationship.arrow.head_right.is_none();
+
+                for (id, name, leaving) in [
+                    (&mut edge.from_id, &relationship.from, !reversed),
+                    (&mut edge.to_id, &relationship.to, reversed),
+                ] {
+                    if name == TERMINAL_STATE {
+                        let kind: StateKind = match leaving {
+                            true => StateKind::Initial,
+                            false => StateKind::Final,
+                        };
+                        *id = terminal_state_id(parent_id, &kind);
+                        push_state(id, State::new(kind, name.clone()), parent_id, diagram);
+                    } else {
+                        push_state(
+                            id,
+                            State::new(StateKind::Simple, name.clone()),
+                            parent_id,
+                            diagram,
+                        );
+                    }
+                }
+
+                diagram.edges.push(edge);
+            }
+            PlantUmlElement::StateDescription(name, text) => {
+                let id: String = resolve(ids_by_name, name);
+
+                push_state(
+                    &id,
+                    State::new(StateKind::Simple, name.clone()),
+                    parent_id,
+                    diagram,
+                );
+                if let Some(NodeType::State(state)) = diagram
+                    .nodes
+                    .iter_mut()
+                    .find(|node| node.id == id)
+                    .map(|node| &mut node.r#type)
+                {
+                    state.description.push(text.clone());
+                }
+            }
+            _ => collect_elements(
+                std::slice::from_ref(element),
+                parent_id,
+                ids_by_name,
+                diagram,
+            ),
+        }
+    }
+}
+
+// States referenced before being declared are created where they are first referenced
+fn push_state(id: &str, state: State, parent_id: Option<&String>, diagram: &mut Diagram) {
+    if !diagram.nodes.iter().any(|node| node.id == id) {
+        diagram.nodes.push(Node {
+            parent_id: parent_id.cloned(),
+            ..Node::new(id.to_owned(), NodeType::State(state))
+        });
+    }
+}
+
+// Descriptions given before the state is declared, e.g., "Idle : waiting" then "state Idle { ... }"
+fn known_description(id: &str, diagram: &Diagram) -> Vec<String> {
+    match diagram.nodes.iter().find(|node| node.id == id) {
+        Some(Node {
+            r#type: NodeType::State(state),
+            ..
+        }) => state.description.clone(),
+        _ => Vec::new(),
+    }
+}
+
+fn terminal_state_id(parent_id: Option<&String>, kind: &StateKind) -> String {
+    let side: &str = match kind {
+        StateKind::Initial => "initial",
+        _ => "final",
+    };
+
+    match parent_id {
+        Some(parent_id) => format!("{}.{}.{}", parent_id, TERMINAL_STATE, side),
+        None => format!("{}.{}", TERMINAL_STATE, side),
+    }
+}
+
+fn state_kind_from(stereotype: &str) -> Option<StateKind> {
+    match stereotype {
+        "fork" => Some(StateKind::Fork),
+        "join" => Some(StateKind::Join),
+        "choice" => Some(StateKind::Choice),
+        "start" => Some(StateKind::Initial),
+        "end" => Some(StateKind::Final),
+        _ => None,
+    }
+}
+
+fn resolve(ids_by_name: &HashMap<String, String>, reference: &String) -> String {
+    ids_by_name
+        .get(reference)
+        .cloned()
+        .unwrap_or(reference.clone())
+}
+
+fn participant_type_from(kind: &PlantUmlElementKind, name: String) -> ParticipantType {
+    match kind {
+        PlantUmlElementKind::Actor => ParticipantType::Actor(name),
+        PlantUmlElementKind::Boundary => ParticipantType::Boundary(name),
+        PlantUmlElementKind::Control => ParticipantType::Control(name),
+        PlantUmlElementKind::Entity => ParticipantType::Entity(name),
+        PlantUmlElementKind::Database => ParticipantType::Database(name),
+        PlantUmlElementKind::Collections => ParticipantType::Collections(name),
+        PlantUmlElementKind::Queue => ParticipantType::Queue(name),
+        _ => ParticipantType::Participant(name),
+    }
+}
+
+// "a <- b" is the message "b -> a"; dashed or dotted lines are returns, open heads asynchronous
+fn message_from(value: &PlantUmlRelationship, ids_by_name: &HashMap<String, String>) -> Message {
+    let (from, to, head) = match (&value.arrow.head_left, &value.arrow.head_right) {
+        (Some(head), None) => (&value.to, &value.from, Some(head)),
+        (_, head) => (&value.from, &value.to, head.as_ref()),
+    };
+    let kind: MessageKind = match (&value.arrow.line, value.arrow.short, head) {
+        (PlantUmlArrowLine::Dotted, ..) | (_, false, _) => MessageKind::Return,
+        (_, true, Some(PlantUmlArrowHead::Open)) => MessageKind::Async,
+        (_, true, _) => MessageKind::Sync,
+    };
+
+    Message::new(
+        resolve(ids_by_name, from),
+        resolve(ids_by_name, to),
+        kind,
+        value.label.clone(),
+    )
+}
+
+fn collect_elements(
+    elements: &[PlantUmlElement],
+    parent_id: Option<&String>,
+    ids_by_name: &HashMap<String, String>,
+    diagram: &mut Diagram,
+) {
+    for element in elements {
+        match element {
+            // Only drawn on the border of the component declaring them
+            PlantUmlElement::Element(declaration) if is_port(declaration) => {}
+            PlantUmlElement::Element(declaration) => {
+                let node: Node = Node {
+                    parent_id: parent_id.cloned(),
+                    stereotypes: declaration.stereotypes.clone(),
+                    ..Node::new(
+                        ids_by_name
+                            .get(&declaration.name)
+                            .filter(|_| declaration.implicit)
+                            .cloned()
+                            .unwrap_or(declaration_id(declaration)),
+                        node_type_from(declaration),
+                    )
+                };
+
+                push_node(node, declaration.implicit, diagram);
+            }
+            PlantUmlElement::Relationship(relationship) => {
+                diagram.edges.push(edge_from(relationship, ids_by_name))
+            }
+            // e.g., "component C { portin p }", a component with nothing inside but its ports
+            PlantUmlElement::Container(PlantUmlContainerKind::Component, name, alias, children)
+                if children.iter().all(|child| match child {
+                    PlantUmlElement::Element(declaration) => is_port(declaration),
+                    PlantUmlElement::Comment(_) => true,
+                    _ => false,
+                }) =>
+            {
+                let node: Node = Node {
+                    parent_id: parent_id.cloned(),
+                    ports: children
+                        .iter()
+                        .filter_map(|child| match child {
+                            PlantUmlElement::Element(declaration) => Some(port_from(declaration)),
+                            _ => None,
+                        })
+                        .collect(),
+                    ..Node::new(
+                        alias.clone().unwrap_or(name.clone()),
+                        NodeType::Component(name.clone()),
+                    )
+                };
+
+                push_node(node, false, diagram);
+            }
+            PlantUmlElement::Container(kind, name, alias, children) => {
+                let id: String = alias.clone().unwrap_or(name.clone());
+
+                diagram.groups.push(Group::new(
+                    id.clone(),
+                    group_type_from(kind, name.clone()),
+                    parent_id.cloned(),
+                ));
+                collect_elements(children, Some(&id), ids_by_name, diagram);
+            }
+            PlantUmlElement::Directive(kind, text) => {
+                let field: &mut Option<String> = match kind {
+                    PlantUmlDirectiveKind::Title => &mut diagram.title,
+                    PlantUmlDirectiveKind::Header => &mut diagram.header,
+                    PlantUmlDirectiveKind::Footer => &mut diagram.footer,
+                    PlantUmlDirectiveKind::Caption => &mut diagram.caption,
+                    PlantUmlDirectiveKind::Legend => &mut diagram.legend,
+                };
+
+                *field = Some(text.clone());
+            }
+            PlantUmlElement::Note(note) => diagram.notes.push(Note {
+                id: note.alias.clone(),
+                anchor_id: note
+                    .anchor
+                    .as_ref()
+                    .map(|anchor| ids_by_name.get(anchor).unwrap_or(anchor).clone()),
+                side: NoteSide::from(&note.side),
+                ..Note::new(note.text.clone())
+            }),
+            PlantUmlElement::Direction(direction) => {
+                diagram.direction = LayoutDirection::from(direction)
+            }
+            PlantUmlElement::Comment(_)
+            | PlantUmlElement::Activation(..)
+            | PlantUmlElement::FragmentStart(..)
+            | PlantUmlElement::FragmentElse(_)
+            | PlantUmlElement::FragmentEnd
+            | PlantUmlElement::Divider(_)
+            | PlantUmlElement::Delay(_)
+            | PlantUmlElement::StateDescription(..)
+            | PlantUmlElement::Activity(_)
+            | PlantUmlElement::Timing(_)
+            | PlantUmlElement::TreeNode(_)
+            | PlantUmlElement::Highlight(_)
+            | PlantUmlElement::Data(_)
+            | PlantUmlElement::Gantt(_) => {}
+        }
+    }
+}
+
+// The same element may be referenced several times with "[A]", and declared explicitly
+// before or after; only one node is kept, the explicit declaration taking precedence.
+fn push_node(node: Node, implicit: bool, diagram: &mut Diagram) {
+    match diagram.nodes.iter_mut().find(|n| n.id == node.id) {
+        Some(existing) if !implicit => *existing = node,
+        Some(_) => {}
+        None => diagram.nodes.push(node),
+    }
+}
+
+// Elements only referenced by relationships, e.g., "a --> b", are created as PlantUML
+// does, as classes in a class diagram and as rectangles otherwise
+fn push_referenced_nodes(diagram: &mut Diagram, as_classes: bool) {
+    let ends: Vec<String> = diagram
+        .edges
+        .iter()
+        .flat_map(|edge| [edge.from_id.clone(), edge.to_id.clone()])
+        .collect();
+
+    for id in ends {
+        let known: bool = diagram.nodes.iter().any(|node| node.id == id)
+            || diagram.groups.iter().any(|group| group.id == id)
+            || diagram
+                .notes
+                .iter()
+                .any(|note| note.id.as_ref() == Some(&id));
+
+        if !known {
+            let r#type: NodeType = match as_classes {
+                true => NodeType::Class(Classifier::new(ClassifierKind::Class, id.clone())),
+                false => NodeType::Rectangle(id.clone()),
+            };
+
+            diagram.nodes.push(Node::new(id, r#type));
+        }
+    }
+}
+
+fn is_port(declaration: &PlantUmlDeclaration) -> bool {
+    matches!(
+        declaration.kind,
+        PlantUmlElementKind::Port | PlantUmlElementKind::PortIn | PlantUmlElementKind::PortOut
+    )
+}
+
+fn port_from(declaration: &PlantUmlDeclaration) -> Port {
+    let kind: PortKind = match declaration.kind {
+        PlantUmlElementKind::PortIn => PortKind::In,
+        PlantUmlElementKind::PortOut => PortKind::Out,
+        _ => PortKind::InOut,
+    };
+
+    Port::new(declaration_id(declaration), declaration.name.clone(), kind)
+}
+
+// Edges reference a port like any other element, e.g., "a --> p1", and are connected
+// instead to the node owning it
+fn attach_edges_to_ports(diagram: &mut Diagram) {
+    let owners: HashMap<String, String> = diagram
+        .nodes
+        .iter()
+        .flat_map(|node| {
+            node.ports
+                .iter()
+                .map(|port| (port.id.clone(), node.id.clone()))
+        })
+        .collect();
+
+    for edge in diagram.edges.iter_mut() {
+        if let Some(owner) = owners.get(&edge.from_id) {
+            edge.from_port = Some(std::mem::replace(&mut edge.from_id, owner.clone()));
+        }
+        if let Some(owner) = owners.get(&edge.to_id) {
+            edge.to_port = Some(std::mem::replace(&mut edge.to_id, owner.clone()));
+        }
+    }
+}
+
+// A plain line between an interface and a component, e.g., "X - [Comp]", shows the
+// component provides it, with a lollipop on the component end
+fn connect_lollipops(diagram: &mut Diagram) {
+    let interfaces: Vec<String> = diagram
+        .nodes
+        .iter()
+        .filter(|node| matches!(node.r#type, NodeType::Interface(_)))
+        .map(|node| node.id.clone())
+        .collect();
+
+    for edge in diagram.edges.iter_mut() {
+        let plain: bool = edge.style_from == EdgeStyle::Solid && edge.style_to == EdgeStyle::Solid;
+
+        match (
+            plain,
+            interfaces.contains(&edge.from_id),
+            interfaces.contains(&edge.to_id),
+        ) {
+            (true, true, false) => edge.style_to = EdgeStyle::Lollipop,
+            (true, false, true) => edge.style_from = EdgeStyle::Lollipop,
+            _ => {}
+        }
+    }
+}
+
+fn declaration_id(declaration: &PlantUmlDeclaration) -> String {
+    declaration
+        .alias
+        .clone()
+        .unwrap_or(declaration.name.clone())
+}
+
+fn node_type_from(declaration: &PlantUmlDeclaration) -> NodeType {
+    let name: String = declaration.name.clone();
+    let classifier = |kind: ClassifierKind| {
+        NodeType::Class(Classifier {
+            generics: declaration.generics.clone(),
+            members: declaration.members.iter().map(Member::from).collect(),
+            ..Classifier::new(kind, name.clone())
+        })
+    };
+    let object = |kind: ObjectKind| {
+        NodeType::Object(Object {
+            fields: declaration.fields.iter().map(Field::from).collect(),
+            ..Object::new(kind, name.clone())
+        })
+    };
+
+    match declaration.kind {
+        PlantUmlElementKind::Class => classifier(ClassifierKind::Class),
+        PlantUmlElementKind::AbstractClass => classifier(ClassifierKind::AbstractClass),
+        PlantUmlElementKind::Enum => classifier(ClassifierKind::Enum),
+        PlantUmlElementKind::Object => object(ObjectKind::Object),
+        PlantUmlElementKind::Map => object(ObjectKind::Map),
+        PlantUmlElementKind::Interface
+            if declaration.generics.is_some() || !declaration.members.is_empty() =>
+        {
+            classifier(ClassifierKind::Interface)
+        }
+        PlantUmlElementKind::Component => NodeType::Component(name),
+        PlantUmlElementKind::Rectangle => NodeType::Rectangle(name),
+        PlantUmlElementKind::Circle => NodeType::Circle(name),
+        PlantUmlElementKind::Interface => NodeType::Interface(name),
+        PlantUmlElementKind::Actor => NodeType::Actor(name),
+        PlantUmlElementKind::Database => NodeType::Database(name),
+        PlantUmlElementKind::Queue => NodeType::Queue(name),
+        PlantUmlElementKind::Artifact => NodeType::Artifact(name),
+        PlantUmlElementKind::Storage => NodeType::Storage(name),
+        PlantUmlElementKind::UseCase => NodeType::UseCase(name),
+        PlantUmlElementKind::Boundary => NodeType::Boundary(name),
+        PlantUmlElementKind::Node => NodeType::Node(name),
+        PlantUmlElementKind::Cloud => NodeType::Cloud(name),
+        // Ports are not nodes, only components have them
+        PlantUmlElementKind::Port | PlantUmlElementKind::PortIn | PlantUmlElementKind::PortOut => {
+            NodeType::Rectangle(name)
+        }
+        // Only found in sequence diagrams
+        PlantUmlElementKind::Participant
+        | PlantUmlElementKind::Control
+        | PlantUmlElementKind::Entity
+        | PlantUmlElementKind::Collections => NodeType::Rectangle(name),
+        PlantUmlElementKind::State => NodeType::State(State::new(StateKind::Simple, name)),
+    }
+}
+
+fn group_type_from(kind: &PlantUmlContainerKind, name: String) -> GroupType {
+    match kind {
+        PlantUmlContainerKind::Package => GroupType::Package(name),
+        PlantUmlContainerKind::Node => GroupType::Node(name),
+        PlantUmlContainerKind::Folder => GroupType::Folder(name),
+        PlantUmlContainerKind::Frame => GroupType::Frame(name),
+        PlantUmlContainerKind::Cloud => GroupType::Cloud(name),
+        PlantUmlContainerKind::Component => GroupType::Component(name),
+        PlantUmlContainerKind::Rectangle => GroupType::Rectangle(name),
+        PlantUmlContainerKind::Database => GroupType::Database(name),
+        PlantUmlContainerKind::Storage => GroupType::Storage(name),
+        PlantUmlContainerKind::Artifact => GroupType::Artifact(name),
+        // Only found in state diagrams, where it is read as a composite state instead
+        PlantUmlContainerKind::State => GroupType::Frame(name),
+    }
+}
+
+fn edge_from(value: &PlantUmlRelationship, ids_by_name: &HashMap<String, String>) -> Edge {
+    let resolve = |reference: &String| -> String {
+        ids_by_name
+            .get(reference)
+            .cloned()
+            .unwrap_or(reference.clone())
+    };
+
+    Edge {
+        line_style: LineStyle::from(&value.arrow.line),
+        stereotypes: value.stereotypes.clone(),
+        direction: value.arrow.direction.as_ref().map(EdgeDirection::from),
+        hidden: value.arrow.hidden,
+        from_multiplicity: value.from_multiplicity.clone(),
+        to_multiplicity: value.to_multiplicity.clone(),
+        ..Edge::new(
+            resolve(&value.from),
+            resolve(&value.to),
+            edge_style_from(value.arrow.head_left.as_ref()),
+            edge_style_from(value.arrow.head_right.as_ref()),
+            value.label.clone(),
+        )
+    }
+}
+
+fn edge_style_from(value: Option<&PlantUmlArrowHead>) -> EdgeStyle {
+    value.map(EdgeStyle::from).unwrap_or(EdgeStyle::Solid)
+}
+
+impl From<&PlantUmlArrowHead> for EdgeStyle {
+    fn from(value: &PlantUmlArrowHead) -> Self {
+        match value {
+            PlantUmlArrowHead::Arrow => EdgeStyle::Arrow,
+            PlantUmlArrowHead::Open => EdgeStyle::OpenArrow,
+            PlantUmlArrowHead::Extension => EdgeStyle::Extension,
+            PlantUmlArrowHead::Composition => EdgeStyle::Composition,
+            PlantUmlArrowHead::Aggregation => EdgeStyle::Aggregation,
+            PlantUmlArrowHead::Socket => EdgeStyle::Socket,
+        }
+    }
+}
+
+impl From<&PlantUmlFragmentKind> for FragmentKind {
+    fn from(value: &PlantUmlFragmentKind) -> Self {
+        match value {
+            PlantUmlFragmentKind::Alt => FragmentKind::Alt,
+            PlantUmlFragmentKind::Opt => FragmentKind::Opt,
+            PlantUmlFragmentKind::Loop => FragmentKind::Loop,
+            PlantUmlFragmentKind::Par => FragmentKind::Par,
+            PlantUmlFragmentKind::Break => FragmentKind::Break,
+            PlantUmlFragmentKind::Critical => FragmentKind::Critical,
+            PlantUmlFragmentKind::Group => FragmentKind::Group,
+        }
+    }
+}
+
+impl From<&PlantUmlNoteSide> for NoteSide {
+    fn from(value: &PlantUmlNoteSide) -> Self {
+        match value {
+            PlantUmlNoteSide::Left => NoteSide::Left,
+            PlantUmlNoteSide::Right => NoteSide::Right,
+            PlantUmlNoteSide::Top => NoteSide::Top,
+            PlantUmlNoteSide::Bottom => NoteSide::Bottom,
+        }
+    }
+}
+
+impl From<&PlantUmlArrowDirection> for EdgeDirection {
+    fn from(value: &PlantUmlArrowDirection) -> Self {
+        match value {
+            PlantUmlArrowDirection::Up => EdgeDirection::Up,
+            PlantUmlArrowDirection::Down => EdgeDirection::Down,
+            PlantUmlArrowDirection::Left => EdgeDirection::Left,
+            PlantUmlArrowDirection::Right => EdgeDirection::Right,
+        }
+    }
+}
+
+impl From<&PlantUmlLayoutDirection> for LayoutDirection {
+    fn from(value: &PlantUmlLayoutDirection) -> Self {
+        match value {
+            PlantUmlLayoutDirection::TopToBottom => LayoutDirection::TopToBottom,
+            PlantUmlLayoutDirection::LeftToRight => LayoutDirection::LeftToRight,
+        }
+    }
+}
+
+impl From<&PlantUmlArrowLine> for LineStyle {
+    fn from(value: &PlantUmlArrowLine) -> Self {
+        match value {
+            PlantUmlArrowLine::Solid => LineStyle::Solid,
+            PlantUmlArrowLine::Dotted => LineStyle::Dotted,
+        }
+    }
+}
+
+impl From<&PlantUmlMember> for Member {
+    fn from(value: &PlantUmlMember) -> Self {
+        let kind: MemberKind = match value.is_method {
+            true => MemberKind::Method,
+            false => MemberKind::Field,
+        };
+
+        Member {
+            visibility: value.visibility.as_ref().map(Visibility::from),
+            is_static: value.is_static,
+            is_abstract: value.is_abstract,
+            ..Member::new(kind, value.text.clone())
+        }
+    }
+}
+
+impl From<&PlantUmlField> for Field {
+    fn from(value: &PlantUmlField) -> Self {
+        Field::new(value.name.clone(), value.value.clone())
+    }
+}
+
+impl From<&PlantUmlVisibility> for Visibility {
+    fn from(value: &PlantUmlVisibility) -> Self {
+        match value {
+            PlantUmlVisibility::Public => Visibility::Public,
+            PlantUmlVisibility::Private => Visibility::Private,
+            PlantUmlVisibility::Protected => Visibility::Protected,
+            PlantUmlVisibility::PackagePrivate => Visibility::PackagePrivate,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use crate::{
+        adapters::repositories::diagram_repository_plant_uml_impl::{
+            DiagramRepositoryPlantUmlImpl, PlantUmlParser, PlantUmlParserError,
+            PlantUmlPreprocessor, PlantUmlPreprocessorError,
+        },
+        infra::models::plant_uml_diagram::{
+            PlantUmlActivationKind, PlantUmlActivityStep, PlantUmlArrow, PlantUmlArrowDirection,
+            PlantUmlArrowHead, PlantUmlArrowLine, PlantUmlComment, PlantUmlContainerKind,
+            PlantUmlDataValue, PlantUmlDate, PlantUmlDeclaration, PlantUmlDiagram,
+            PlantUmlDiagramKind, PlantUmlDirectiveKind, PlantUmlElement, PlantUmlElementKind,
+            PlantUmlField, PlantUmlFragmentKind, PlantUmlGanttStatement, PlantUmlLayoutDirection,
+            PlantUmlMember, PlantUmlNote, PlantUmlNoteSide, PlantUmlRelationship, PlantUmlTask,
+            PlantUmlTaskAnchor, PlantUmlTaskConstraint, PlantUmlTaskStart, PlantUmlTime,
+            PlantUmlTimingKind, PlantUmlTimingParticipant, PlantUmlTimingStep, PlantUmlTreeNode,
+            PlantUmlTreeSide, PlantUmlVisibility, PlantUmlWeekday,
+        },
+        infra::models::plant_uml_source::{PlantUmlSource, PlantUmlSourceLocation},
+    };
+    use lib_core::domain::{
+        entities::{
+            activity::{Activity, ActivityStep, Branch, Loop},
+            class::{Classifier, ClassifierKind, Member, MemberKind, Visibility},
+            data::{Data, DataEntry, DataKind, DataValue},
+            diagnostic::{Diagnostic, Span},
+            diagram::{
+                Diagram, DiagramKind, Edge, EdgeDirection, EdgeStyle, Group, GroupType,
+                LayoutDirection, LineStyle, Node, NodeType, Note, NoteSide, Port, PortKind,
+            },
+            gantt::{ClosedDays, Date, Gantt, GanttTask, TaskAnchor, TaskStart, Weekday},
+            object::{Field, Object, ObjectKind},
+            sequence::{
+                FragmentKind, Message, MessageKind, Participant, ParticipantType, Sequence,
+                SequenceEvent,
+            },
+            state::{State, StateKind},
+            timing::{StateChange, TimeConstraint, Timing, TimingKind, TimingParticipant},
+            tree::{Tree, TreeKind, TreeNode, TreeSide},
+        },
+        repositories::diagram_repository::{
+            DiagramRepository, DiagramRepositoryError, RecoveredDiagram,
+        },
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_from_content_should_return_expected_error_given_parser_error() {
+        let diagnostic: Diagnostic =
+            Diagnostic::new("expected a name".to_string(), Span::new(10, 12), 2, 1);
+        let test_cases: Vec<(PlantUmlParserError, DiagramRepositoryError)> = vec![
+            (
+                PlantUmlParserError::UnknownError("Parser unknown error".to_string()),
+                DiagramRepositoryError::Unknown("Parser unknown error".to_string()),
+            ),
+            (
+                PlantUmlParserError::SyntaxError(diagnostic.clone()),
+                DiagramRepositoryError::Syntax(diagnostic),
+            ),
+        ];
+
+        for (parser_error, expected_repository_error) in test_cases {
+            let parser_result: Result<PlantUmlDiagram, PlantUmlParserError> = Err(parser_error);
+
+            let parser: Arc<dyn PlantUmlParser> =
+                Arc::new(PlantUmlParserMockImpl::new(parser_result));
+            let repository: DiagramRepositoryPlantUmlImpl =
+                DiagramRepositoryPlantUmlImpl::new(parser);
+
+            let result = repository.parse_from_content("Some dummy content");
+
+            assert_eq!(Err(expected_repository_error), result)
+        }
+    }
+
+    #[test]
+    fn parse_from_file_content_should_locate_syntax_errors_in_included_files() {
+        let included: Arc<PathBuf> = Arc::new(PathBuf::from("docs/common.puml"));
+        let source: PlantUmlSource = PlantUmlSource::new(
+            "@startuml\ncomponent\n@enduml".to_string(),
+            vec![
+                PlantUmlSourceLocation::new(None, 1),
+                PlantUmlSourceLocation::new(Some(included.clone()), 4),
+                PlantUmlSourceLocation::new(None, 3),
+            ],
+        );
+        let diagnostic: Diagnostic =
+            Diagnostic::new("expected a name".to_string(), Span::new(19, 19), 2, 10);
+        let expected: Diagnostic = Diagnostic {
+            file: Some(included),
+            line: 4,
+            ..diagnostic.clone()
+        };
+
+        let parser: Arc<dyn PlantUmlParser> = Arc::new(PlantUmlParserMockImpl::new(Err(
+            PlantUmlParserError::SyntaxError(diagnostic),
+        )));
+        let preprocessor: Arc<dyn PlantUmlPreprocessor> =
+            Arc::new(PlantUmlPreprocessorMockImpl::new(Ok(source)));
+        let repository: DiagramRepositoryPlantUmlImpl =
+            DiagramRepositoryPlantUmlImpl::new(parser).with_preprocessor(preprocessor);
+
+        let result = repository.parse_from_file_content("Some dummy content", Path::new("a.puml"));
+
+        assert_eq!(Err(DiagramRepositoryError::Syntax(expected)), result)
+    }
+
+    #[test]
+    fn parse_from_content_should_return_preprocessor_errors_as_syntax_errors() {
+        let diagnostic: Diagnostic =
+            Diagnostic::new("include cycle: a -> a".to_string(), Span::new(0, 10), 1, 1);
+
+        let parser: Arc<dyn PlantUmlParser> = Arc::new(PlantUmlParserMockImpl::new(Err(
+            PlantUmlParserError::UnknownError("Unexpected call to parse".to_string()),
+        )));
+        let preprocessor: Arc<dyn PlantUmlPreprocessor> =
+            Arc::new(PlantUmlPreprocessorMockImpl::new(Err(
+                PlantUmlPreprocessorError::IncludeCycle(diagnostic.clone()),
+            )));
+        let repository: DiagramRepositoryPlantUmlImpl =
+            DiagramRepositoryPlantUmlImpl::new(parser).with_preprocessor(preprocessor);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Err(DiagramRepositoryError::Syntax(diagnostic)), result)
+    }
+
+    #[test]
+    fn parse_from_content_should_return_expected_diagram_given_parsed_plant_uml_diagram() {
+        let test_cases: Vec<(PlantUmlDiagram, Diagram)> = vec![
+            (
+                PlantUmlDiagram::new(Vec::new()),
+                Diagram::new(None, Vec::new(), Vec::new()),
+            ),
+            (
+                PlantUmlDiagram::new(vec![PlantUmlElement::Element(PlantUmlDeclaration::new(
+                    PlantUmlElementKind::Component,
+                    "Some Component".to_owned(),
+                    None,
+                ))]),
+                Diagram::new(
+                    None,
+                    vec![Node::new(
+                        "Some Component".to_owned(),
+                        NodeType::Component("Some Component".to_owned()),
+                    )],
+                    vec![],
+                ),
+            ),
+            (
+                PlantUmlDiagram::new(vec![
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(
+                        PlantUmlElementKind::Component,
+                        "Component A".to_owned(),
+                        Some("A".to_owned()),
+                    )),
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(
+                        PlantUmlElementKind::Component,
+                        "Component B".to_owned(),
+                        None,
+                    )),
+                ]),
+                Diagram::new(
+                    None,
+                    vec![
+                        Node::new(
+                            "A".to_owned(),
+                            NodeType::Component("Component A".to_owned()),
+                        ),
+                        Node::new(
+                            "Component B".to_owned(),
+                            NodeType::Component("Component B".to_owned()),
+                        ),
+                    ],
+                    vec![],
+                ),
+            ),
+            (
+                PlantUmlDiagram::new(vec![
+                    PlantUmlElement::Comment(PlantUmlComment::Line("A note".to_owned())),
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(
+                        PlantUmlElementKind::Component,
+                        "A".to_owned(),
+                        None,
+                    )),
+                ]),
+                Diagram::new(
+                    None,
+                    vec![Node::new(
+                        "A".to_owned(),
+                        NodeType::Component("A".to_owned()),
+                    )],
+                    vec![],
+                ),
+            ),
+        ];
+
+        for (plant_uml_diagram, expected_diagram) in test_cases {
+            let parser_result: Result<PlantUmlDiagram, PlantUmlParserError> = Ok(plant_uml_diagram);
+
+            let parser: Arc<dyn PlantUmlParser> =
+                Arc::new(PlantUmlParserMockImpl::new(parser_result));
+            let repository: DiagramRepositoryPlantUmlImpl =
+                DiagramRepositoryPlantUmlImpl::new(parser);
+
+            let result = repository.parse_from_content("Some dummy content");
+
+            assert_eq!(Ok(expected_diagram), result)
+        }
+    }
+
+    #[test]
+    fn parse_from_content_should_map_relationships_to_edges_resolving_aliases() {
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Component,
+                "Component A".to_owned(),
+                Some("a".to_owned()),
+            )),
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Component,
+                "B".to_owned(),
+                None,
+            )),
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Component,
+                "C".to_owned(),
+                Some("c".to_owned()),
+            )),
+            PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                "a".to_owned(),
+                "B".to_owned(),
+                PlantUmlArrow::new(
+                    None,
+                    PlantUmlArrowLine::Solid,
+                    Some(PlantUmlArrowHead::Arrow),
+                ),
+                Some("uses".to_owned()),
+            )),
+            PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                "C".to_owned(),
+                "Component A".to_owned(),
+                PlantUmlArrow::new(
+                    Some(PlantUmlArrowHead::Arrow),
+                    PlantUmlArrowLine::Dotted,
+                    None,
+                ),
+                None,
+            )),
+        ]);
+        let expected_edges: Vec<Edge> = vec![
+            Edge::new(
+                "a".to_owned(),
+                "B".to_owned(),
+                EdgeStyle::Solid,
+                EdgeStyle::Arrow,
+                Some("uses".to_owned()),
+            ),
+            Edge {
+                line_style: LineStyle::Dotted,
+                ..Edge::new(
+                    "c".to_owned(),
+                    "a".to_owned(),
+                    EdgeStyle::Arrow,
+                    EdgeStyle::Solid,
+                    None,
+                )
+            },
+        ];
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result: Diagram = repository.parse_from_content("Some dummy content").unwrap();
+
+        assert_eq!(3, result.nodes.len());
+        assert_eq!(expected_edges, result.edges);
+    }
+
+    #[test]
+    fn parse_from_content_should_create_nodes_for_elements_only_referenced_by_relationships() {
+        let relationship = |from: &str, to: &str, head: PlantUmlArrowHead| {
+            PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                from.to_owned(),
+                to.to_owned(),
+                PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(head)),
+                None,
+            ))
+        };
+        let graph: PlantUmlDiagram =
+            PlantUmlDiagram::new(vec![relationship("a", "b", PlantUmlArrowHead::Arrow)]);
+        let classes: PlantUmlDiagram =
+            PlantUmlDiagram::new(vec![relationship("B", "A", PlantUmlArrowHead::Extension)]);
+
+        let graph_nodes: Vec<Node> =
+            DiagramRepositoryPlantUmlImpl::new(Arc::new(PlantUmlParserMockImpl::new(Ok(graph))))
+                .parse_from_content("Some dummy content")
+                .unwrap()
+                .nodes;
+        let class_nodes: Vec<Node> =
+            DiagramRepositoryPlantUmlImpl::new(Arc::new(PlantUmlParserMockImpl::new(Ok(classes))))
+                .parse_from_content("Some dummy content")
+                .unwrap()
+                .nodes;
+
+        assert_eq!(
+            vec![
+                Node::new("a".to_owned(), NodeType::Rectangle("a".to_owned())),
+                Node::new("b".to_owned(), NodeType::Rectangle("b".to_owned())),
+            ],
+            graph_nodes
+        );
+        assert_eq!(
+            vec![
+                Node::new(
+                    "B".to_owned(),
+                    NodeType::Class(Classifier::new(ClassifierKind::Class, "B".to_owned()))
+                ),
+                Node::new(
+                    "A".to_owned(),
+                    NodeType::Class(Classifier::new(ClassifierKind::Class, "A".to_owned()))
+                ),
+            ],
+            class_nodes
+        );
+    }
+
+    #[test]
+    fn parse_from_content_should_not_create_nodes_for_relationships_to_notes() {
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Note(PlantUmlNote {
+                alias: Some("N1".to_owned()),
+                ..PlantUmlNote::new("floating".to_owned())
+            }),
+            PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                "N1".to_owned(),
+                "A".to_owned(),
+                PlantUmlArrow::new(None, PlantUmlArrowLine::Dotted, None),
+                None,
+            )),
+        ]);
+
+        let nodes: Vec<Node> = DiagramRepositoryPlantUmlImpl::new(Arc::new(
+            PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)),
+        ))
+        .parse_from_content("Some dummy content")
+        .unwrap()
+        .nodes;
+
+        assert_eq!(
+            vec![Node::new(
+                "A".to_owned(),
+                NodeType::Rectangle("A".to_owned())
+            )],
+            nodes
+        );
+    }
+
+    #[test]
+    fn parse_from_content_should_map_containers_to_nested_groups() {
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Component,
+                "outside".to_owned(),
+                None,
+            )),
+            PlantUmlElement::Container(
+                PlantUmlContainerKind::Component,
+                "uml".to_owned(),
+                None,
+                vec![PlantUmlElement::Container(
+                    PlantUmlContainerKind::Package,
+                    "application".to_owned(),
+                    Some("uml_application".to_owned()),
+                    vec![
+                        PlantUmlElement::Element(PlantUmlDeclaration::new(
+                            PlantUmlElementKind::Component,
+                            "Repository".to_owned(),
+                            Some("repo".to_owned()),
+                        )),
+                        PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                            "Repository".to_owned(),
+                            "outside".to_owned(),
+                            PlantUmlArrow::new(
+                                None,
+                                PlantUmlArrowLine::Solid,
+                                Some(PlantUmlArrowHead::Arrow),
+                            ),
+                            None,
+                        )),
+                    ],
+                )],
+            ),
+        ]);
+        let expected: Diagram = Diagram {
+            groups: vec![
+                Group::new(
+                    "uml".to_owned(),
+                    GroupType::Component("uml".to_owned()),
+                    None,
+                ),
+                Group::new(
+                    "uml_application".to_owned(),
+                    GroupType::Package("application".to_owned()),
+                    Some("uml".to_owned()),
+                ),
+            ],
+            ..Diagram::new(
+                None,
+                vec![
+                    Node::new(
+                        "outside".to_owned(),
+                        NodeType::Component("outside".to_owned()),
+                    ),
+                    Node {
+                        parent_id: Some("uml_application".to_owned()),
+                        ..Node::new(
+                            "repo".to_owned(),
+                            NodeType::Component("Repository".to_owned()),
+                        )
+                    },
+                ],
+                vec![Edge::new(
+                    "repo".to_owned(),
+                    "outside".to_owned(),
+                    EdgeStyle::Solid,
+                    EdgeStyle::Arrow,
+                    None,
+                )],
+            )
+        };
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_map_use_cases_inside_system_boundaries() {
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Actor,
+                "Customer".to_owned(),
+                Some("c".to_owned()),
+            )),
+            PlantUmlElement::Container(
+                PlantUmlContainerKind::Rectangle,
+                "Shop".to_owned(),
+                None,
+                vec![PlantUmlElement::Element(PlantUmlDeclaration::new(
+                    PlantUmlElementKind::UseCase,
+                    "Checkout".to_owned(),
+                    Some("UC1".to_owned()),
+                ))],
+            ),
+            PlantUmlElement::Element(PlantUmlDeclaration {
+                implicit: true,
+                ..PlantUmlDeclaration::new(PlantUmlElementKind::UseCase, "Pay".to_owned(), None)
+            }),
+            PlantUmlElement::Relationship(PlantUmlRelationship {
+                stereotypes: vec!["include".to_owned()],
+                ..PlantUmlRelationship::new(
+                    "UC1".to_owned(),
+                    "Pay".to_owned(),
+                    PlantUmlArrow::new(
+                        None,
+                        PlantUmlArrowLine::Dotted,
+                        Some(PlantUmlArrowHead::Arrow),
+                    ),
+                    None,
+                )
+            }),
+        ]);
+        let expected: Diagram = Diagram {
+            groups: vec![Group::new(
+                "Shop".to_owned(),
+                GroupType::Rectangle("Shop".to_owned()),
+                None,
+            )],
+            ..Diagram::new(
+                None,
+                vec![
+                    Node::new("c".to_owned(), NodeType::Actor("Customer".to_owned())),
+                    Node {
+                        parent_id: Some("Shop".to_owned()),
+                        ..Node::new("UC1".to_owned(), NodeType::UseCase("Checkout".to_owned()))
+                    },
+                    Node::new("Pay".to_owned(), NodeType::UseCase("Pay".to_owned())),
+                ],
+                vec![Edge {
+                    line_style: LineStyle::Dotted,
+                    stereotypes: vec!["include".to_owned()],
+                    ..Edge::new(
+                        "UC1".to_owned(),
+                        "Pay".to_owned(),
+                        EdgeStyle::Solid,
+                        EdgeStyle::Arrow,
+                        None,
+                    )
+                }],
+            )
+        };
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_connect_edges_to_component_ports() {
+        let port = |kind: PlantUmlElementKind, name: &str| {
+            PlantUmlElement::Element(PlantUmlDeclaration::new(kind, name.to_owned(), None))
+        };
+        let arrow = |from: &str, to: &str| {
+            PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                from.to_owned(),
+                to.to_owned(),
+                PlantUmlArrow::new(
+                    None,
+                    PlantUmlArrowLine::Solid,
+                    Some(PlantUmlArrowHead::Arrow),
+                ),
+                None,
+            ))
+        };
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Container(
+                PlantUmlContainerKind::Node,
+                "Server".to_owned(),
+                None,
+                vec![PlantUmlElement::Container(
+                    PlantUmlContainerKind::Component,
+                    "Api".to_owned(),
+                    Some("api".to_owned()),
+                    vec![
+                        port(PlantUmlElementKind::PortIn, "in"),
+                        port(PlantUmlElementKind::PortOut, "out"),
+                    ],
+                )],
+            ),
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Cloud,
+                "Internet".to_owned(),
+                None,
+            )),
+            arrow("Internet", "in"),
+            arrow("out", "Internet"),
+        ]);
+        let edge = |from: &str, to: &str| {
+            Edge::new(
+                from.to_owned(),
+                to.to_owned(),
+                EdgeStyle::Solid,
+                EdgeStyle::Arrow,
+                None,
+            )
+        };
+        let expected: Diagram = Diagram {
+            groups: vec![Group::new(
+                "Server".to_owned(),
+                GroupType::Node("Server".to_owned()),
+                None,
+            )],
+            ..Diagram::new(
+                None,
+                vec![
+                    Node {
+                        parent_id: Some("Server".to_owned()),
+                        ports: vec![
+                            Port::new("in".to_owned(), "in".to_owned(), PortKind::In),
+                            Port::new("out".to_owned(), "out".to_owned(), PortKind::Out),
+                        ],
+                        ..Node::new("api".to_owned(), NodeType::Component("Api".to_owned()))
+                    },
+                    Node::new(
+                        "Internet".to_owned(),
+                        NodeType::Cloud("Internet".to_owned()),
+                    ),
+                ],
+                vec![
+                    Edge {
+                        to_port: Some("in".to_owned()),
+                        ..edge("Internet", "api")
+                    },
+                    Edge {
+                        from_port: Some("out".to_owned()),
+                        ..edge("api", "Internet")
+                    },
+                ],
+            )
+        };
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_mark_provided_and_required_interfaces_with_lollipops_and_sockets()
+    {
+        let component = |name: &str| {
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Component,
+                name.to_owned(),
+                None,
+            ))
+        };
+        let line = |from: &str, to: &str, head: Option<PlantUmlArrowHead>| {
+            PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                from.to_owned(),
+                to.to_owned(),
+                PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, head),
+                None,
+            ))
+        };
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Interface,
+                "Data Access".to_owned(),
+                Some("DA".to_owned()),
+            )),
+            component("Store"),
+            component("Web"),
+            line("DA", "Store", None),
+            line("Web", "DA", Some(PlantUmlArrowHead::Socket)),
+            line("Web", "Store", None),
+        ]);
+        let edge = |from: &str, to: &str, style_from: EdgeStyle, style_to: EdgeStyle| {
+            Edge::new(from.to_owned(), to.to_owned(), style_from, style_to, None)
+        };
+        let expected: Diagram = Diagram::new(
+            None,
+            vec![
+                Node::new(
+                    "DA".to_owned(),
+                    NodeType::Interface("Data Access".to_owned()),
+                ),
+                Node::new("Store".to_owned(), NodeType::Component("Store".to_owned())),
+                Node::new("Web".to_owned(), NodeType::Component("Web".to_owned())),
+            ],
+            vec![
+                edge("DA", "Store", EdgeStyle::Solid, EdgeStyle::Lollipop),
+                edge("Web", "DA", EdgeStyle::Solid, EdgeStyle::Socket),
+                edge("Web", "Store", EdgeStyle::Solid, EdgeStyle::Solid),
+            ],
+        );
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_map_element_kinds_to_node_types() {
+        let test_cases: Vec<(PlantUmlElementKind, NodeType)> = vec![
+            (
+                PlantUmlElementKind::Component,
+                NodeType::Component("x".to_owned()),
+            ),
+            (
+                PlantUmlElementKind::Rectangle,
+                NodeType::Rectangle("x".to_owned()),
+            ),
+            (
+                PlantUmlElementKind::Circle,
+                NodeType::Circle("x".to_owned()),
+            ),
+            (
+                PlantUmlElementKind::Interface,
+                NodeType::Interface("x".to_owned()),
+            ),
+            (PlantUmlElementKind::Actor, NodeType::Actor("x".to_owned())),
+            (
+                PlantUmlElementKind::Database,
+                NodeType::Database("x".to_owned()),
+            ),
+            (PlantUmlElementKind::Queue, NodeType::Queue("x".to_owned())),
+            (
+                PlantUmlElementKind::Artifact,
+                NodeType::Artifact("x".to_owned()),
+            ),
+            (
+                PlantUmlElementKind::Storage,
+                NodeType::Storage("x".to_owned()),
+            ),
+            (
+                PlantUmlElementKind::UseCase,
+                NodeType::UseCase("x".to_owned()),
+            ),
+            (
+                PlantUmlElementKind::Boundary,
+                NodeType::Boundary("x".to_owned()),
+            ),
+            (PlantUmlElementKind::Node, NodeType::Node("x".to_owned())),
+            (PlantUmlElementKind::Cloud, NodeType::Cloud("x".to_owned())),
+        ];
+
+        for (kind, expected_type) in test_cases {
+            let plant_uml_diagram: PlantUmlDiagram =
+                PlantUmlDiagram::new(vec![PlantUmlElement::Element(PlantUmlDeclaration::new(
+                    kind,
+                    "x".to_owned(),
+                    None,
+                ))]);
+
+            let parser: Arc<dyn PlantUmlParser> =
+                Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+            let repository: DiagramRepositoryPlantUmlImpl =
+                DiagramRepositoryPlantUmlImpl::new(parser);
+
+            let result = repository.parse_from_content("Some dummy content");
+
+            assert_eq!(
+                Ok(Diagram::new(
+                    None,
+                    vec![Node::new("x".to_owned(), expected_type)],
+                    vec![]
+                )),
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn parse_from_content_should_map_stereotypes_to_nodes_and_edges() {
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Element(PlantUmlDeclaration {
+                stereotypes: vec!["lib".to_owned()],
+                ..PlantUmlDeclaration::new(PlantUmlElementKind::Component, "uml".to_owned(), None)
+            }),
+            PlantUmlElement::Relationship(PlantUmlRelationship {
+                stereotypes: vec!["uses".to_owned()],
+                ..PlantUmlRelationship::new(
+                    "a".to_owned(),
+                    "uml".to_owned(),
+                    PlantUmlArrow::new(
+                        None,
+                        PlantUmlArrowLine::Solid,
+                        Some(PlantUmlArrowHead::Arrow),
+                    ),
+                    None,
+                )
+            }),
+        ]);
+        let expected: Diagram = Diagram::new(
+            None,
+            vec![
+                Node {
+                    stereotypes: vec!["lib".to_owned()],
+                    ..Node::new("uml".to_owned(), NodeType::Component("uml".to_owned()))
+                },
+                Node::new("a".to_owned(), NodeType::Rectangle("a".to_owned())),
+            ],
+            vec![Edge {
+                stereotypes: vec!["uses".to_owned()],
+                ..Edge::new(
+                    "a".to_owned(),
+                    "uml".to_owned(),
+                    EdgeStyle::Solid,
+                    EdgeStyle::Arrow,
+                    None,
+                )
+            }],
+        );
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_merge_implicit_declarations_with_explicit_ones() {
+        let implicit = |name: &str| -> PlantUmlElement {
+            PlantUmlElement::Element(PlantUmlDeclaration {
+                implicit: true,
+                ..PlantUmlDeclaration::new(PlantUmlElementKind::Component, name.to_owned(), None)
+            })
+        };
+        let relationship = |from: &str, to: &str| -> PlantUmlElement {
+            PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                from.to_owned(),
+                to.to_owned(),
+                PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, None),
+                None,
+            ))
+        };
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            implicit("A"),
+            implicit("B"),
+            relationship("A", "B"),
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Database,
+                "A".to_owned(),
+                Some("a".to_owned()),
+            )),
+            implicit("B"),
+            implicit("A"),
+            relationship("B", "A"),
+        ]);
+        let expected: Diagram = Diagram::new(
+            None,
+            vec![
+                Node::new("a".to_owned(), NodeType::Database("A".to_owned())),
+                Node::new("B".to_owned(), NodeType::Component("B".to_owned())),
+            ],
+            vec![
+                Edge::new(
+                    "a".to_owned(),
+                    "B".to_owned(),
+                    EdgeStyle::Solid,
+                    EdgeStyle::Solid,
+                    None,
+                ),
+                Edge::new(
+                    "B".to_owned(),
+                    "a".to_owned(),
+                    EdgeStyle::Solid,
+                    EdgeStyle::Solid,
+                    None,
+                ),
+            ],
+        );
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_map_directives_to_diagram_fields() {
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Directive(PlantUmlDirectiveKind::Title, "Draft".to_owned()),
+            PlantUmlElement::Directive(PlantUmlDirectiveKind::Header, "Header".to_owned()),
+            PlantUmlElement::Directive(PlantUmlDirectiveKind::Footer, "Footer".to_owned()),
+            PlantUmlElement::Directive(PlantUmlDirectiveKind::Caption, "Caption".to_owned()),
+            PlantUmlElement::Directive(PlantUmlDirectiveKind::Legend, "A\nB".to_owned()),
+            PlantUmlElement::Directive(PlantUmlDirectiveKind::Title, "Overview".to_owned()),
+        ]);
+        let expected: Diagram = Diagram {
+            header: Some("Header".to_owned()),
+            footer: Some("Footer".to_owned()),
+            caption: Some("Caption".to_owned()),
+            legend: Some("A\nB".to_owned()),
+            ..Diagram::new(Some("Overview".to_owned()), vec![], vec![])
+        };
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_map_notes_resolving_anchors() {
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Component,
+                "Component A".to_owned(),
+                Some("a".to_owned()),
+            )),
+            PlantUmlElement::Note(PlantUmlNote {
+                anchor: Some("Component A".to_owned()),
+                side: PlantUmlNoteSide::Top,
+                ..PlantUmlNote::new("Entry point".to_owned())
+            }),
+            PlantUmlElement::Note(PlantUmlNote {
+                alias: Some("N1".to_owned()),
+                ..PlantUmlNote::new("Shared".to_owned())
+            }),
+            PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                "N1".to_owned(),
+                "Component A".to_owned(),
+                PlantUmlArrow::new(None, PlantUmlArrowLine::Dotted, None),
+                None,
+            )),
+        ]);
+        let expected_notes: Vec<Note> = vec![
+            Note {
+                anchor_id: Some("a".to_owned()),
+                side: NoteSide::Top,
+                ..Note::new("Entry point".to_owned())
+            },
+            Note {
+                id: Some("N1".to_owned()),
+                ..Note::new("Shared".to_owned())
+            },
+        ];
+        let expected_edges: Vec<Edge> = vec![Edge {
+            line_style: LineStyle::Dotted,
+            ..Edge::new(
+                "N1".to_owned(),
+                "a".to_owned(),
+                EdgeStyle::Solid,
+                EdgeStyle::Solid,
+                None,
+            )
+        }];
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result: Diagram = repository.parse_from_content("Some dummy content").unwrap();
+
+        assert_eq!(expected_notes, result.notes);
+        assert_eq!(expected_edges, result.edges);
+    }
+
+    #[test]
+    fn parse_from_content_should_map_direction_hints() {
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Direction(PlantUmlLayoutDirection::LeftToRight),
+            PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                "a".to_owned(),
+                "b".to_owned(),
+                PlantUmlArrow {
+                    direction: Some(PlantUmlArrowDirection::Up),
+                    hidden: true,
+                    ..PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, None)
+                },
+                None,
+            )),
+        ]);
+        let expected: Diagram = Diagram {
+            direction: LayoutDirection::LeftToRight,
+            ..Diagram::new(
+                None,
+                vec![
+                    Node::new("a".to_owned(), NodeType::Rectangle("a".to_owned())),
+                    Node::new("b".to_owned(), NodeType::Rectangle("b".to_owned())),
+                ],
+                vec![Edge {
+                    direction: Some(EdgeDirection::Up),
+                    hidden: true,
+                    ..Edge::new(
+                        "a".to_owned(),
+                        "b".to_owned(),
+                        EdgeStyle::Solid,
+                        EdgeStyle::Solid,
+                        None,
+                    )
+                }],
+            )
+        };
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_map_sequence_statements_to_a_sequence() {
+        let message = |from: &str, to: &str, arrow: PlantUmlArrow| {
+            PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                from.to_owned(),
+                to.to_owned(),
+                arrow,
+                None,
+            ))
+        };
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Participant,
+                "Web Server".to_owned(),
+                Some("web".to_owned()),
+            )),
+            PlantUmlElement::Directive(PlantUmlDirectiveKind::Title, "Login".to_owned()),
+            PlantUmlElement::Activation(PlantUmlActivationKind::Activate, "web".to_owned()),
+            PlantUmlElement::FragmentStart(PlantUmlFragmentKind::Alt, Some("cached".to_owned())),
+            message(
+                "web",
+                "db",
+                PlantUmlArrow {
+                    short: true,
+                    ..PlantUmlArrow::new(
+                        None,
+                        PlantUmlArrowLine::Solid,
+                        Some(PlantUmlArrowHead::Open),
+                    )
+                },
+            ),
+            PlantUmlElement::FragmentElse(None),
+            message(
+                "Web Server",
+                "db",
+                PlantUmlArrow {
+                    short: true,
+                    ..PlantUmlArrow::new(
+                        None,
+                        PlantUmlArrowLine::Solid,
+                        Some(PlantUmlArrowHead::Arrow),
+                    )
+                },
+            ),
+            message(
+                "web",
+                "db",
+                PlantUmlArrow::new(
+                    Some(PlantUmlArrowHead::Arrow),
+                    PlantUmlArrowLine::Solid,
+                    None,
+                ),
+            ),
+            PlantUmlElement::FragmentEnd,
+            PlantUmlElement::Divider("Later".to_owned()),
+            PlantUmlElement::Delay(None),
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Database,
+                "db".to_owned(),
+                None,
+            )),
+        ]);
+        let expected: Diagram = Diagram {
+            kind: DiagramKind::Sequence(Sequence::new(
+                vec![
+                    Participant::new(
+                        "web".to_owned(),
+                        ParticipantType::Participant("Web Server".to_owned()),
+                    ),
+                    Participant::new("db".to_owned(), ParticipantType::Database("db".to_owned())),
+                ],
+                vec![
+                    SequenceEvent::Activate("web".to_owned()),
+                    SequenceEvent::FragmentStart(FragmentKind::Alt, Some("cached".to_owned())),
+                    SequenceEvent::Message(Message::new(
+                        "web".to_owned(),
+                        "db".to_owned(),
+                        MessageKind::Async,
+                        None,
+                    )),
+                    SequenceEvent::FragmentElse(None),
+                    SequenceEvent::Message(Message::new(
+                        "web".to_owned(),
+                        "db".to_owned(),
+                        MessageKind::Sync,
+                        None,
+                    )),
+                    SequenceEvent::Message(Message::new(
+                        "db".to_owned(),
+                        "web".to_owned(),
+                        MessageKind::Return,
+                        None,
+                    )),
+                    SequenceEvent::FragmentEnd,
+                    SequenceEvent::Divider("Later".to_owned()),
+                    SequenceEvent::Delay(None),
+                ],
+            )),
+            ..Diagram::new(Some("Login".to_owned()), vec![], vec![])
+        };
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_map_messages_between_implicit_participants_to_a_sequence() {
+        let plant_uml_diagram: PlantUmlDiagram =
+            PlantUmlDiagram::new(vec![PlantUmlElement::Relationship(
+                PlantUmlRelationship::new(
+                    "Alice".to_owned(),
+                    "Bob".to_owned(),
+                    PlantUmlArrow {
+                        short: true,
+                        ..PlantUmlArrow::new(
+                            None,
+                            PlantUmlArrowLine::Solid,
+                            Some(PlantUmlArrowHead::Arrow),
+                        )
+                    },
+                    Some("Authentication Request".to_owned()),
+                ),
+            )]);
+        let expected: Diagram = Diagram {
+            kind: DiagramKind::Sequence(Sequence::new(
+                vec![
+                    Participant::new(
+                        "Alice".to_owned(),
+                        ParticipantType::Participant("Alice".to_owned()),
+                    ),
+                    Participant::new(
+                        "Bob".to_owned(),
+                        ParticipantType::Participant("Bob".to_owned()),
+                    ),
+                ],
+                vec![SequenceEvent::Message(Message::new(
+                    "Alice".to_owned(),
+                    "Bob".to_owned(),
+                    MessageKind::Sync,
+                    Some("Authentication Request".to_owned()),
+                ))],
+            )),
+            ..Diagram::new(None, vec![], vec![])
+        };
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_map_messages_of_an_actor_to_a_sequence() {
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Actor,
+                "User".to_owned(),
+                None,
+            )),
+            PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                "User".to_owned(),
+                "DB".to_owned(),
+                PlantUmlArrow::new(
+                    None,
+                    PlantUmlArrowLine::Solid,
+                    Some(PlantUmlArrowHead::Arrow),
+                ),
+                Some("query".to_owned()),
+            )),
+        ]);
+        let expected: Diagram = Diagram {
+            kind: DiagramKind::Sequence(Sequence::new(
+                vec![
+                    Participant::new("User".to_owned(), ParticipantType::Actor("User".to_owned())),
+                    Participant::new(
+                        "DB".to_owned(),
+                        ParticipantType::Participant("DB".to_owned()),
+                    ),
+                ],
+                vec![SequenceEvent::Message(Message::new(
+                    "User".to_owned(),
+                    "DB".to_owned(),
+                    MessageKind::Return,
+                    Some("query".to_owned()),
+                ))],
+            )),
+            ..Diagram::new(None, vec![], vec![])
+        };
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_map_class_declarations_to_classifiers() {
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Element(PlantUmlDeclaration {
+                generics: Some("T".to_owned()),
+                members: vec![
+                    PlantUmlMember {
+                        visibility: Some(PlantUmlVisibility::Protected),
+                        is_static: true,
+                        ..PlantUmlMember::new("count : int".to_owned())
+                    },
+                    PlantUmlMember {
+                        is_abstract: true,
+                        is_method: true,
+                        ..PlantUmlMember::new("area()".to_owned())
+                    },
+                ],
+                ..PlantUmlDeclaration::new(
+                    PlantUmlElementKind::AbstractClass,
+                    "Shape".to_owned(),
+                    Some("shape".to_owned()),
+                )
+            }),
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Interface,
+                "Drawable".to_owned(),
+                None,
+            )),
+            PlantUmlElement::Relationship(PlantUmlRelationship {
+                from_multiplicity: Some("1".to_owned()),
+                to_multiplicity: Some("*".to_owned()),
+                ..PlantUmlRelationship::new(
+                    "Shape".to_owned(),
+                    "Drawable".to_owned(),
+                    PlantUmlArrow::new(
+                        Some(PlantUmlArrowHead::Aggregation),
+                        PlantUmlArrowLine::Dotted,
+                        Some(PlantUmlArrowHead::Extension),
+                    ),
+                    None,
+                )
+            }),
+        ]);
+        let expected: Diagram = Diagram::new(
+            None,
+            vec![
+                Node::new(
+                    "shape".to_owned(),
+                    NodeType::Class(Classifier {
+                        generics: Some("T".to_owned()),
+                        members: vec![
+                            Member {
+                                visibility: Some(Visibility::Protected),
+                                is_static: true,
+                                ..Member::new(MemberKind::Field, "count : int".to_owned())
+                            },
+                            Member {
+                                is_abstract: true,
+                                ..Member::new(MemberKind::Method, "area()".to_owned())
+                            },
+                        ],
+                        ..Classifier::new(ClassifierKind::AbstractClass, "Shape".to_owned())
+                    }),
+                ),
+                // A bodyless interface is drawn as a class once the diagram is a class diagram
+                Node::new(
+                    "Drawable".to_owned(),
+                    NodeType::Class(Classifier::new(
+                        ClassifierKind::Interface,
+                        "Drawable".to_owned(),
+                    )),
+                ),
+            ],
+            vec![Edge {
+                line_style: LineStyle::Dotted,
+                from_multiplicity: Some("1".to_owned()),
+                to_multiplicity: Some("*".to_owned()),
+                ..Edge::new(
+                    "shape".to_owned(),
+                    "Drawable".to_owned(),
+                    EdgeStyle::Aggregation,
+                    EdgeStyle::Extension,
+                    None,
+                )
+            }],
+        );
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_highlight_every_entry_along_a_path() {
+        let text = |value: &str| PlantUmlDataValue::Text(value.to_owned());
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram {
+            kind: PlantUmlDiagramKind::Json,
+            ..PlantUmlDiagram::new(vec![
+                PlantUmlElement::Highlight(vec!["langs".to_owned(), "1".to_owned()]),
+                PlantUmlElement::Data(PlantUmlDataValue::Object(vec![
+                    ("name".to_owned(), text("Ada")),
+                    (
+                        "langs".to_owned(),
+                        PlantUmlDataValue::Array(vec![text("en"), text("fr")]),
+                    ),
+                ])),
+            ])
+        };
+        let highlighted = |entry: DataEntry| DataEntry {
+            highlighted: true,
+            ..entry
+        };
+        let expected: Diagram = Diagram {
+            kind: DiagramKind::Data(Data::new(
+                DataKind::Json,
+                DataValue::Object(vec![
+                    DataEntry::new("name".to_owned(), DataValue::Text("Ada".to_owned())),
+                    highlighted(DataEntry::new(
+                        "langs".to_owned(),
+                        DataValue::Array(vec![
+                            DataEntry::new("0".to_owned(), DataValue::Text("en".to_owned())),
+                            highlighted(DataEntry::new(
+                                "1".to_owned(),
+                                DataValue::Text("fr".to_owned()),
+                            )),
+                        ]),
+                    )),
+                ]),
+            )),
+            ..Diagram::default()
+        };
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_gather_gantt_statements_by_task() {
+        let task = |name: &str, alias: Option<&str>, constraints: Vec<PlantUmlTaskConstraint>| {
+            PlantUmlElement::Gantt(PlantUmlGanttStatement::Task(PlantUmlTask {
+                constraints,
+                ..PlantUmlTask::new(name.to_owned(), alias.map(str::to_owned))
+            }))
+        };
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram {
+            kind: PlantUmlDiagramKind::Gantt,
+            ..PlantUmlDiagram::new(vec![
+                PlantUmlElement::Gantt(PlantUmlGanttStatement::ProjectStart(PlantUmlDate::new(
+                    2026, 1, 5,
+                ))),
+                PlantUmlElement::Gantt(PlantUmlGanttStatement::ClosedWeekday(
+                    PlantUmlWeekday::Sunday,
+                )),
+                task("Design", Some("D"), vec![]),
+                PlantUmlElement::Gantt(PlantUmlGanttStatement::Task(PlantUmlTask {
+                    then: true,
+                    ..PlantUmlTask::new("Build".to_owned(), None)
+                })),
+                task("D", None, vec![PlantUmlTaskConstraint::Lasts(4)]),
+                task(
+                    "Release",
+                    None,
+                    vec![PlantUmlTaskConstraint::Happens(PlantUmlTaskStart::After(
+                        "D".to_owned(),
+                        PlantUmlTaskAnchor::Start,
+                        2,
+                    ))],
+                ),
+            ])
+        };
+        let expected: Diagram = Diagram {
+            kind: DiagramKind::Gantt(Gantt {
+                closed_days: vec![ClosedDays::Weekday(Weekday::Sunday)],
+                ..Gantt::new(
+                    Some(Date::new(2026, 1, 5)),
+                    vec![
+                        GanttTask::new("Design".to_owned(), "Design".to_owned(), 4),
+                        GanttTask {
+                            start: TaskStart::After("Design".to_owned(), TaskAnchor::End, 0),
+                            ..GanttTask::new("Build".to_owned(), "Build".to_owned(), 1)
+                        },
+                        GanttTask {
+                            milestone: true,
+                            start: TaskStart::After("Design".to_owned(), TaskAnchor::Start, 2),
+                            ..GanttTask::new("Release".to_owned(), "Release".to_owned(), 0)
+                        },
+                    ],
+                )
+            }),
+            ..Diagram::default()
+        };
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_resolve_timing_times() {
+        let step = |step: PlantUmlTimingStep| PlantUmlElement::Timing(step);
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            step(PlantUmlTimingStep::Participant(
+                PlantUmlTimingParticipant::new(
+                    PlantUmlTimingKind::Robust,
+                    "Web Browser".to_owned(),
+                    Some("WB".to_owned()),
+                ),
+            )),
+            step(PlantUmlTimingStep::AtParticipant("WB".to_owned())),
+            step(PlantUmlTimingStep::TimedStateChange(
+                PlantUmlTime::Absolute(0),
+                "Idle".to_owned(),
+            )),
+            step(PlantUmlTimingStep::TimedStateChange(
+                PlantUmlTime::Relative(100),
+                "Busy".to_owned(),
+            )),
+            step(PlantUmlTimingStep::At(PlantUmlTime::Absolute(20))),
+            step(PlantUmlTimingStep::StateChange(
+                "Web Browser".to_owned(),
+                "Loading".to_owned(),
+            )),
+            step(PlantUmlTimingStep::Constraint(
+                "WB".to_owned(),
+                PlantUmlTime::Relative(10),
+                PlantUmlTime::Relative(50),
+                None,
+            )),
+        ]);
+        let expected: Diagram = Diagram {
+            kind: DiagramKind::Timing(Timing::new(
+                vec![TimingParticipant {
+                    changes: vec![
+                        StateChange::new(0, "Idle".to_owned()),
+                        StateChange::new(20, "Loading".to_owned()),
+                        StateChange::new(100, "Busy".to_owned()),
+                    ],
+                    ..TimingParticipant::new(
+                        "WB".to_owned(),
+                        "Web Browser".to_owned(),
+                        TimingKind::Robust,
+                    )
+                }],
+                vec![TimeConstraint::new("WB".to_owned(), 30, 80, None)],
+            )),
+            ..Diagram::default()
+        };
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_leave_out_timing_steps_past_the_last_time() {
+        let step = |step: PlantUmlTimingStep| PlantUmlElement::Timing(step);
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            step(PlantUmlTimingStep::Participant(
+                PlantUmlTimingParticipant::new(PlantUmlTimingKind::Robust, "WB".to_owned(), None),
+            )),
+            step(PlantUmlTimingStep::At(PlantUmlTime::Absolute(
+                4_000_000_000,
+            ))),
+            step(PlantUmlTimingStep::StateChange(
+                "WB".to_owned(),
+                "Idle".to_owned(),
+            )),
+            step(PlantUmlTimingStep::At(PlantUmlTime::Relative(
+                4_000_000_000,
+            ))),
+            step(PlantUmlTimingStep::StateChange(
+                "WB".to_owned(),
+                "Busy".to_owned(),
+            )),
+            step(PlantUmlTimingStep::Constraint(
+                "WB".to_owned(),
+                PlantUmlTime::Relative(0),
+                PlantUmlTime::Relative(4_000_000_000),
+                None,
+            )),
+        ]);
+        let expected: Diagram = Diagram {
+            kind: DiagramKind::Timing(Timing::new(
+                vec![TimingParticipant {
+                    changes: vec![
+                        StateChange::new(4_000_000_000, "Idle".to_owned()),
+                        StateChange::new(4_000_000_000, "Busy".to_owned()),
+                    ],
+                    ..TimingParticipant::new("WB".to_owned(), "WB".to_owned(), TimingKind::Robust)
+                }],
+                vec![],
+            )),
+            ..Diagram::default()
+        };
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_nest_tree_nodes_by_depth() {
+        let tree_node = |depth: usize, side: Option<PlantUmlTreeSide>, text: &str| {
+            PlantUmlElement::TreeNode(PlantUmlTreeNode::new(depth, side, text.to_owned()))
+        };
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram {
+            kind: PlantUmlDiagramKind::MindMap,
+            ..PlantUmlDiagram::new(vec![
+                tree_node(1, None, "Root"),
+                tree_node(2, None, "Design"),
+                tree_node(3, None, "UI"),
+                tree_node(2, Some(PlantUmlTreeSide::Left), "Docs"),
+                tree_node(3, None, "FAQ"),
+                tree_node(2, None, "Build"),
+            ])
+        };
+        let left = |node: TreeNode| TreeNode {
+            side: TreeSide::Left,
+            ..node
+        };
+        let expected: Diagram = Diagram {
+            kind: DiagramKind::Tree(Tree::new(
+                TreeKind::MindMap,
+                vec![TreeNode::new(
+                    "Root".to_owned(),
+                    vec![
+                        TreeNode::new(
+                            "Design".to_owned(),
+                            vec![TreeNode::new("UI".to_owned(), vec![])],
+                        ),
+                        // A "*" node stays on the side of its parent
+                        left(TreeNode::new(
+                            "Docs".to_owned(),
+                            vec![left(TreeNode::new("FAQ".to_owned(), vec![]))],
+                        )),
+                        TreeNode::new("Build".to_owned(), vec![]),
+                    ],
+                )],
+            )),
+            ..Diagram::default()
+        };
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_map_objects_and_maps_with_their_fields() {
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Element(PlantUmlDeclaration {
+                fields: vec![
+                    PlantUmlField::new("host".to_owned(), Some("db.local".to_owned())),
+                    PlantUmlField::new("debug".to_owned(), None),
+                ],
+                ..PlantUmlDeclaration::new(PlantUmlElementKind::Object, "server".to_owned(), None)
+            }),
+            PlantUmlElement::Element(PlantUmlDeclaration {
+                fields: vec![PlantUmlField::new("http".to_owned(), Some("80".to_owned()))],
+                ..PlantUmlDeclaration::new(PlantUmlElementKind::Map, "ports".to_owned(), None)
+            }),
+        ]);
+        let expected: Diagram = Diagram::new(
+            None,
+            vec![
+                Node::new(
+                    "server".to_owned(),
+                    NodeType::Object(Object {
+                        fields: vec![
+                            Field::new("host".to_owned(), Some("db.local".to_owned())),
+                            Field::new("debug".to_owned(), None),
+                        ],
+                        ..Object::new(ObjectKind::Object, "server".to_owned())
+                    }),
+                ),
+                Node::new(
+                    "ports".to_owned(),
+                    NodeType::Object(Object {
+                        fields: vec![Field::new("http".to_owned(), Some("80".to_owned()))],
+                        ..Object::new(ObjectKind::Map, "ports".to_owned())
+                    }),
+                ),
+            ],
+            vec![],
+        );
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_map_states_and_pseudo_states() {
+        let arrow = || {
+            PlantUmlArrow::new(
+                None,
+                PlantUmlArrowLine::Solid,
+                Some(PlantUmlArrowHead::Arrow),
+            )
+        };
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                "[*]".to_owned(),
+                "Idle".to_owned(),
+                arrow(),
+                None,
+            )),
+            PlantUmlElement::StateDescription("Active".to_owned(), "busy".to_owned()),
+            PlantUmlElement::Container(
+                PlantUmlContainerKind::State,
+                "Active".to_owned(),
+                None,
+                vec![PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "Loading".to_owned(),
+                    "[*]".to_owned(),
+                    arrow(),
+                    None,
+                ))],
+            ),
+            PlantUmlElement::Element(PlantUmlDeclaration {
+                stereotypes: vec!["choice".to_owned()],
+                ..PlantUmlDeclaration::new(PlantUmlElementKind::State, "c".to_owned(), None)
+            }),
+            PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                "Idle".to_owned(),
+                "c".to_owned(),
+                arrow(),
+                Some("start [ready] / init()".to_owned()),
+            )),
+        ]);
+        let expected: Diagram = Diagram::new(
+            None,
+            vec![
+                Node::new(
+                    "[*].initial".to_owned(),
+                    NodeType::State(State::new(StateKind::Initial, "[*]".to_owned())),
+                ),
+                Node::new(
+                    "Idle".to_owned(),
+                    NodeType::State(State::new(StateKind::Simple, "Idle".to_owned())),
+                ),
+                // The description given first is kept by the composite state
+                Node::new(
+                    "Active".to_owned(),
+                    NodeType::State(State {
+                        description: vec!["busy".to_owned()],
+                        ..State::new(StateKind::Simple, "Active".to_owned())
+                    }),
+                ),
+                Node {
+                    parent_id: Some("Active".to_owned()),
+                    ..Node::new(
+                        "Loading".to_owned(),
+                        NodeType::State(State::new(StateKind::Simple, "Loading".to_owned())),
+                    )
+                },
+                Node {
+                    parent_id: Some("Active".to_owned()),
+                    ..Node::new(
+                        "Active.[*].final".to_owned(),
+                        NodeType::State(State::new(StateKind::Final, "[*]".to_owned())),
+                    )
+                },
+                Node::new(
+                    "c".to_owned(),
+                    NodeType::State(State::new(StateKind::Choice, "c".to_owned())),
+                ),
+            ],
+            vec![
+                Edge::new(
+                    "[*].initial".to_owned(),
+                    "Idle".to_owned(),
+                    EdgeStyle::Solid,
+                    EdgeStyle::Arrow,
+                    None,
+                ),
+                Edge::new(
+                    "Loading".to_owned(),
+                    "Active.[*].final".to_owned(),
+                    EdgeStyle::Solid,
+                    EdgeStyle::Arrow,
+                    None,
+                ),
+                Edge::new(
+                    "Idle".to_owned(),
+                    "c".to_owned(),
+                    EdgeStyle::Solid,
+                    EdgeStyle::Arrow,
+                    Some("start [ready] / init()".to_owned()),
+                ),
+            ],
+        );
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_should_nest_activity_blocks() {
+        let step = |step: PlantUmlActivityStep| PlantUmlElement::Activity(step);
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            step(PlantUmlActivityStep::Start),
+            step(PlantUmlActivityStep::If(
+                "found?".to_owned(),
+                Some("yes".to_owned()),
+            )),
+            step(PlantUmlActivityStep::Action("Read".to_owned())),
+            PlantUmlElement::FragmentElse(Some("(no)".to_owned())),
+            step(PlantUmlActivityStep::While("retry?".to_owned(), None)),
+            step(PlantUmlActivityStep::Action("Wait".to_owned())),
+            // Ends no open "repeat", so it is ignored
+            step(PlantUmlActivityStep::RepeatWhile(
+                "late?".to_owned(),
+                None,
+                None,
+            )),
+            step(PlantUmlActivityStep::EndWhile(Some("done".to_owned()))),
+            step(PlantUmlActivityStep::EndIf),
+            step(PlantUmlActivityStep::Fork),
+            step(PlantUmlActivityStep::Action("Log".to_owned())),
+            step(PlantUmlActivityStep::ForkAgain),
+            PlantUmlElement::FragmentEnd,
+        ]);
+        let expected: Diagram = Diagram {
+            kind: DiagramKind::Activity(Activity::new(vec![
+                ActivityStep::Start,
+                ActivityStep::If(vec![
+                    Branch::new(
+                        Some("found?".to_owned()),
+                        Some("yes".to_owned()),
+                        vec![ActivityStep::Action("Read".to_owned())],
+                    ),
+                    Branch::new(
+                        None,
+                        Some("no".to_owned()),
+                        vec![ActivityStep::While(Loop {
+                            exit_label: Some("done".to_owned()),
+                            ..Loop::new(
+                                "retry?".to_owned(),
+                                vec![ActivityStep::Action("Wait".to_owned())],
+                            )
+                        })],
+                    ),
+                ]),
+                // Left open at the end of the diagram
+                ActivityStep::Fork(vec![
+                    vec![ActivityStep::Action("Log".to_owned())],
+                    vec![ActivityStep::End],
+                ]),
+            ])),
+            ..Diagram::default()
+        };
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_all_from_content_should_keep_diagram_names() {
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram {
+            name: Some("overview".to_owned()),
+            ..PlantUmlDiagram::new(vec![])
+        };
+        let expected: Vec<Diagram> = vec![Diagram {
+            name: Some("overview".to_owned()),
+            ..Diagram::default()
+        }];
+
+        let parser: Arc<dyn PlantUmlParser> =
+            Arc::new(PlantUmlParserMockImpl::new(Ok(plant_uml_diagram)));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result = repository.parse_all_from_content("Some dummy content");
+
+        assert_eq!(Ok(expected), result);
+    }
+
+    #[test]
+    fn parse_from_content_recovering_should_return_partial_diagram_and_diagnostics() {
+        let diagnostic: Diagnostic = Diagnostic::new(
+            "expected a name, found \"->\"".to_string(),
+            Span::new(27, 29),
+            3,
+            6,
+        );
+        let plant_uml_diagram: PlantUmlDiagram =
+            PlantUmlDiagram::new(vec![PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Component,
+                "A".to_owned(),
+                None,
+            ))]);
+        let expected: RecoveredDiagram = RecoveredDiagram::new(
+            Diagram::new(
+                None,
+                vec![Node::new(
+                    "A".to_owned(),
+                    NodeType::Component("A".to_owned()),
+                )],
+                vec![],
+            ),
+            vec![diagnostic.clone()],
+        );
+
+        let parser: Arc<dyn PlantUmlParser> = Arc::new(PlantUmlParserMockImpl::new_recovering(
+            plant_uml_diagram,
+            vec![diagnostic],
+        ));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result: RecoveredDiagram =
+            repository.parse_from_content_recovering("Some dummy content");
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn parse_from_content_recovering_should_keep_nodes_next_to_timing_statements() {
+        let plant_uml_diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![
+            PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Component,
+                "A".to_owned(),
+                None,
+            )),
+            PlantUmlElement::Timing(PlantUmlTimingStep::Participant(
+                PlantUmlTimingParticipant::new(PlantUmlTimingKind::Robust, "WB".to_owned(), None),
+            )),
+            PlantUmlElement::Timing(PlantUmlTimingStep::StateChange(
+                "WB".to_owned(),
+                "Idle".to_owned(),
+            )),
+        ]);
+        let expected: RecoveredDiagram = RecoveredDiagram::new(
+            Diagram::new(
+                None,
+                vec![Node::new(
+                    "A".to_owned(),
+                    NodeType::Component("A".to_owned()),
+                )],
+                vec![],
+            ),
+            vec![],
+        );
+
+        let parser: Arc<dyn PlantUmlParser> = Arc::new(PlantUmlParserMockImpl::new_recovering(
+            plant_uml_diagram,
+            vec![],
+        ));
+        let repository: DiagramRepositoryPlantUmlImpl = DiagramRepositoryPlantUmlImpl::new(parser);
+
+        let result: RecoveredDiagram =
+            repository.parse_from_content_recovering("Some dummy content");
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn parse_from_file_content_recovering_should_keep_the_lines_around_preprocessor_errors() {
+        let file: Arc<PathBuf> = Arc::new(PathBuf::from("a.puml"));
+        let source: PlantUmlSource = PlantUmlSource::new(
+            "@startuml\ncomponent A\n[".to_string(),
+            vec![
+                PlantUmlSourceLocation::new(Some(file.clone()), 1),
+                PlantUmlSourceLocation::new(Some(file.clone()), 3),
+                PlantUmlSourceLocation::new(Some(file.clone()), 4),
+            ],
+        );
+        let include_error: Diagnostic = Diagnostic {
+            file: Some(file.clone()),
+            ..Diagnostic::new(
+                "included file \"missing.puml\" does not exist".to_string(),
+                Span::new(10, 31),
+                2,
+                1,
+            )
+        };
+        let syntax_error: Diagnostic =
+            Diagnostic::new("expected a name".to_string(), Span::new(24, 24), 3, 2);
+        let plant_uml_diagram: PlantUmlDiagram =
+            PlantUmlDiagram::new(vec![PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Component,
+                "A".to_owned(),
+                None,
+            ))]);
+        let expected: RecoveredDiagram = RecoveredDiagram::new(
+            Diagram::new(
+                None,
+                vec![Node::new(
+                    "A".to_owned(),
+                    NodeType::Component("A".to_owned()),
+                )],
+                vec![],
+            ),
+            vec![
+                include_error.clone(),
+                Diagnostic {
+                    file: Some(file),
+                    line: 4,
+                    ..syntax_error.clone()
+                },
+            ],
+        );
+
+        let parser: Arc<dyn PlantUmlParser> = Arc::new(PlantUmlParserMockImpl::new_recovering(
+            plant_uml_diagram,
+            vec![syntax_error],
+        ));
+        let preprocessor: Arc<dyn PlantUmlPreprocessor> = Arc::new(
+            PlantUmlPreprocessorMockImpl::new_recovering(source, vec![include_error]),
+        );
+        let repository: DiagramRepositoryPlantUmlImpl =
+            DiagramRepositoryPlantUmlImpl::new(parser).with_preprocessor(preprocessor);
+
+        let result: RecoveredDiagram = repository
+            .parse_from_file_content_recovering("Some dummy content", Path::new("a.puml"));
+
+        assert_eq!(expected, result);
+    }
+
+    struct PlantUmlParserMockImpl {
+        result: Result<PlantUmlDiagram, PlantUmlParserError>,
+        recovered: Option<(PlantUmlDiagram, Vec<Diagnostic>)>,
+    }
+
+    impl PlantUmlParserMockImpl {
+        fn new(result: Result<PlantUmlDiagram, PlantUmlParserError>) -> Self {
+            Self {
+                result,
+                recovered: None,
+            }
+        }
+
+        fn new_recovering(diagram: PlantUmlDiagram, diagnostics: Vec<Diagnostic>) -> Self {
+            Self {
+                result: Err(PlantUmlParserError::UnknownError(
+                    "Unexpected call to parse".to_string(),
+                )),
+                recovered: Some((diagram, diagnostics)),
+            }
+        }
+    }
+
+    impl PlantUmlParser for PlantUmlParserMockImpl {
+        fn parse(&self, _input: &str) -> Result<PlantUmlDiagram, PlantUmlParserError> {
+            self.result.clone()
+        }
+
+        fn parse_all(&self, _input: &str) -> Result<Vec<PlantUmlDiagram>, PlantUmlParserError> {
+            self.result.clone().map(|diagram| vec![diagram])
+        }
+
+        fn parse_recovering(&self, _input: &str) -> (PlantUmlDiagram, Vec<Diagnostic>) {
+            if let Some(value) = self.recovered.as_ref() {
+                return value.clone();
+            }
+
+            panic!("Unexpected call to PlantUmlParserMockImpl.parse_recovering")
+        }
+    }
+
+    struct PlantUmlPreprocessorMockImpl {
+        result: Result<PlantUmlSource, PlantUmlPreprocessorError>,
+        recovered: Option<(PlantUmlSource, Vec<Diagnostic>)>,
+    }
+
+    impl PlantUmlPreprocessorMockImpl {
+        fn new(result: Result<PlantUmlSource, PlantUmlPreprocessorError>) -> Self {
+            Self {
+                result,
+                recovered: None,
+            }
+        }
+
+        fn new_recovering(source: PlantUmlSource, diagnostics: Vec<Diagnostic>) -> Self {
+            Self {
+                result: Ok(PlantUmlSource::default()),
+                recovered: Some((source, diagnostics)),
+            }
+        }
+    }
+
+    impl PlantUmlPreprocessor for PlantUmlPreprocessorMockImpl {
+        fn preprocess(
+            &self,
+            _input: &str,
+            _origin: Option<&Path>,
+        ) -> Result<PlantUmlSource, PlantUmlPreprocessorError> {
+            self.result.clone()
+        }
+
+        fn preprocess_recovering(
+            &self,
+            _input: &str,
+            _origin: Option<&Path>,
+        ) -> (PlantUmlSource, Vec<Diagnostic>) {
+            if let Some(value) = self.recovered.as_ref() {
+                return value.clone();
+            }
+
+            panic!("Unexpected call to PlantUmlPreprocessorMockImpl.preprocess_recovering")
         }
     }
 }
@@ -1,2 +1,4 @@
 pub mod models;
 pub mod pest;
+pub mod preprocessor;
+pub mod yaml;
@@ -1 +1,2 @@
 pub mod plant_uml_diagram;
+pub mod plant_uml_source;
@@ -1,16 +1,538 @@
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PlantUmlDiagram {
+    // From "@startuml(id=name)" or "@startuml name"
+    pub name: Option<String>,
     pub elements: Vec<PlantUmlElement>,
+    // Told by the start tag
+    pub kind: PlantUmlDiagramKind,
 }
 
 impl PlantUmlDiagram {
     pub fn new(elements: Vec<PlantUmlElement>) -> Self {
-        Self { elements }
+        Self {
+            name: None,
+            elements,
+            kind: PlantUmlDiagramKind::default(),
+        }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum PlantUmlDiagramKind {
+    #[default]
+    Uml, // @startuml
+    MindMap, // @startmindmap
+    Wbs,     // @startwbs
+    Json,    // @startjson
+    Yaml,    // @startyaml
+    Gantt,   // @startgantt
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PlantUmlElement {
-    // Name, Alias
-    Component(String, Option<String>),
+    Element(PlantUmlDeclaration),
+    Relationship(PlantUmlRelationship),
+    // Kind, Name, Alias, Children
+    Container(
+        PlantUmlContainerKind,
+        String,
+        Option<String>,
+        Vec<PlantUmlElement>,
+    ),
+    // Kept as trivia for formatters, attached to the element that follows it
+    Comment(PlantUmlComment),
+    // Kind, Text
+    Directive(PlantUmlDirectiveKind, String),
+    Note(PlantUmlNote),
+    Direction(PlantUmlLayoutDirection),
+    // Kind, Name
+    Activation(PlantUmlActivationKind, String),
+    // Kind, Label
+    FragmentStart(PlantUmlFragmentKind, Option<String>),
+    // Label
+    FragmentElse(Option<String>),
+    FragmentEnd,
+    // Label
+    Divider(String),
+    // Label
+    Delay(Option<String>),
+    // Name, Text, e.g., "Idle : waiting for input"
+    StateDescription(String, String),
+    Activity(PlantUmlActivityStep),
+    Timing(PlantUmlTimingStep),
+    TreeNode(PlantUmlTreeNode),
+    // Keys from the outermost one, e.g., ["address", "city"]
+    Highlight(Vec<String>),
+    Data(PlantUmlDataValue),
+    Gantt(PlantUmlGanttStatement),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlActivationKind {
+    Activate,   // activate
+    Deactivate, // deactivate
+    Destroy,    // destroy
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlFragmentKind {
+    Alt,
+    Opt,
+    Loop,
+    Par,
+    Break,
+    Critical,
+    Group,
+}
+
+/// A line of an activity diagram, in the new syntax. The "else" and "end" keywords are read
+/// as the fragment ones, since sequence diagrams use them too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlActivityStep {
+    Start, // start
+    Stop,  // stop
+    // Text, e.g., ":Read the file;"
+    Action(String),
+    // Name, e.g., "|Backend|"
+    Swimlane(String),
+    // Condition, Label, e.g., "if (found?) then (yes)"
+    If(String, Option<String>),
+    ElseIf(String, Option<String>),
+    EndIf,
+    // Condition, Label, e.g., "while (more?) is (yes)"
+    While(String, Option<String>),
+    // Label, e.g., "endwhile (no)"
+    EndWhile(Option<String>),
+    Repeat,
+    // Condition, Label, Exit label, e.g., "repeat while (more?) is (yes) not (no)"
+    RepeatWhile(String, Option<String>, Option<String>),
+    Fork,      // fork
+    ForkAgain, // fork again
+    EndFork,   // end fork
+}
+
+/// A line of a timing diagram. State changes are either grouped by time, after "@100",
+/// or by participant, after "@WB".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlTimingStep {
+    Participant(PlantUmlTimingParticipant),
+    // e.g., "@100" or "@+50"
+    At(PlantUmlTime),
+    // Name, e.g., "@WB"
+    AtParticipant(String),
+    // Name, State, e.g., "WB is Idle"
+    StateChange(String, String),
+    // Time, State, e.g., "+50 is Busy"
+    TimedStateChange(PlantUmlTime, String),
+    // Name, Start, End, Label, e.g., "WB@0 <-> @50 : {50 ms lag}"
+    Constraint(String, PlantUmlTime, PlantUmlTime, Option<String>),
+}
+
+/// A participant of a timing diagram, e.g., 'concise "Web User" as WU'
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlantUmlTimingParticipant {
+    pub kind: PlantUmlTimingKind,
+    pub name: String,
+    pub alias: Option<String>,
+    // Only for clocks, e.g., "with period 50 pulse 15 offset 10"
+    pub clock: Option<PlantUmlClock>,
+}
+
+impl PlantUmlTimingParticipant {
+    pub fn new(kind: PlantUmlTimingKind, name: String, alias: Option<String>) -> Self {
+        Self {
+            kind,
+            name,
+            alias,
+            clock: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlTimingKind {
+    Robust,
+    Concise,
+    Clock,
+    Binary,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlantUmlClock {
+    pub period: u32,
+    pub pulse: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlTime {
+    Absolute(u32), // 100
+    Relative(u32), // +50
+}
+
+/// A line of a mind map or of a work breakdown structure, e.g., "** Child" or "--:Left\nnode;"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlantUmlTreeNode {
+    // Length of the marker, 1 for the root
+    pub depth: usize,
+    // Missing for "*", the node then stays on the side of its parent
+    pub side: Option<PlantUmlTreeSide>,
+    pub text: String,
+}
+
+impl PlantUmlTreeNode {
+    pub fn new(depth: usize, side: Option<PlantUmlTreeSide>, text: String) -> Self {
+        Self { depth, side, text }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlTreeSide {
+    Right, // +
+    Left,  // -
+}
+
+/// A value read from the data of a JSON or YAML diagram, entries keep their order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlDataValue {
+    Null,
+    Boolean(bool),
+    // e.g., "42" or "1.50", decimals are kept as written
+    Number(String),
+    Text(String),
+    Array(Vec<PlantUmlDataValue>),
+    Object(Vec<(String, PlantUmlDataValue)>),
+}
+
+/// A line of a gantt chart
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlGanttStatement {
+    // e.g., "Project starts 2026-01-05"
+    ProjectStart(PlantUmlDate),
+    // e.g., "saturday are closed"
+    ClosedWeekday(PlantUmlWeekday),
+    // First, Last, e.g., "2026-01-05 to 2026-01-09 is closed"
+    ClosedDates(PlantUmlDate, PlantUmlDate),
+    Task(PlantUmlTask),
+}
+
+/// A task or a milestone along with its constraints, e.g., "[Design] as [D] lasts 5 days"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlantUmlTask {
+    pub name: String,
+    pub alias: Option<String>,
+    // Starts at the end of the task given before, e.g., "then [Build] lasts 3 days"
+    pub then: bool,
+    pub constraints: Vec<PlantUmlTaskConstraint>,
+}
+
+impl PlantUmlTask {
+    pub fn new(name: String, alias: Option<String>) -> Self {
+        Self {
+            name,
+            alias,
+            then: false,
+            constraints: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlTaskConstraint {
+    // Days, e.g., "lasts 2 weeks"
+    Lasts(u32),
+    // e.g., "starts at [Design]'s end"
+    Starts(PlantUmlTaskStart),
+    // A milestone, e.g., "happens 2026-01-20"
+    Happens(PlantUmlTaskStart),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlTaskStart {
+    On(PlantUmlDate),
+    // Name, Anchor, Days, e.g., "2 days after [Design]'s end"
+    After(String, PlantUmlTaskAnchor, u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlTaskAnchor {
+    Start, // [Design]'s start
+    End,   // [Design]'s end
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlantUmlDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl PlantUmlDate {
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        Self { year, month, day }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlWeekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlLayoutDirection {
+    TopToBottom, // top to bottom direction
+    LeftToRight, // left to right direction
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlantUmlNote {
+    pub text: String,
+    pub alias: Option<String>,
+    // Name or alias of the element the note is attached to
+    pub anchor: Option<String>,
+    pub side: PlantUmlNoteSide,
+}
+
+impl PlantUmlNote {
+    pub fn new(text: String) -> Self {
+        Self {
+            text,
+            alias: None,
+            anchor: None,
+            side: PlantUmlNoteSide::Right,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlNoteSide {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlDirectiveKind {
+    Title,
+    Header,
+    Footer,
+    Caption,
+    Legend,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlComment {
+    Line(String),  // ' text
+    Block(String), // /' text '/
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlantUmlDeclaration {
+    pub kind: PlantUmlElementKind,
+    pub name: String,
+    pub alias: Option<String>,
+    pub stereotypes: Vec<String>,
+    // Created by a reference such as "[A] --> b" rather than an explicit declaration
+    pub implicit: bool,
+    // Type parameters, e.g., "K, V" for "class Map<K, V>"
+    pub generics: Option<String>,
+    // Fields and methods from a class body, e.g., "class A { -id : int }"
+    pub members: Vec<PlantUmlMember>,
+    // From "state Idle : waiting for input"
+    pub description: Option<String>,
+    // Fields of an object or entries of a map, e.g., "object A { id = 1 }"
+    pub fields: Vec<PlantUmlField>,
+}
+
+impl PlantUmlDeclaration {
+    pub fn new(kind: PlantUmlElementKind, name: String, alias: Option<String>) -> Self {
+        Self {
+            kind,
+            name,
+            alias,
+            stereotypes: Vec::new(),
+            implicit: false,
+            generics: None,
+            members: Vec::new(),
+            description: None,
+            fields: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlantUmlField {
+    pub name: String,
+    // Missing when the field is written alone, e.g., "object A { id }"
+    pub value: Option<String>,
+}
+
+impl PlantUmlField {
+    pub fn new(name: String, value: Option<String>) -> Self {
+        Self { name, value }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlantUmlMember {
+    // Everything but the visibility and modifiers, e.g., "count() : int"
+    pub text: String,
+    pub visibility: Option<PlantUmlVisibility>,
+    // From "{static}" or "{classifier}"
+    pub is_static: bool,
+    // From "{abstract}"
+    pub is_abstract: bool,
+    // Has parentheses or was marked "{method}"
+    pub is_method: bool,
+}
+
+impl PlantUmlMember {
+    pub fn new(text: String) -> Self {
+        Self {
+            text,
+            visibility: None,
+            is_static: false,
+            is_abstract: false,
+            is_method: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlVisibility {
+    Public,         // +
+    Private,        // -
+    Protected,      // #
+    PackagePrivate, // ~
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlElementKind {
+    Component,
+    Rectangle,
+    Circle,
+    Interface,
+    Actor,
+    Database,
+    Queue,
+    Artifact,
+    Storage,
+    UseCase,
+    Boundary,
+    Participant,
+    Control,
+    Entity,
+    Collections,
+    Class,
+    AbstractClass,
+    Enum,
+    State,
+    Object,
+    Map,
+    Node,
+    Cloud,
+    Port,    // port
+    PortIn,  // portin
+    PortOut, // portout
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlContainerKind {
+    Package,
+    Node,
+    Folder,
+    Frame,
+    Cloud,
+    Component,
+    State,
+    Rectangle,
+    Database,
+    Storage,
+    Artifact,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlantUmlRelationship {
+    pub from: String,
+    pub to: String,
+    pub arrow: PlantUmlArrow,
+    pub label: Option<String>,
+    pub stereotypes: Vec<String>,
+    // From "A \"1\" --> \"*\" B"
+    pub from_multiplicity: Option<String>,
+    pub to_multiplicity: Option<String>,
+}
+
+impl PlantUmlRelationship {
+    pub fn new(from: String, to: String, arrow: PlantUmlArrow, label: Option<String>) -> Self {
+        Self {
+            from,
+            to,
+            arrow,
+            label,
+            stereotypes: Vec::new(),
+            from_multiplicity: None,
+            to_multiplicity: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlantUmlArrow {
+    pub head_left: Option<PlantUmlArrowHead>,
+    pub line: PlantUmlArrowLine,
+    pub head_right: Option<PlantUmlArrowHead>,
+    pub direction: Option<PlantUmlArrowDirection>,
+    // From "-[hidden]->"
+    pub hidden: bool,
+    // A single line character, e.g., "->", drawn solid in sequence diagrams where "-->" is dashed
+    pub short: bool,
+}
+
+impl PlantUmlArrow {
+    pub fn new(
+        head_left: Option<PlantUmlArrowHead>,
+        line: PlantUmlArrowLine,
+        head_right: Option<PlantUmlArrowHead>,
+    ) -> Self {
+        Self {
+            head_left,
+            line,
+            head_right,
+            direction: None,
+            hidden: false,
+            short: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlArrowDirection {
+    Up,    // -up-> or -u->
+    Down,  // -down-> or -d->
+    Left,  // -left-> or -l->
+    Right, // -right-> or -r->
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlArrowHead {
+    Arrow,       // < or >
+    Open,        // << or >>
+    Extension,   // <| or |>
+    Composition, // *
+    Aggregation, // o
+    Socket,      // ( or )
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlantUmlArrowLine {
+    Solid,  // --
+    Dotted, // ..
 }
@@ -0,0 +1,44 @@
+use std::{path::PathBuf, sync::Arc};
+
+use lib_core::domain::entities::diagnostic::Diagnostic;
+
+/// Diagram source once preprocessed, remembering where each of its lines comes from
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PlantUmlSource {
+    pub text: String,
+    // One per line of `text`
+    pub locations: Vec<PlantUmlSourceLocation>,
+}
+
+impl PlantUmlSource {
+    pub fn new(text: String, locations: Vec<PlantUmlSourceLocation>) -> Self {
+        Self { text, locations }
+    }
+
+    /// Points a diagnostic found in the preprocessed text back to the file and line
+    /// it was written at; columns and spans keep referring to the preprocessed line
+    pub fn locate(&self, diagnostic: Diagnostic) -> Diagnostic {
+        match self.locations.get(diagnostic.line.saturating_sub(1)) {
+            Some(location) => Diagnostic {
+                file: location.file.clone(),
+                line: location.line,
+                ..diagnostic
+            },
+            None => diagnostic,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlantUmlSourceLocation {
+    // None for the content given to the preprocessor without a file
+    pub file: Option<Arc<PathBuf>>,
+    // 1-based
+    pub line: usize,
+}
+
+impl PlantUmlSourceLocation {
+    pub fn new(file: Option<Arc<PathBuf>>, line: usize) -> Self {
+        Self { file, line }
+    }
+}
@@ -1,62 +1,2119 @@
-use pest::Parser;
+use std::collections::{HashMap, HashSet};
+
+use lib_core::domain::entities::{
+    diagnostic::{Diagnostic, Severity, Span},
+    gantt::MAX_DAYS,
+};
+use pest::{
+    Parser,
+    error::{ErrorVariant, InputLocation},
+};
 use pest_derive::Parser;
 
-use crate::{
-    adapters::repositories::diagram_repository_plant_uml_impl::{
-        PlantUmlParser, PlantUmlParserError,
-    },
-    infra::models::plant_uml_diagram::{PlantUmlDiagram, PlantUmlElement},
-};
+use crate::{
+    adapters::repositories::diagram_repository_plant_uml_impl::{
+        PlantUmlParser, PlantUmlParserError,
+    },
+    infra::models::plant_uml_diagram::{
+        PlantUmlActivationKind, PlantUmlActivityStep, PlantUmlArrow, PlantUmlArrowDirection,
+        PlantUmlArrowHead, PlantUmlArrowLine, PlantUmlClock, PlantUmlComment,
+        PlantUmlContainerKind, PlantUmlDate, PlantUmlDeclaration, PlantUmlDiagram,
+        PlantUmlDiagramKind, PlantUmlDirectiveKind, PlantUmlElement, PlantUmlElementKind,
+        PlantUmlField, PlantUmlFragmentKind, PlantUmlGanttStatement, PlantUmlLayoutDirection,
+        PlantUmlMember, PlantUmlNote, PlantUmlNoteSide, PlantUmlRelationship, PlantUmlTask,
+        PlantUmlTaskAnchor, PlantUmlTaskConstraint, PlantUmlTaskStart, PlantUmlTime,
+        PlantUmlTimingKind, PlantUmlTimingParticipant, PlantUmlTimingStep, PlantUmlTreeNode,
+        PlantUmlTreeSide, PlantUmlVisibility, PlantUmlWeekday,
+    },
+    infra::yaml::yaml_data_reader::YamlDataReader,
+};
+
+pub struct PestPlantUmlParser;
+
+impl PestPlantUmlParser {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn parse_with_pest_parser<'a>(
+        &self,
+        input: &'a str,
+    ) -> Result<pest::iterators::Pairs<'a, Rule>, PlantUmlParserError> {
+        PestParser::parse(Rule::file, input).map_err(|e| {
+            PlantUmlParserError::SyntaxError(self.create_diagnostic_from_error(input, 0, e))
+        })
+    }
+
+    fn parse_recovering_with_pest_parser<'a>(
+        &self,
+        input: &'a str,
+    ) -> Result<pest::iterators::Pairs<'a, Rule>, Diagnostic> {
+        PestParser::parse(Rule::recovering_diagram, input)
+            .map_err(|e| self.create_diagnostic_from_error(input, 0, e))
+    }
+
+    /// Reads an unrecognized line again on its own to explain what was expected
+    fn create_diagnostic_from_invalid_statement(
+        &self,
+        input: &str,
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Diagnostic {
+        let offset: usize = pair.as_span().start();
+
+        match PestParser::parse(Rule::isolated_statement, pair.as_str()) {
+            Err(e) => self.create_diagnostic_from_error(input, offset, e),
+            Ok(_) => self.create_diagnostic_at(
+                input,
+                Span::new(offset, pair.as_span().end()),
+                "unrecognized statement".to_string(),
+            ),
+        }
+    }
+
+    /// Reads the line of an unrecognized part of a mind map, a work breakdown structure or
+    /// a gantt chart again on its own, from its start, to explain what was expected
+    fn create_diagnostic_from_invalid_line(
+        &self,
+        input: &str,
+        pair: pest::iterators::Pair<Rule>,
+        kind: &PlantUmlDiagramKind,
+    ) -> Diagnostic {
+        let span: pest::Span = pair.as_span();
+        let line_start: usize = input[..span.start()]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let rule: Rule = match kind {
+            PlantUmlDiagramKind::Gantt => Rule::isolated_gantt_statement,
+            _ => Rule::isolated_tree_node,
+        };
+
+        match PestParser::parse(rule, &input[line_start..span.end()]) {
+            Err(e) => self.create_diagnostic_from_error(input, line_start, e),
+            Ok(_) => self.create_diagnostic_at(
+                input,
+                Span::new(span.start(), span.end()),
+                "unrecognized statement".to_string(),
+            ),
+        }
+    }
+
+    /// Statements which are well formed but cannot be used as they are
+    fn create_diagnostics_for_statements(
+        &self,
+        input: &str,
+        pairs: pest::iterators::Pairs<Rule>,
+        declared: &[String],
+    ) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> =
+            self.create_diagnostics_for_undeclared_state_changes(input, pairs.clone(), declared);
+
+        diagnostics.extend(self.create_diagnostics_for_numbers_out_of_range(input, pairs.clone()));
+        diagnostics.extend(self.create_diagnostics_for_task_references(input, pairs.clone()));
+        diagnostics.extend(self.create_diagnostics_for_unbalanced_fragments(input, pairs));
+        diagnostics
+    }
+
+    /// Every "else" and "end" of a sequence diagram belongs to an open fragment, and every
+    /// fragment is closed by an "end". Activity diagrams use them for their own blocks.
+    fn create_diagnostics_for_unbalanced_fragments(
+        &self,
+        input: &str,
+        pairs: pest::iterators::Pairs<Rule>,
+    ) -> Vec<Diagnostic> {
+        if pairs
+            .clone()
+            .flatten()
+            .any(|p| is_activity_rule(&p.as_rule()))
+        {
+            return vec![];
+        }
+
+        let mut diagnostics: Vec<Diagnostic> = Vec::new();
+        let mut open: Vec<pest::iterators::Pair<Rule>> = Vec::new();
+        let span_of = |pair: &pest::iterators::Pair<Rule>| {
+            Span::new(pair.as_span().start(), pair.as_span().end())
+        };
+
+        for pair in pairs.flatten() {
+            match pair.as_rule() {
+                Rule::fragment_start => open.push(pair),
+                Rule::fragment_else if open.is_empty() => {
+                    diagnostics.push(self.create_diagnostic_at(
+                        input,
+                        span_of(&pair),
+                        "\"else\" outside of a fragment".to_string(),
+                    ))
+                }
+                Rule::fragment_end if open.pop().is_none() => {
+                    diagnostics.push(self.create_diagnostic_at(
+                        input,
+                        span_of(&pair),
+                        "\"end\" without a fragment to close".to_string(),
+                    ))
+                }
+                _ => {}
+            }
+        }
+        for fragment in open {
+            let keyword: &str = fragment
+                .clone()
+                .into_inner()
+                .next()
+                .map_or("", |k| k.as_str());
+
+            diagnostics.push(self.create_diagnostic_at(
+                input,
+                span_of(&fragment),
+                format!("unclosed \"{}\" fragment, expected \"end\"", keyword),
+            ));
+        }
+
+        diagnostics
+    }
+
+    /// "X is Y" only reads as a state change when X is a declared timing participant,
+    /// otherwise any line with "is" in it would be taken for one, e.g., "this is garbage"
+    fn create_diagnostics_for_undeclared_state_changes(
+        &self,
+        input: &str,
+        pairs: pest::iterators::Pairs<Rule>,
+        declared: &[String],
+    ) -> Vec<Diagnostic> {
+        pairs
+            .flatten()
+            .filter(|p| p.as_rule() == Rule::state_change)
+            .filter_map(|p| {
+                let subject: pest::iterators::Pair<Rule> = p.clone().into_inner().next()?;
+                let name: String = self.create_name_from_pair(subject.clone()).text;
+
+                (subject.as_rule() != Rule::timing_time && !declared.contains(&name)).then(|| {
+                    self.create_diagnostic_at(
+                        input,
+                        Span::new(p.as_span().start(), p.as_span().end()),
+                        format!(
+                            "unrecognized statement, \"{}\" is not a timing participant",
+                            name
+                        ),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Numbers are read as u32, and so are times once resolved, e.g., "@+4000000000" after
+    /// "@4000000000" is past the last one. Gantt dates must be days of the calendar, and
+    /// durations no longer than `MAX_DAYS`.
+    fn create_diagnostics_for_numbers_out_of_range(
+        &self,
+        input: &str,
+        pairs: pest::iterators::Pairs<Rule>,
+    ) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> = Vec::new();
+        let mut time: u32 = 0;
+        let diagnostic_at = |pair: &pest::iterators::Pair<Rule>, message: String| {
+            self.create_diagnostic_at(
+                input,
+                Span::new(pair.as_span().start(), pair.as_span().end()),
+                message,
+            )
+        };
+
+        for pair in pairs.flatten() {
+            if is_unreadable_number(&pair) {
+                diagnostics.push(diagnostic_at(
+                    &pair,
+                    format!(
+                        "number too large, \"{}\" is more than {}",
+                        pair.as_str(),
+                        u32::MAX
+                    ),
+                ));
+                continue;
+            }
+            if let Some(message) = gantt_value_error(&pair) {
+                diagnostics.push(diagnostic_at(&pair, message));
+                continue;
+            }
+            if !matches!(
+                pair.as_rule(),
+                Rule::time_marker | Rule::state_change | Rule::timing_constraint
+            ) || pair
+                .clone()
+                .into_inner()
+                .flatten()
+                .any(|p| is_unreadable_number(&p))
+            {
+                continue;
+            }
+
+            let times: Vec<PlantUmlTime> = pair
+                .clone()
+                .into_inner()
+                .filter(|p| p.as_rule() == Rule::timing_time)
+                .map(|p| timing_time(p.as_str()))
+                .collect();
+            let resolved: Option<u32> = match (pair.as_rule(), times.as_slice()) {
+                (Rule::timing_constraint, [start, end]) => {
+                    resolve_time(time, start).and_then(|start| resolve_time(start, end))
+                }
+                (Rule::timing_constraint, _) => Some(time),
+                (_, [at]) => resolve_time(time, at).inspect(|at| time = *at),
+                // At a participant, times start over
+                (Rule::time_marker, _) => Some(0).inspect(|at| time = *at),
+                _ => Some(time),
+            };
+
+            if resolved.is_none() {
+                diagnostics.push(diagnostic_at(
+                    &pair,
+                    format!("time too large, it ends after {}", u32::MAX),
+                ));
+            }
+        }
+
+        diagnostics
+    }
+
+    /// Tasks may only start from tasks of the plan, and not in the end from themselves,
+    /// e.g., "[A] starts at [B]'s end" along with "[B] starts at [A]'s end", or they could
+    /// not be scheduled. Only the last start given to a task counts, as when scheduling.
+    fn create_diagnostics_for_task_references(
+        &self,
+        input: &str,
+        pairs: pest::iterators::Pairs<Rule>,
+    ) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> = Vec::new();
+        let mut names_by_alias: HashMap<String, String> = HashMap::new();
+        let mut tasks: Vec<String> = Vec::new();
+        // The task each task starts from, and where it is given
+        let mut starts: HashMap<String, (String, Span)> = HashMap::new();
+        let mut previous: Option<String> = None;
+        let span_of = |pair: &pest::iterators::Pair<Rule>| {
+            Span::new(pair.as_span().start(), pair.as_span().end())
+        };
+
+        // Statements with invalid values are left out of the plan
+        for statement in pairs.flatten().filter(|p| {
+            p.as_rule() == Rule::task_statement
+                && !p
+                    .clone()
+                    .into_inner()
+                    .flatten()
+                    .any(|p| gantt_value_error(&p).is_some())
+        }) {
+            let mut then: Option<Span> = None;
+            let mut name: Option<String> = None;
+
+            for statement_pair in statement.into_inner() {
+                match (statement_pair.as_rule(), &name) {
+                    (Rule::then_keyword, _) => then = Some(span_of(&statement_pair)),
+                    (Rule::task_name, None) => {
+                        let given: String = task_name(statement_pair);
+                        let resolved: String = names_by_alias.get(&given).cloned().unwrap_or(given);
+
+                        if !tasks.contains(&resolved) {
+                            tasks.push(resolved.clone());
+                        }
+                        // "then" starts the task at the end of the one given before
+                        if let (Some(then), Some(previous)) = (then.take(), &previous) {
+                            starts.insert(resolved.clone(), (previous.clone(), then));
+                        }
+                        name = Some(resolved);
+                    }
+                    (Rule::task_name, Some(name)) => {
+                        names_by_alias.insert(task_name(statement_pair), name.clone());
+                    }
+                    (Rule::task_start | Rule::milestone, Some(name)) => {
+                        let anchor: Option<pest::iterators::Pair<Rule>> = statement_pair
+                            .into_inner()
+                            .find(|p| p.as_rule() == Rule::task_anchor);
+
+                        match anchor {
+                            Some(anchor) => {
+                                let other: String = anchor
+                                    .clone()
+                                    .into_inner()
+                                    .next()
+                                    .map(task_name)
+                                    .unwrap_or_default();
+                                let other: String =
+                                    names_by_alias.get(&other).cloned().unwrap_or(other);
+
+                                starts.insert(name.clone(), (other, span_of(&anchor)));
+                            }
+                            None => {
+                                starts.remove(name);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            previous = name;
+        }
+
+        for (name, (other, span)) in tasks
+            .iter()
+            .filter_map(|name| Some((name, starts.get(name)?)))
+        {
+            if !tasks.contains(other) {
+                diagnostics.push(self.create_diagnostic_at(
+                    input,
+                    span.clone(),
+                    format!("unknown task, [{}] is not in the plan of [{}]", other, name),
+                ));
+            }
+        }
+
+        let mut visited: HashSet<&str> = HashSet::new();
+        for task in tasks.iter() {
+            let mut path: Vec<&str> = Vec::new();
+            let mut current: Option<&str> = Some(task);
+
+            while let Some(id) = current.filter(|id| !visited.contains(id)) {
+                if let Some(index) = path.iter().position(|p| *p == id) {
+                    let cycle: &[&str] = &path[index..];
+                    let ids: Vec<String> = cycle
+                        .iter()
+                        .chain([&id])
+                        .map(|id| format!("[{}]", id))
+                        .collect();
+                    // The start closing the cycle is the last one given
+                    let span: Span = cycle
+                        .iter()
+                        .filter_map(|id| starts.get(*id))
+                        .map(|(_, span)| span.clone())
+                        .max_by_key(|span| span.start)
+                        .unwrap_or(Span::new(0, 0));
+
+                    diagnostics.push(self.create_diagnostic_at(
+                        input,
+                        span,
+                        format!("tasks start from each other: {}", ids.join(" -> ")),
+                    ));
+                    break;
+                }
+                path.push(id);
+                current = starts.get(id).map(|(other, _)| other.as_str());
+            }
+            visited.extend(path);
+        }
+
+        diagnostics
+    }
+
+    /// `offset` is where the text given to pest starts within `input`,
+    /// so errors from a single re-parsed line are located in the whole source
+    fn create_diagnostic_from_error(
+        &self,
+        input: &str,
+        offset: usize,
+        error: pest::error::Error<Rule>,
+    ) -> Diagnostic {
+        let (start, end) = match error.location {
+            InputLocation::Pos(start) => (offset + start, None),
+            InputLocation::Span((start, end)) => (offset + start, Some(offset + end)),
+        };
+        let found: &str = match end {
+            Some(end) => &input[start..end],
+            None => unexpected_token_at(input, start),
+        };
+
+        let mut expected: Vec<String> = Vec::new();
+        let message: String = match &error.variant {
+            ErrorVariant::ParsingError { positives, .. } => {
+                // Comments and plain text are allowed almost anywhere, listing them would only add noise
+                for rule in positives
+                    .iter()
+                    .filter(|rule| !matches!(rule, Rule::COMMENT | Rule::outside_text))
+                {
+                    let description: String = describe_rule(rule);
+
+                    if !expected.contains(&description) {
+                        expected.push(description);
+                    }
+                }
+
+                let found: String = match found {
+                    "" => "end of input".to_string(),
+                    token => format!("\"{}\"", token),
+                };
+
+                match expected.split_last() {
+                    None => format!("unexpected {}", found),
+                    Some((last, [])) => format!("expected {}, found {}", last, found),
+                    Some((last, others)) => {
+                        format!(
+                            "expected {} or {}, found {}",
+                            others.join(", "),
+                            last,
+                            found
+                        )
+                    }
+                }
+            }
+            ErrorVariant::CustomError { message } => message.clone(),
+        };
+
+        Diagnostic {
+            expected,
+            ..self.create_diagnostic_at(input, Span::new(start, start + found.len()), message)
+        }
+    }
+
+    fn create_diagnostic_at(&self, input: &str, span: Span, message: String) -> Diagnostic {
+        let position: pest::Position =
+            pest::Position::new(input, span.start).unwrap_or(pest::Position::from_start(input));
+        let (line, column) = position.line_col();
+
+        Diagnostic {
+            source_line: position
+                .line_of()
+                .trim_end_matches(['\r', '\n'])
+                .to_string(),
+            ..Diagnostic::new(message, span, line, column)
+        }
+    }
+
+    /// Fails when the data of a JSON or YAML diagram cannot be read
+    fn create_diagram_from_pair(
+        &self,
+        input: &str,
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Result<PlantUmlDiagram, Diagnostic> {
+        let mut diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![]);
+
+        for diagram_pair in pair.into_inner() {
+            match diagram_pair.as_rule() {
+                Rule::start_tag
+                | Rule::mindmap_start
+                | Rule::wbs_start
+                | Rule::json_start
+                | Rule::yaml_start
+                | Rule::gantt_start => {
+                    diagram.kind = diagram_kind(&diagram_pair.as_rule());
+                    diagram.name = self.create_diagram_name_from_pair(diagram_pair);
+                }
+                Rule::data_text => diagram.elements.push(self.create_data_from_pair(
+                    input,
+                    diagram_pair,
+                    &diagram.kind,
+                )?),
+                _ => diagram
+                    .elements
+                    .extend(self.create_elements_from_pair(diagram_pair)),
+            }
+        }
+
+        self.move_descriptions_to_class_members(&mut diagram.elements);
+
+        Ok(diagram)
+    }
+
+    fn create_data_from_pair(
+        &self,
+        input: &str,
+        pair: pest::iterators::Pair<Rule>,
+        kind: &PlantUmlDiagramKind,
+    ) -> Result<PlantUmlElement, Diagnostic> {
+        let span: pest::Span = pair.as_span();
+        // The indentation of the first line was skipped as whitespace, it is read again
+        // so an indented YAML document stays consistent
+        let line_start: usize = input[..span.start()]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let start: usize = match input[line_start..span.start()].trim().is_empty() {
+            true => line_start,
+            false => span.start(),
+        };
+
+        let reader: YamlDataReader = YamlDataReader::new();
+        let data: &str = &input[start..span.end()];
+
+        match kind {
+            PlantUmlDiagramKind::Json => reader.read_json(data),
+            _ => reader.read(data),
+        }
+        .map(PlantUmlElement::Data)
+        .map_err(|error| {
+            let offset: usize = start + error.offset;
+
+            self.create_diagnostic_at(input, Span::new(offset, offset), error.message)
+        })
+    }
+
+    fn create_diagram_name_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> Option<String> {
+        pair.into_inner()
+            .find(|p| matches!(p.as_rule(), Rule::diagram_id | Rule::diagram_title))
+            .map(|p| p.as_str().trim().to_string())
+            .filter(|name| !name.is_empty())
+    }
+
+    fn create_elements_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> Vec<PlantUmlElement> {
+        match pair.as_rule() {
+            Rule::element_declaration
+            | Rule::bracketed_declaration
+            | Rule::class_declaration
+            | Rule::object_declaration
+            | Rule::state_declaration => {
+                let comments: Vec<PlantUmlElement> = self.create_comments_within_pair(&pair);
+
+                [vec![self.create_declaration_from_pair(pair)], comments].concat()
+            }
+            Rule::relationship => {
+                let comments: Vec<PlantUmlElement> = self.create_comments_within_pair(&pair);
+
+                [self.create_relationship_from_pair(pair), comments].concat()
+            }
+            Rule::COMMENT => vec![self.create_comment_from_pair(pair)],
+            Rule::directive => vec![self.create_directive_from_pair(pair)],
+            Rule::note => vec![self.create_note_from_pair(pair)],
+            Rule::layout_direction => {
+                vec![PlantUmlElement::Direction(
+                    match pair.into_inner().next().map(|p| p.as_rule()) {
+                        Some(Rule::left_to_right) => PlantUmlLayoutDirection::LeftToRight,
+                        _ => PlantUmlLayoutDirection::TopToBottom,
+                    },
+                )]
+            }
+            Rule::container | Rule::recovering_container => {
+                vec![self.create_container_from_pair(pair)]
+            }
+            Rule::activation => vec![self.create_activation_from_pair(pair)],
+            Rule::described_state => vec![self.create_state_description_from_pair(pair)],
+            Rule::action
+            | Rule::swimlane
+            | Rule::activity_start
+            | Rule::activity_stop
+            | Rule::if_start
+            | Rule::else_if
+            | Rule::if_end
+            | Rule::while_start
+            | Rule::while_end
+            | Rule::repeat_start
+            | Rule::repeat_end
+            | Rule::fork_start
+            | Rule::fork_again
+            | Rule::fork_end => vec![self.create_activity_step_from_pair(pair)],
+            Rule::timing_participant
+            | Rule::time_marker
+            | Rule::state_change
+            | Rule::timing_constraint => self
+                .create_timing_step_from_pair(pair)
+                .into_iter()
+                .collect(),
+            Rule::project_start | Rule::closed_days | Rule::task_statement => self
+                .create_gantt_statement_from_pair(pair)
+                .into_iter()
+                .collect(),
+            Rule::fragment_start => vec![self.create_fragment_start_from_pair(pair)],
+            Rule::fragment_else => vec![PlantUmlElement::FragmentElse(fragment_label(pair))],
+            Rule::fragment_end => vec![PlantUmlElement::FragmentEnd],
+            Rule::divider => vec![PlantUmlElement::Divider(
+                pair.into_inner().as_str().trim().to_string(),
+            )],
+            Rule::delay => vec![PlantUmlElement::Delay(
+                Some(pair.into_inner().as_str().trim().to_string()).filter(|l| !l.is_empty()),
+            )],
+            Rule::tree_node => vec![self.create_tree_node_from_pair(pair)],
+            Rule::highlight => vec![PlantUmlElement::Highlight(
+                pair.into_inner()
+                    .map(|p| self.create_name_from_pair(p).text)
+                    .collect(),
+            )],
+            _ => Vec::new(),
+        }
+    }
+
+    // Comments skipped in the middle or at the end of a statement, e.g., "component A ' note",
+    // come after it so they stay attached to the element that follows.
+    fn create_comments_within_pair(
+        &self,
+        pair: &pest::iterators::Pair<Rule>,
+    ) -> Vec<PlantUmlElement> {
+        pair.clone()
+            .into_inner()
+            .flatten()
+            .filter(|p| p.as_rule() == Rule::COMMENT)
+            .map(|p| self.create_comment_from_pair(p))
+            .collect()
+    }
+
+    fn create_comment_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> PlantUmlElement {
+        let comment: Option<pest::iterators::Pair<Rule>> = pair.into_inner().next();
+        let text = |p: pest::iterators::Pair<Rule>| p.into_inner().as_str().trim().to_string();
+
+        PlantUmlElement::Comment(match comment {
+            Some(p) if p.as_rule() == Rule::block_comment => PlantUmlComment::Block(text(p)),
+            Some(p) => PlantUmlComment::Line(text(p)),
+            None => PlantUmlComment::Line(String::new()),
+        })
+    }
+
+    fn create_directive_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> PlantUmlElement {
+        let mut kind: PlantUmlDirectiveKind = PlantUmlDirectiveKind::Title;
+        let mut lines: Vec<&str> = Vec::new();
+
+        for directive_pair in pair.into_inner() {
+            match directive_pair.as_rule() {
+                Rule::directive_keyword => {
+                    kind = match directive_pair.as_str() {
+                        "header" => PlantUmlDirectiveKind::Header,
+                        "footer" => PlantUmlDirectiveKind::Footer,
+                        "caption" => PlantUmlDirectiveKind::Caption,
+                        "legend" => PlantUmlDirectiveKind::Legend,
+                        _ => PlantUmlDirectiveKind::Title,
+                    };
+                }
+                Rule::directive_text => lines.push(directive_pair.as_str().trim()),
+                _ => {}
+            }
+        }
+
+        PlantUmlElement::Directive(kind, lines.join("\n").trim().to_string())
+    }
+
+    fn create_note_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> PlantUmlElement {
+        let mut side: Option<PlantUmlNoteSide> = None;
+        let mut names: Vec<ParsedName> = Vec::new();
+        let mut lines: Vec<String> = Vec::new();
+
+        for note_pair in pair.into_inner() {
+            match note_pair.as_rule() {
+                Rule::note_side => {
+                    side = Some(match note_pair.as_str() {
+                        "left" => PlantUmlNoteSide::Left,
+                        "top" => PlantUmlNoteSide::Top,
+                        "bottom" => PlantUmlNoteSide::Bottom,
+                        _ => PlantUmlNoteSide::Right,
+                    });
+                }
+                Rule::identifier
+                | Rule::quoted_name
+                | Rule::bracketed_name
+                | Rule::parenthesized_name
+                | Rule::actor_name => {
+                    names.push(self.create_name_from_pair(note_pair));
+                }
+                Rule::note_text => lines.push(unescape(note_pair.as_str().trim())),
+                _ => {}
+            }
+        }
+
+        let mut names = names.into_iter().map(|name| name.text);
+        let mut note: PlantUmlNote = PlantUmlNote::new(lines.join("\n").trim().to_string());
+
+        match side {
+            Some(side) => {
+                note.anchor = names.next();
+                note.side = side;
+            }
+            // Either "note \"text\" as N1" or "note as N1" followed by the text lines
+            None => {
+                if lines.is_empty() {
+                    note.text = names.next().unwrap_or_default();
+                }
+                note.alias = names.next();
+            }
+        }
+
+        PlantUmlElement::Note(note)
+    }
+
+    fn create_activation_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> PlantUmlElement {
+        let mut kind: PlantUmlActivationKind = PlantUmlActivationKind::Activate;
+        let mut name: ParsedName = ParsedName::default();
+
+        for activation_pair in pair.into_inner() {
+            match activation_pair.as_rule() {
+                Rule::activation_keyword => {
+                    kind = match activation_pair.as_str() {
+                        "deactivate" => PlantUmlActivationKind::Deactivate,
+                        "destroy" => PlantUmlActivationKind::Destroy,
+                        _ => PlantUmlActivationKind::Activate,
+                    };
+                }
+                Rule::identifier
+                | Rule::quoted_name
+                | Rule::bracketed_name
+                | Rule::parenthesized_name
+                | Rule::actor_name => {
+                    name = self.create_name_from_pair(activation_pair);
+                }
+                _ => {}
+            }
+        }
+
+        PlantUmlElement::Activation(kind, name.text)
+    }
+
+    fn create_state_description_from_pair(
+        &self,
+        pair: pest::iterators::Pair<Rule>,
+    ) -> PlantUmlElement {
+        let mut name: ParsedName = ParsedName::default();
+        let mut text: String = String::new();
+
+        for description_pair in pair.into_inner() {
+            match description_pair.as_rule() {
+                Rule::identifier
+                | Rule::quoted_name
+                | Rule::bracketed_name
+                | Rule::parenthesized_name
+                | Rule::actor_name => {
+                    name = self.create_name_from_pair(description_pair);
+                }
+                Rule::state_description => {
+                    text = description_pair.into_inner().as_str().trim().to_string();
+                }
+                _ => {}
+            }
+        }
+
+        PlantUmlElement::StateDescription(name.text, text)
+    }
+
+    fn create_activity_step_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> PlantUmlElement {
+        let rule: Rule = pair.as_rule();
+        let mut text: String = String::new();
+        let mut condition: String = String::new();
+        let mut label: Option<String> = None;
+        let mut exit_label: Option<String> = None;
+        let inner_text =
+            |p: pest::iterators::Pair<Rule>| p.into_inner().as_str().trim().to_string();
+
+        for step_pair in pair.into_inner() {
+            match step_pair.as_rule() {
+                // Each line of a multi-line action is trimmed of its indentation
+                Rule::action_text | Rule::swimlane_name => {
+                    text = step_pair
+                        .as_str()
+                        .trim()
+                        .lines()
+                        .map(str::trim)
+                        .collect::<Vec<&str>>()
+                        .join("\n");
+                }
+                Rule::condition => condition = inner_text(step_pair),
+                Rule::branch_label => label = Some(inner_text(step_pair)),
+                Rule::exit_label => exit_label = Some(inner_text(step_pair)),
+                _ => {}
+            }
+        }
+
+        PlantUmlElement::Activity(match rule {
+            Rule::action => PlantUmlActivityStep::Action(text),
+            Rule::swimlane => PlantUmlActivityStep::Swimlane(text),
+            Rule::activity_stop => PlantUmlActivityStep::Stop,
+            Rule::if_start => PlantUmlActivityStep::If(condition, label),
+            Rule::else_if => PlantUmlActivityStep::ElseIf(condition, label),
+            Rule::if_end => PlantUmlActivityStep::EndIf,
+            Rule::while_start => PlantUmlActivityStep::While(condition, label),
+            Rule::while_end => PlantUmlActivityStep::EndWhile(exit_label),
+            Rule::repeat_start => PlantUmlActivityStep::Repeat,
+            Rule::repeat_end => PlantUmlActivityStep::RepeatWhile(condition, label, exit_label),
+            Rule::fork_start => PlantUmlActivityStep::Fork,
+            Rule::fork_again => PlantUmlActivityStep::ForkAgain,
+            Rule::fork_end => PlantUmlActivityStep::EndFork,
+            _ => PlantUmlActivityStep::Start,
+        })
+    }
+
+    /// Statements with numbers too large to be read are left out, a diagnostic tells why
+    fn create_timing_step_from_pair(
+        &self,
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Option<PlantUmlElement> {
+        if pair
+            .clone()
+            .into_inner()
+            .flatten()
+            .any(|p| is_unreadable_number(&p))
+        {
+            return None;
+        }
+
+        let rule: Rule = pair.as_rule();
+        let mut participant: PlantUmlTimingParticipant =
+            PlantUmlTimingParticipant::new(PlantUmlTimingKind::Robust, String::new(), None);
+        let mut name: Option<ParsedName> = None;
+        let mut alias: Option<ParsedName> = None;
+        let mut times: Vec<PlantUmlTime> = Vec::new();
+        let mut state: String = String::new();
+        let mut label: Option<String> = None;
+
+        for step_pair in pair.into_inner() {
+            match step_pair.as_rule() {
+                Rule::timing_keyword => {
+                    participant.kind = match step_pair.as_str() {
+                        "concise" => PlantUmlTimingKind::Concise,
+                        "clock" => PlantUmlTimingKind::Clock,
+                        "binary" => PlantUmlTimingKind::Binary,
+                        _ => PlantUmlTimingKind::Robust,
+                    };
+                }
+                Rule::identifier
+                | Rule::quoted_name
+                | Rule::bracketed_name
+                | Rule::parenthesized_name
+                | Rule::actor_name => name = Some(self.create_name_from_pair(step_pair)),
+                Rule::alias => {
+                    alias = step_pair
+                        .into_inner()
+                        .next()
+                        .map(|p| self.create_name_from_pair(p));
+                }
+                Rule::clock_period => {
+                    participant.clock = Some(self.create_clock_from_pair(step_pair))
+                }
+                Rule::timing_time => times.push(timing_time(step_pair.as_str())),
+                Rule::timing_state => {
+                    state = match step_pair.into_inner().next() {
+                        Some(p) if p.as_rule() == Rule::quoted_name => {
+                            self.create_name_from_pair(p).text
+                        }
+                        Some(p) => p.as_str().trim().to_string(),
+                        None => String::new(),
+                    };
+                }
+                // The braces around the duration are dropped, e.g., "{50 ms lag}"
+                Rule::relationship_label => {
+                    label = step_pair
+                        .into_inner()
+                        .find(|p| p.as_rule() == Rule::label_text)
+                        .map(|p| p.as_str().trim())
+                        .map(|l| {
+                            l.strip_prefix('{')
+                                .and_then(|l| l.strip_suffix('}'))
+                                .unwrap_or(l)
+                        })
+                        .map(|l| l.trim().to_string())
+                        .filter(|l| !l.is_empty());
+                }
+                _ => {}
+            }
+        }
+
+        let (name, alias) = name.unwrap_or_default().with_alias(alias);
+        let mut times = times.into_iter();
+
+        Some(PlantUmlElement::Timing(match rule {
+            Rule::timing_participant => {
+                PlantUmlTimingStep::Participant(PlantUmlTimingParticipant {
+                    name,
+                    alias,
+                    ..participant
+                })
+            }
+            Rule::time_marker => match times.next() {
+                Some(time) => PlantUmlTimingStep::At(time),
+                None => PlantUmlTimingStep::AtParticipant(name),
+            },
+            Rule::state_change => match times.next() {
+                Some(time) => PlantUmlTimingStep::TimedStateChange(time, state),
+                None => PlantUmlTimingStep::StateChange(name, state),
+            },
+            _ => PlantUmlTimingStep::Constraint(
+                name,
+                times.next().unwrap_or(PlantUmlTime::Absolute(0)),
+                times.next().unwrap_or(PlantUmlTime::Absolute(0)),
+                label,
+            ),
+        }))
+    }
+
+    fn create_clock_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> PlantUmlClock {
+        let number = |p: pest::iterators::Pair<Rule>| p.into_inner().as_str().parse().ok();
+        let mut clock: PlantUmlClock = PlantUmlClock {
+            period: 0,
+            pulse: None,
+            offset: None,
+        };
+
+        for clock_pair in pair.into_inner() {
+            match clock_pair.as_rule() {
+                Rule::timing_number => clock.period = clock_pair.as_str().parse().unwrap_or(0),
+                Rule::clock_pulse => clock.pulse = number(clock_pair),
+                Rule::clock_offset => clock.offset = number(clock_pair),
+                _ => {}
+            }
+        }
+
+        clock
+    }
+
+    fn create_gantt_statement_from_pair(
+        &self,
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Option<PlantUmlElement> {
+        if pair
+            .clone()
+            .into_inner()
+            .flatten()
+            .any(|p| gantt_value_error(&p).is_some())
+        {
+            return None;
+        }
+
+        let rule: Rule = pair.as_rule();
+        let mut dates: Vec<PlantUmlDate> = Vec::new();
+        let mut weekday: Option<PlantUmlWeekday> = None;
+        let mut names: Vec<String> = Vec::new();
+        let mut task: PlantUmlTask = PlantUmlTask::new(String::new(), None);
+
+        for statement_pair in pair.into_inner() {
+            match statement_pair.as_rule() {
+                Rule::gantt_date => dates.push(gantt_date(statement_pair)),
+                Rule::weekday => weekday = Some(gantt_weekday(statement_pair.as_str())),
+                Rule::then_keyword => task.then = true,
+                Rule::task_name => names.push(task_name(statement_pair)),
+                Rule::task_duration => task.constraints.push(PlantUmlTaskConstraint::Lasts(
+                    statement_pair
+                        .into_inner()
+                        .next()
+                        .and_then(task_days)
+                        .unwrap_or(0),
+                )),
+                Rule::task_start => task.constraints.push(PlantUmlTaskConstraint::Starts(
+                    self.create_task_start_from_pair(statement_pair),
+                )),
+                Rule::milestone => task.constraints.push(PlantUmlTaskConstraint::Happens(
+                    self.create_task_start_from_pair(statement_pair),
+                )),
+                _ => {}
+            }
+        }
+
+        let mut names = names.into_iter();
+        let mut dates = dates.into_iter();
+
+        Some(PlantUmlElement::Gantt(match (rule, weekday) {
+            (Rule::task_statement, _) => PlantUmlGanttStatement::Task(PlantUmlTask {
+                name: names.next().unwrap_or_default(),
+                alias: names.next(),
+                ..task
+            }),
+            (Rule::closed_days, Some(weekday)) => PlantUmlGanttStatement::ClosedWeekday(weekday),
+            (Rule::closed_days, None) => {
+                let first: PlantUmlDate = dates.next().unwrap_or(PlantUmlDate::new(0, 1, 1));
+                let last: PlantUmlDate = dates.next().unwrap_or(first.clone());
+
+                PlantUmlGanttStatement::ClosedDates(first, last)
+            }
+            _ => PlantUmlGanttStatement::ProjectStart(
+                dates.next().unwrap_or(PlantUmlDate::new(0, 1, 1)),
+            ),
+        }))
+    }
+
+    fn create_task_start_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> PlantUmlTaskStart {
+        let mut days: u32 = 0;
+
+        for start_pair in pair.into_inner() {
+            match start_pair.as_rule() {
+                Rule::gantt_date => return PlantUmlTaskStart::On(gantt_date(start_pair)),
+                Rule::task_days => days = task_days(start_pair).unwrap_or(0),
+                Rule::task_anchor => {
+                    let mut anchor_pairs = start_pair.into_inner();
+                    let name: String = anchor_pairs.next().map(task_name).unwrap_or_default();
+                    let anchor: PlantUmlTaskAnchor = match anchor_pairs.next() {
+                        Some(p) if p.as_rule() == Rule::anchor_start => PlantUmlTaskAnchor::Start,
+                        _ => PlantUmlTaskAnchor::End,
+                    };
+
+                    return PlantUmlTaskStart::After(name, anchor, days);
+                }
+                _ => {}
+            }
+        }
+
+        PlantUmlTaskStart::After(String::new(), PlantUmlTaskAnchor::End, days)
+    }
+
+    fn create_fragment_start_from_pair(
+        &self,
+        pair: pest::iterators::Pair<Rule>,
+    ) -> PlantUmlElement {
+        let kind: PlantUmlFragmentKind = match pair.clone().into_inner().next().map(|p| p.as_str())
+        {
+            Some("opt") => PlantUmlFragmentKind::Opt,
+            Some("loop") => PlantUmlFragmentKind::Loop,
+            Some("par") => PlantUmlFragmentKind::Par,
+            Some("break") => PlantUmlFragmentKind::Break,
+            Some("critical") => PlantUmlFragmentKind::Critical,
+            Some("group") => PlantUmlFragmentKind::Group,
+            _ => PlantUmlFragmentKind::Alt,
+        };
+
+        PlantUmlElement::FragmentStart(kind, fragment_label(pair))
+    }
+
+    fn create_container_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> PlantUmlElement {
+        let mut kind: PlantUmlContainerKind = PlantUmlContainerKind::Package;
+        let mut name: ParsedName = ParsedName::default();
+        let mut alias: Option<ParsedName> = None;
+        let mut children: Vec<PlantUmlElement> = Vec::new();
+
+        for container_pair in pair.into_inner() {
+            match container_pair.as_rule() {
+                Rule::container_keyword => {
+                    kind = match container_pair.as_str() {
+                        "node" => PlantUmlContainerKind::Node,
+                        "folder" => PlantUmlContainerKind::Folder,
+                        "frame" => PlantUmlContainerKind::Frame,
+                        "cloud" => PlantUmlContainerKind::Cloud,
+                        "component" => PlantUmlContainerKind::Component,
+                        "state" => PlantUmlContainerKind::State,
+                        "rectangle" => PlantUmlContainerKind::Rectangle,
+                        "database" => PlantUmlContainerKind::Database,
+                        "storage" => PlantUmlContainerKind::Storage,
+                        "artifact" => PlantUmlContainerKind::Artifact,
+                        _ => PlantUmlContainerKind::Package,
+                    };
+                }
+                Rule::identifier
+                | Rule::quoted_name
+                | Rule::bracketed_name
+                | Rule::parenthesized_name
+                | Rule::actor_name => {
+                    name = self.create_name_from_pair(container_pair);
+                }
+                Rule::alias => {
+                    alias = container_pair
+                        .into_inner()
+                        .next()
+                        .map(|p| self.create_name_from_pair(p));
+                }
+                _ => children.extend(self.create_elements_from_pair(container_pair)),
+            }
+        }
+
+        let (name, alias) = name.with_alias(alias);
+
+        PlantUmlElement::Container(kind, name, alias, children)
+    }
+
+    fn create_declaration_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> PlantUmlElement {
+        let mut kind: PlantUmlElementKind = PlantUmlElementKind::Component;
+        let mut name: ParsedName = ParsedName::default();
+        let mut alias: Option<ParsedName> = None;
+        let mut stereotypes: Vec<String> = Vec::new();
+        let mut generics: Option<String> = None;
+        let mut members: Vec<PlantUmlMember> = Vec::new();
+        let mut description: Option<String> = None;
+        let mut fields: Vec<PlantUmlField> = Vec::new();
+        // Without a keyword, the shorthand tells what is declared, e.g., an actor for ":User:"
+        let is_shorthand: bool = pair.as_rule() == Rule::bracketed_declaration;
+
+        for declaration_pair in pair.into_inner() {
+            match declaration_pair.as_rule() {
+                Rule::class_keyword => {
+                    kind = match declaration_pair.as_str() {
+                        "class" => PlantUmlElementKind::Class,
+                        "enum" => PlantUmlElementKind::Enum,
+                        _ => PlantUmlElementKind::AbstractClass,
+                    };
+                }
+                Rule::interface_keyword | Rule::interface_shorthand => {
+                    kind = PlantUmlElementKind::Interface
+                }
+                Rule::object_keyword => {
+                    kind = match declaration_pair.as_str() {
+                        "map" => PlantUmlElementKind::Map,
+                        _ => PlantUmlElementKind::Object,
+                    };
+                }
+                Rule::state_keyword => kind = PlantUmlElementKind::State,
+                Rule::generics => {
+                    generics = Some(declaration_pair.into_inner().as_str().trim().to_string());
+                }
+                Rule::class_body => {
+                    members.extend(
+                        declaration_pair
+                            .into_inner()
+                            .filter(|p| p.as_rule() == Rule::class_member)
+                            .filter_map(|p| self.create_member_from_pair(p)),
+                    );
+                }
+                Rule::element_keyword => {
+                    kind = match declaration_pair.as_str() {
+                        "rectangle" => PlantUmlElementKind::Rectangle,
+                        "circle" => PlantUmlElementKind::Circle,
+                        "interface" => PlantUmlElementKind::Interface,
+                        "actor" => PlantUmlElementKind::Actor,
+                        "database" => PlantUmlElementKind::Database,
+                        "queue" => PlantUmlElementKind::Queue,
+                        "artifact" => PlantUmlElementKind::Artifact,
+                        "storage" => PlantUmlElementKind::Storage,
+                        "usecase" => PlantUmlElementKind::UseCase,
+                        "boundary" => PlantUmlElementKind::Boundary,
+                        "participant" => PlantUmlElementKind::Participant,
+                        "control" => PlantUmlElementKind::Control,
+                        "entity" => PlantUmlElementKind::Entity,
+                        "collections" => PlantUmlElementKind::Collections,
+                        "node" => PlantUmlElementKind::Node,
+                        "cloud" => PlantUmlElementKind::Cloud,
+                        "port" => PlantUmlElementKind::Port,
+                        "portin" => PlantUmlElementKind::PortIn,
+                        "portout" => PlantUmlElementKind::PortOut,
+                        _ => PlantUmlElementKind::Component,
+                    };
+                }
+                Rule::identifier
+                | Rule::quoted_name
+                | Rule::bracketed_name
+                | Rule::parenthesized_name
+                | Rule::actor_name => {
+                    name = self.create_name_from_pair(declaration_pair);
+                    if let Some(shorthand) = name.shorthand.clone().filter(|_| is_shorthand) {
+                        kind = shorthand;
+                    }
+                }
+                Rule::alias => {
+                    alias = declaration_pair
+                        .into_inner()
+                        .next()
+                        .map(|p| self.create_name_from_pair(p));
+                }
+                Rule::stereotype => {
+                    stereotypes.push(self.create_stereotype_from_pair(declaration_pair));
+                }
+                Rule::state_description => {
+                    description = Some(declaration_pair.into_inner().as_str().trim().to_string())
+                        .filter(|d| !d.is_empty());
+                }
+                Rule::object_body => {
+                    fields.extend(
+                        declaration_pair
+                            .into_inner()
+                            .map(|p| self.create_field_from_pair(p)),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        let (name, alias) = name.with_alias(alias);
+
+        PlantUmlElement::Element(PlantUmlDeclaration {
+            stereotypes,
+            generics,
+            members,
+            description,
+            fields,
+            ..PlantUmlDeclaration::new(kind, name, alias)
+        })
+    }
+
+    fn create_tree_node_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> PlantUmlElement {
+        let mut node: PlantUmlTreeNode = PlantUmlTreeNode::new(0, None, String::new());
+
+        for node_pair in pair.into_inner() {
+            match node_pair.as_rule() {
+                Rule::tree_marker => {
+                    let marker: &str = node_pair.as_str();
+
+                    node.depth = marker.len();
+                    node.side = match marker.chars().next() {
+                        Some('+') => Some(PlantUmlTreeSide::Right),
+                        Some('-') => Some(PlantUmlTreeSide::Left),
+                        _ => None,
+                    };
+                }
+                // Each line of a multi-line text is trimmed of its indentation
+                Rule::tree_text | Rule::tree_multiline_text => {
+                    node.text = node_pair
+                        .as_str()
+                        .trim()
+                        .lines()
+                        .map(str::trim)
+                        .collect::<Vec<&str>>()
+                        .join("\n");
+                }
+                _ => {}
+            }
+        }
+
+        PlantUmlElement::TreeNode(node)
+    }
+
+    fn create_field_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> PlantUmlField {
+        let mut field: PlantUmlField = PlantUmlField::new(String::new(), None);
+
+        for field_pair in pair.into_inner() {
+            match field_pair.as_rule() {
+                Rule::field_name => field.name = field_pair.as_str().trim().to_string(),
+                Rule::field_value => {
+                    field.value = Some(field_pair.as_str().trim().to_string());
+                }
+                _ => {}
+            }
+        }
+
+        field
+    }
+
+    /// Separator lines such as "--" or "== Private ==" only split compartments and are dropped
+    fn create_member_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> Option<PlantUmlMember> {
+        let line: &str = pair.as_str().trim();
+
+        if ["--", "..", "==", "__"]
+            .iter()
+            .any(|separator| line.starts_with(separator))
+        {
+            return None;
+        }
+
+        let mut member: PlantUmlMember = PlantUmlMember::new(String::new());
+        let mut forced_kind: Option<bool> = None;
+
+        for member_pair in pair.into_inner() {
+            match member_pair.as_rule() {
+                Rule::member_modifier => match member_pair.as_str() {
+                    "{abstract}" => member.is_abstract = true,
+                    "{field}" => forced_kind = Some(false),
+                    "{method}" => forced_kind = Some(true),
+                    _ => member.is_static = true,
+                },
+                Rule::member_visibility => {
+                    member.visibility = Some(match member_pair.as_str() {
+                        "-" => PlantUmlVisibility::Private,
+                        "#" => PlantUmlVisibility::Protected,
+                        "~" => PlantUmlVisibility::PackagePrivate,
+                        _ => PlantUmlVisibility::Public,
+                    });
+                }
+                Rule::member_text => member.text = member_pair.as_str().trim().to_string(),
+                _ => {}
+            }
+        }
+
+        member.is_method = forced_kind.unwrap_or(member.text.contains('('));
+
+        Some(member)
+    }
+
+    /// "Name : text" describes a state, unless Name is a declared class, in which case the
+    /// text is one of its members, e.g., "ArrayList : size()"
+    fn move_descriptions_to_class_members(&self, elements: &mut Vec<PlantUmlElement>) {
+        let classes: Vec<String> = declared_class_names(elements);
+        let mut members: Vec<(String, PlantUmlMember)> =
+            take_class_descriptions(elements, &classes)
+                .into_iter()
+                .map(|(name, text)| {
+                    let member: PlantUmlMember = PestParser::parse(Rule::class_member, &text)
+                        .ok()
+                        .and_then(|mut pairs| pairs.next())
+                        .and_then(|pair| self.create_member_from_pair(pair))
+                        .unwrap_or_else(|| PlantUmlMember::new(text.clone()));
+
+                    (name, member)
+                })
+                .collect();
+
+        add_class_members(elements, &mut members);
+    }
+
+    // Bracketed endpoints, as in "[A] --> [B]", also declare the components they refer to
+    fn create_relationship_from_pair(
+        &self,
+        pair: pest::iterators::Pair<Rule>,
+    ) -> Vec<PlantUmlElement> {
+        let mut endpoints: Vec<ParsedName> = Vec::new();
+        let mut arrow: PlantUmlArrow = PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, None);
+        let mut label: Option<String> = None;
+        let mut stereotypes: Vec<String> = Vec::new();
+        let mut from_multiplicity: Option<String> = None;
+        let mut to_multiplicity: Option<String> = None;
+        let mut has_arrow: bool = false;
+
+        for relationship_pair in pair.into_inner() {
+            match relationship_pair.as_rule() {
+                Rule::identifier
+                | Rule::quoted_name
+                | Rule::bracketed_name
+                | Rule::parenthesized_name
+                | Rule::actor_name
+                | Rule::terminal_state => {
+                    endpoints.push(self.create_name_from_pair(relationship_pair));
+                }
+                // The arrow has not been read yet when the multiplicity is on the left
+                Rule::multiplicity => {
+                    let text: Option<String> =
+                        Some(relationship_pair.into_inner().as_str().trim().to_string())
+                            .filter(|m| !m.is_empty());
+
+                    match has_arrow {
+                        false => from_multiplicity = text,
+                        true => to_multiplicity = text,
+                    }
+                }
+                Rule::arrow => {
+                    arrow = self.create_arrow_from_pair(relationship_pair);
+                    has_arrow = true;
+                }
+                Rule::relationship_label => {
+                    for label_pair in relationship_pair.into_inner() {
+                        match label_pair.as_rule() {
+                            Rule::stereotype => {
+                                stereotypes.push(self.create_stereotype_from_pair(label_pair));
+                            }
+                            Rule::label_text => {
+                                label = Some(label_pair.as_str().trim().to_string())
+                                    .filter(|l| !l.is_empty());
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut elements: Vec<PlantUmlElement> = endpoints
+            .iter()
+            .filter_map(|endpoint| {
+                let kind: PlantUmlElementKind = endpoint.shorthand.clone()?;
+
+                Some(PlantUmlElement::Element(PlantUmlDeclaration {
+                    implicit: true,
+                    ..PlantUmlDeclaration::new(kind, endpoint.text.clone(), None)
+                }))
+            })
+            .collect();
+
+        let to: String = endpoints.pop().unwrap_or_default().text;
+        let from: String = endpoints.pop().unwrap_or_default().text;
+
+        elements.push(PlantUmlElement::Relationship(PlantUmlRelationship {
+            stereotypes,
+            from_multiplicity,
+            to_multiplicity,
+            ..PlantUmlRelationship::new(from, to, arrow, label)
+        }));
+
+        elements
+    }
+
+    fn create_name_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> ParsedName {
+        let rule: Rule = pair.as_rule();
+
+        match rule {
+            Rule::quoted_name
+            | Rule::bracketed_name
+            | Rule::parenthesized_name
+            | Rule::actor_name => ParsedName {
+                text: unescape(pair.into_inner().as_str()),
+                quoted: rule == Rule::quoted_name,
+                shorthand: match rule {
+                    Rule::bracketed_name => Some(PlantUmlElementKind::Component),
+                    Rule::parenthesized_name => Some(PlantUmlElementKind::UseCase),
+                    Rule::actor_name => Some(PlantUmlElementKind::Actor),
+                    _ => None,
+                },
+            },
+            _ => ParsedName {
+                text: pair.as_str().to_string(),
+                ..ParsedName::default()
+            },
+        }
+    }
+
+    fn create_stereotype_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> String {
+        pair.into_inner().as_str().trim().to_string()
+    }
+
+    fn create_arrow_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> PlantUmlArrow {
+        let mut arrow: PlantUmlArrow = PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, None);
+
+        for arrow_pair in pair.into_inner() {
+            match arrow_pair.as_rule() {
+                Rule::arrow_head_left => arrow.head_left = Some(arrow_head(arrow_pair.as_str())),
+                Rule::arrow_head_right => arrow.head_right = Some(arrow_head(arrow_pair.as_str())),
+                Rule::arrow_line => {
+                    arrow.short = arrow_pair.as_str().chars().count() == 1;
+
+                    for line_pair in arrow_pair.into_inner() {
+                        match line_pair.as_rule() {
+                            Rule::dotted_line => arrow.line = PlantUmlArrowLine::Dotted,
+                            Rule::arrow_direction => {
+                                arrow.direction = Some(match &line_pair.as_str()[..1] {
+                                    "u" => PlantUmlArrowDirection::Up,
+                                    "d" => PlantUmlArrowDirection::Down,
+                                    "l" => PlantUmlArrowDirection::Left,
+                                    _ => PlantUmlArrowDirection::Right,
+                                })
+                            }
+                            Rule::arrow_style => {
+                                // Colors and thickness are not drawn, only visibility matters
+                                arrow.hidden |= line_pair
+                                    .into_inner()
+                                    .any(|item| item.as_str().trim() == "hidden");
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        arrow
+    }
+}
+
+impl PlantUmlParser for PestPlantUmlParser {
+    fn parse(&self, input: &str) -> Result<PlantUmlDiagram, PlantUmlParserError> {
+        self.parse_all(input)
+            .map(|diagrams| diagrams.into_iter().next())
+            .map(|diagram| diagram.unwrap_or(PlantUmlDiagram::new(vec![])))
+    }
+
+    fn parse_all(&self, input: &str) -> Result<Vec<PlantUmlDiagram>, PlantUmlParserError> {
+        self.parse_with_pest_parser(input)?
+            .filter(|pair| pair.as_rule() == Rule::diagram)
+            .map(|pair| {
+                let diagram: PlantUmlDiagram =
+                    self.create_diagram_from_pair(input, pair.clone())?;
+                let declared: Vec<String> = declared_timing_names(&diagram.elements);
+
+                match self
+                    .create_diagnostics_for_statements(input, pair.into_inner(), &declared)
+                    .into_iter()
+                    .min_by_key(|diagnostic| diagnostic.span.start)
+                {
+                    Some(diagnostic) => Err(diagnostic),
+                    None => Ok(diagram),
+                }
+            })
+            .collect::<Result<Vec<PlantUmlDiagram>, Diagnostic>>()
+            .map_err(PlantUmlParserError::SyntaxError)
+    }
+
+    fn parse_recovering(&self, input: &str) -> (PlantUmlDiagram, Vec<Diagnostic>) {
+        let first_diagram: Option<pest::iterators::Pair<Rule>> = self
+            .parse_with_pest_parser(input)
+            .ok()
+            .and_then(|mut pairs| pairs.find(|pair| pair.as_rule() == Rule::diagram));
+
+        if let Some(pair) = first_diagram {
+            match self.create_diagram_from_pair(input, pair.clone()) {
+                // The diagram itself is well formed, only its data could not be read
+                Err(diagnostic) => return (PlantUmlDiagram::new(vec![]), vec![diagnostic]),
+                Ok(diagram) => {
+                    let declared: Vec<String> = declared_timing_names(&diagram.elements);
+                    let mut diagnostics: Vec<Diagnostic> =
+                        self.create_diagnostics_for_statements(input, pair.into_inner(), &declared);
+
+                    // Nothing is left out of a gantt chart by the recovering mode, the chart
+                    // is kept as read, without the statements found invalid
+                    if diagnostics.is_empty() || diagram.kind == PlantUmlDiagramKind::Gantt {
+                        diagnostics.sort_by_key(|diagnostic| diagnostic.span.start);
+                        return (diagram, diagnostics);
+                    }
+                }
+            }
+        }
+
+        let pairs: pest::iterators::Pairs<Rule> =
+            match self.parse_recovering_with_pest_parser(input) {
+                Ok(pairs) => pairs,
+                Err(diagnostic) => return (PlantUmlDiagram::new(vec![]), vec![diagnostic]),
+            };
+
+        let mut diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![]);
+        let mut diagnostics: Vec<Diagnostic> = Vec::new();
+        let mut has_start_tag: bool = false;
+        let mut has_end_tag: bool = false;
+
+        for pair in pairs.clone().flatten() {
+            match pair.as_rule() {
+                Rule::start_tag
+                | Rule::mindmap_start
+                | Rule::wbs_start
+                | Rule::json_start
+                | Rule::yaml_start
+                | Rule::gantt_start => {
+                    has_start_tag = true;
+                    diagram.kind = diagram_kind(&pair.as_rule());
+                    diagram.name = self.create_diagram_name_from_pair(pair);
+                }
+                Rule::end_tag
+                | Rule::mindmap_end
+                | Rule::wbs_end
+                | Rule::json_end
+                | Rule::yaml_end
+                | Rule::gantt_end => has_end_tag = true,
+                Rule::invalid_statement => {
+                    diagnostics.push(self.create_diagnostic_from_invalid_statement(input, pair));
+                }
+                Rule::invalid_line => diagnostics.push(self.create_diagnostic_from_invalid_line(
+                    input,
+                    pair,
+                    &diagram.kind,
+                )),
+                Rule::unmatched_brace => diagnostics.push(self.create_diagnostic_at(
+                    input,
+                    Span::new(pair.as_span().start(), pair.as_span().end()),
+                    "unmatched \"}\"".to_string(),
+                )),
+                _ => {}
+            }
+        }
+
+        if !has_start_tag {
+            diagnostics.insert(
+                0,
+                Diagnostic {
+                    severity: Severity::Warning,
+                    expected: vec![describe_rule(&Rule::start_tag)],
+                    ..self.create_diagnostic_at(
+                        input,
+                        Span::new(0, 0),
+                        "missing \"@startuml\"".to_string(),
+                    )
+                },
+            );
+        }
+
+        if !has_end_tag {
+            let end_tag: String = describe_rule(&end_tag_rule(&diagram.kind));
+
+            diagnostics.push(Diagnostic {
+                severity: Severity::Warning,
+                expected: vec![end_tag.clone()],
+                ..self.create_diagnostic_at(
+                    input,
+                    Span::new(input.len(), input.len()),
+                    format!("missing {}", end_tag),
+                )
+            });
+        }
+
+        for pair in pairs.clone() {
+            match pair.as_rule() {
+                Rule::data_text => match self.create_data_from_pair(input, pair, &diagram.kind) {
+                    Ok(data) => diagram.elements.push(data),
+                    Err(diagnostic) => diagnostics.push(diagnostic),
+                },
+                _ => diagram
+                    .elements
+                    .extend(self.create_elements_from_pair(pair)),
+            }
+        }
+        self.move_descriptions_to_class_members(&mut diagram.elements);
+
+        let declared: Vec<String> = declared_timing_names(&diagram.elements);
+        retain_declared_state_changes(&mut diagram.elements, &declared);
+        close_fragments(&mut diagram.elements);
+        diagnostics.extend(self.create_diagnostics_for_statements(input, pairs, &declared));
+        diagnostics.sort_by_key(|diagnostic| diagnostic.span.start);
+
+        (diagram, diagnostics)
+    }
+}
+
+#[derive(Debug, Default)]
+struct ParsedName {
+    text: String,
+    quoted: bool,
+    // Kind of element the name declares by itself, e.g., a component for "[My Component]"
+    shorthand: Option<PlantUmlElementKind>,
+}
+
+impl ParsedName {
+    /// PlantUML accepts both `"Display Name" as alias` and `alias as "Display Name"`,
+    /// a quoted alias next to an unquoted name means the latter form was used
+    fn with_alias(self, alias: Option<ParsedName>) -> (String, Option<String>) {
+        match alias {
+            Some(alias) if alias.quoted && !self.quoted => (alias.text, Some(self.text)),
+            Some(alias) => (self.text, Some(alias.text)),
+            None => (self.text, None),
+        }
+    }
+}
+
+// Names and aliases of the timing participants, wherever they are declared
+fn declared_timing_names(elements: &[PlantUmlElement]) -> Vec<String> {
+    elements
+        .iter()
+        .flat_map(|element| match element {
+            PlantUmlElement::Timing(PlantUmlTimingStep::Participant(participant)) => {
+                [Some(participant.name.clone()), participant.alias.clone()]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+            PlantUmlElement::Container(.., children) => declared_timing_names(children),
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+// Rules only activity diagrams use, which read "else" and "end" for their own blocks
+fn is_activity_rule(rule: &Rule) -> bool {
+    matches!(
+        rule,
+        Rule::action
+            | Rule::swimlane
+            | Rule::activity_start
+            | Rule::activity_stop
+            | Rule::if_start
+            | Rule::else_if
+            | Rule::if_end
+            | Rule::while_start
+            | Rule::while_end
+            | Rule::repeat_start
+            | Rule::repeat_end
+            | Rule::fork_start
+            | Rule::fork_again
+            | Rule::fork_end
+    )
+}
+
+// Fragments left open are closed at the end of the diagram, so they keep their border
+fn close_fragments(elements: &mut Vec<PlantUmlElement>) {
+    if elements
+        .iter()
+        .any(|element| matches!(element, PlantUmlElement::Activity(_)))
+    {
+        return;
+    }
+
+    let mut open: usize = 0;
 
-pub struct PestPlantUmlParser;
+    for element in elements.iter() {
+        match element {
+            PlantUmlElement::FragmentStart(..) => open += 1,
+            PlantUmlElement::FragmentEnd => open = open.saturating_sub(1),
+            _ => {}
+        }
+    }
+    elements.extend(std::iter::repeat_n(PlantUmlElement::FragmentEnd, open));
+}
 
-impl PestPlantUmlParser {
-    fn new() -> Self {
-        Self {}
+fn retain_declared_state_changes(elements: &mut Vec<PlantUmlElement>, declared: &[String]) {
+    elements.retain(|element| match element {
+        PlantUmlElement::Timing(PlantUmlTimingStep::StateChange(name, _)) => {
+            declared.contains(name)
+        }
+        _ => true,
+    });
+
+    for element in elements.iter_mut() {
+        if let PlantUmlElement::Container(.., children) = element {
+            retain_declared_state_changes(children, declared);
+        }
+    }
+}
+
+// Kind of the diagram a start tag opens
+fn diagram_kind(tag: &Rule) -> PlantUmlDiagramKind {
+    match tag {
+        Rule::mindmap_start => PlantUmlDiagramKind::MindMap,
+        Rule::wbs_start => PlantUmlDiagramKind::Wbs,
+        Rule::json_start => PlantUmlDiagramKind::Json,
+        Rule::yaml_start => PlantUmlDiagramKind::Yaml,
+        Rule::gantt_start => PlantUmlDiagramKind::Gantt,
+        _ => PlantUmlDiagramKind::Uml,
     }
+}
 
-    fn parse_with_pest_parser<'a>(
-        &self,
-        input: &'a str,
-    ) -> Result<pest::iterators::Pairs<'a, Rule>, PlantUmlParserError> {
-        PestParser::parse(Rule::diagram, input)
-            .inspect(|p| println!("{:?}", p))
-            .inspect_err(|e| println!("{:?}", e))
-            .map_err(|e| PlantUmlParserError::UnknownError(format!("{:?}", e)))
+fn end_tag_rule(kind: &PlantUmlDiagramKind) -> Rule {
+    match kind {
+        PlantUmlDiagramKind::Uml => Rule::end_tag,
+        PlantUmlDiagramKind::MindMap => Rule::mindmap_end,
+        PlantUmlDiagramKind::Wbs => Rule::wbs_end,
+        PlantUmlDiagramKind::Json => Rule::json_end,
+        PlantUmlDiagramKind::Yaml => Rule::yaml_end,
+        PlantUmlDiagramKind::Gantt => Rule::gantt_end,
     }
+}
 
-    fn create_component_from_pair(&self, pair: pest::iterators::Pair<Rule>) -> PlantUmlElement {
-        let mut name: String = "".to_string();
-        let mut alias: Option<String> = None;
+fn is_class(declaration: &PlantUmlDeclaration) -> bool {
+    matches!(
+        declaration.kind,
+        PlantUmlElementKind::Class
+            | PlantUmlElementKind::AbstractClass
+            | PlantUmlElementKind::Enum
+            | PlantUmlElementKind::Interface
+    )
+}
 
-        for component_pair in pair.into_inner() {
-            match component_pair.as_rule() {
-                Rule::identifier => {
-                    name = component_pair.as_str().to_string();
-                }
-                Rule::alias => {
-                    alias = Some(component_pair.into_inner().as_str().to_string());
-                }
-                _ => {}
+// Names and aliases of the classes, wherever they are declared
+fn declared_class_names(elements: &[PlantUmlElement]) -> Vec<String> {
+    elements
+        .iter()
+        .flat_map(|element| match element {
+            PlantUmlElement::Element(declaration) if is_class(declaration) => {
+                [Some(declaration.name.clone()), declaration.alias.clone()]
+                    .into_iter()
+                    .flatten()
+                    .collect()
             }
+            PlantUmlElement::Container(.., children) => declared_class_names(children),
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+// Removes the descriptions of the given classes, returning the class names and texts
+fn take_class_descriptions(
+    elements: &mut Vec<PlantUmlElement>,
+    classes: &[String],
+) -> Vec<(String, String)> {
+    let mut taken: Vec<(String, String)> = Vec::new();
+
+    elements.retain(|element| match element {
+        PlantUmlElement::StateDescription(name, text) if classes.contains(name) => {
+            taken.push((name.clone(), text.clone()));
+            false
         }
+        _ => true,
+    });
 
-        PlantUmlElement::Component(name, alias)
+    for element in elements.iter_mut() {
+        if let PlantUmlElement::Container(.., children) = element {
+            taken.extend(take_class_descriptions(children, classes));
+        }
     }
+
+    taken
 }
 
-impl PlantUmlParser for PestPlantUmlParser {
-    fn parse(&self, input: &str) -> Result<PlantUmlDiagram, PlantUmlParserError> {
-        let mut diagram: PlantUmlDiagram = PlantUmlDiagram::new(vec![]);
+// Gives each member to the first class declared with its name or alias
+fn add_class_members(
+    elements: &mut [PlantUmlElement],
+    members: &mut Vec<(String, PlantUmlMember)>,
+) {
+    for element in elements.iter_mut() {
+        match element {
+            PlantUmlElement::Element(declaration) if is_class(declaration) => {
+                let (own, others): (Vec<_>, Vec<_>) =
+                    std::mem::take(members).into_iter().partition(|(name, _)| {
+                        *name == declaration.name || Some(name) == declaration.alias.as_ref()
+                    });
 
-        for pair in self.parse_with_pest_parser(input)? {
-            if pair.as_rule() == Rule::component_declaration {
-                diagram.elements.push(self.create_component_from_pair(pair));
+                declaration
+                    .members
+                    .extend(own.into_iter().map(|(_, member)| member));
+                *members = others;
             }
+            PlantUmlElement::Container(.., children) => add_class_members(children, members),
+            _ => {}
         }
+    }
+}
 
-        Ok(diagram)
+/// Human readable name of what the grammar expected, as shown in diagnostics
+fn describe_rule(rule: &Rule) -> String {
+    match rule {
+        // Mind maps, work breakdown structures and data are left out, they are far less common
+        Rule::start_tag | Rule::diagram => "\"@startuml\"",
+        Rule::end_tag => "\"@enduml\"",
+        Rule::mindmap_start => "\"@startmindmap\"",
+        Rule::mindmap_end => "\"@endmindmap\"",
+        Rule::wbs_start => "\"@startwbs\"",
+        Rule::wbs_end => "\"@endwbs\"",
+        Rule::json_start => "\"@startjson\"",
+        Rule::json_end => "\"@endjson\"",
+        Rule::yaml_start => "\"@startyaml\"",
+        Rule::yaml_end => "\"@endyaml\"",
+        Rule::gantt_start => "\"@startgantt\"",
+        Rule::gantt_end => "\"@endgantt\"",
+        Rule::project_start | Rule::closed_days | Rule::task_statement => "a statement",
+        Rule::task_name | Rule::task_anchor => "a task",
+        Rule::gantt_date => "a date",
+        Rule::gantt_year => "a year",
+        Rule::gantt_month => "a month",
+        Rule::gantt_day => "a day",
+        Rule::weekday => "a weekday",
+        Rule::task_text => "a task",
+        Rule::task_duration | Rule::task_start | Rule::milestone => "a constraint",
+        Rule::task_days => "a duration",
+        Rule::day_unit | Rule::week_unit => "\"days\" or \"weeks\"",
+        Rule::anchor_start | Rule::anchor_end => "\"start\" or \"end\"",
+        Rule::timing_time => "a time",
+        Rule::timing_number
+        | Rule::clock_period
+        | Rule::clock_pulse
+        | Rule::clock_offset
+        | Rule::task_count => "a number",
+        Rule::timing_state | Rule::timing_state_text => "a state",
+        Rule::quoted_content
+        | Rule::bracketed_content
+        | Rule::parenthesized_content
+        | Rule::actor_content
+        | Rule::diagram_id => "a name",
+        Rule::diagram_title => "a title",
+        Rule::COMMENT | Rule::block_comment | Rule::line_comment => "a comment",
+        Rule::highlight => "a highlight",
+        Rule::data_text => "data",
+        Rule::tree_node | Rule::tree_marker => "a node",
+        Rule::identifier
+        | Rule::quoted_name
+        | Rule::bracketed_name
+        | Rule::parenthesized_name
+        | Rule::actor_name
+        | Rule::terminal_state => "a name",
+        // Every kind of statement could start here, naming them all would not help
+        Rule::element_keyword
+        | Rule::element_declaration
+        | Rule::bracketed_declaration
+        | Rule::interface_shorthand
+        | Rule::container_keyword
+        | Rule::container
+        | Rule::recovering_container
+        | Rule::relationship
+        | Rule::directive
+        | Rule::directive_keyword
+        | Rule::note
+        | Rule::layout_direction
+        | Rule::left_to_right
+        | Rule::top_to_bottom
+        | Rule::activation
+        | Rule::activation_keyword
+        | Rule::fragment_start
+        | Rule::fragment_keyword
+        | Rule::fragment_else
+        | Rule::fragment_end
+        | Rule::divider
+        | Rule::delay
+        | Rule::class_declaration
+        | Rule::object_declaration
+        | Rule::object_keyword
+        | Rule::state_declaration
+        | Rule::state_keyword
+        | Rule::described_state
+        | Rule::action
+        | Rule::swimlane
+        | Rule::activity_start
+        | Rule::activity_stop
+        | Rule::if_start
+        | Rule::else_if
+        | Rule::if_end
+        | Rule::while_start
+        | Rule::while_end
+        | Rule::repeat_start
+        | Rule::repeat_end
+        | Rule::fork_start
+        | Rule::fork_again
+        | Rule::fork_end
+        | Rule::timing_keyword
+        | Rule::time_marker
+        | Rule::state_change
+        | Rule::timing_constraint
+        | Rule::timing_participant
+        | Rule::then_keyword
+        | Rule::class_keyword
+        | Rule::interface_keyword
+        | Rule::invalid_statement
+        | Rule::invalid_line
+        | Rule::unmatched_brace => "a statement",
+        Rule::arrow
+        | Rule::arrow_line
+        | Rule::arrow_head_left
+        | Rule::arrow_head_right
+        | Rule::solid_line
+        | Rule::dotted_line
+        | Rule::arrow_direction
+        | Rule::arrow_style
+        | Rule::arrow_style_item => "an arrow",
+        Rule::alias => "an alias",
+        Rule::stereotype | Rule::stereotype_name => "a stereotype",
+        Rule::relationship_label | Rule::label_text => "a label",
+        Rule::note_side => "a side",
+        Rule::generics | Rule::generic_content => "type parameters",
+        Rule::class_body
+        | Rule::class_member
+        | Rule::member_modifier
+        | Rule::member_visibility
+        | Rule::member_text => "a member",
+        Rule::object_body | Rule::object_field | Rule::field_name | Rule::field_value => "a field",
+        Rule::multiplicity | Rule::multiplicity_text => "a multiplicity",
+        Rule::condition => "a condition",
+        Rule::branch_label | Rule::exit_label => "a label",
+        Rule::directive_text
+        | Rule::note_text
+        | Rule::fragment_label
+        | Rule::state_description
+        | Rule::description_text
+        | Rule::action_text
+        | Rule::swimlane_name
+        | Rule::condition_text
+        | Rule::divider_text
+        | Rule::delay_text
+        | Rule::tree_text
+        | Rule::tree_multiline_text
+        | Rule::block_comment_text
+        | Rule::line_comment_text
+        | Rule::outside_text
+        | Rule::trailing_content => "a text",
+        Rule::EOI => "end of input",
+        // Silent rules are not reported by pest, every other rule is named above
+        _ => "a statement",
+    }
+    .to_string()
+}
+
+fn fragment_label(pair: pest::iterators::Pair<Rule>) -> Option<String> {
+    pair.into_inner()
+        .find(|p| p.as_rule() == Rule::fragment_label)
+        .map(|p| p.as_str().trim().to_string())
+        .filter(|label| !label.is_empty())
+}
+
+fn arrow_head(value: &str) -> PlantUmlArrowHead {
+    match value {
+        "<|" | "|>" => PlantUmlArrowHead::Extension,
+        "*" => PlantUmlArrowHead::Composition,
+        "o" => PlantUmlArrowHead::Aggregation,
+        "<" | ">" => PlantUmlArrowHead::Arrow,
+        "(" | ")" => PlantUmlArrowHead::Socket,
+        _ => PlantUmlArrowHead::Open,
+    }
+}
+
+// e.g., "100" or "+50"
+fn is_unreadable_number(pair: &pest::iterators::Pair<Rule>) -> bool {
+    matches!(pair.as_rule(), Rule::timing_time | Rule::timing_number)
+        && pair
+            .as_str()
+            .trim_start_matches('+')
+            .parse::<u32>()
+            .is_err()
+}
+
+// None once past the last time
+fn resolve_time(from: u32, time: &PlantUmlTime) -> Option<u32> {
+    match time {
+        PlantUmlTime::Absolute(time) => Some(*time),
+        PlantUmlTime::Relative(delay) => from.checked_add(*delay),
+    }
+}
+
+fn timing_time(value: &str) -> PlantUmlTime {
+    match value.strip_prefix('+') {
+        Some(delay) => PlantUmlTime::Relative(delay.parse().unwrap_or(0)),
+        None => PlantUmlTime::Absolute(value.parse().unwrap_or(0)),
+    }
+}
+
+// e.g., "2026-01-05"
+fn gantt_date(pair: pest::iterators::Pair<Rule>) -> PlantUmlDate {
+    let mut parts = pair.into_inner().map(|p| p.as_str());
+
+    PlantUmlDate::new(
+        parts.next().and_then(|p| p.parse().ok()).unwrap_or(0),
+        parts.next().and_then(|p| p.parse().ok()).unwrap_or(1),
+        parts.next().and_then(|p| p.parse().ok()).unwrap_or(1),
+    )
+}
+
+fn gantt_weekday(value: &str) -> PlantUmlWeekday {
+    match value.to_lowercase().as_str() {
+        "monday" => PlantUmlWeekday::Monday,
+        "tuesday" => PlantUmlWeekday::Tuesday,
+        "wednesday" => PlantUmlWeekday::Wednesday,
+        "thursday" => PlantUmlWeekday::Thursday,
+        "friday" => PlantUmlWeekday::Friday,
+        "saturday" => PlantUmlWeekday::Saturday,
+        _ => PlantUmlWeekday::Sunday,
+    }
+}
+
+// e.g., "[Design]"
+fn task_name(pair: pest::iterators::Pair<Rule>) -> String {
+    pair.into_inner().as_str().trim().to_string()
+}
+
+// e.g., "5 days" or "2 weeks", None when more than u32 can hold
+fn task_days(pair: pest::iterators::Pair<Rule>) -> Option<u32> {
+    let mut count: Option<u32> = Some(0);
+    let mut unit: u32 = 1;
+
+    for days_pair in pair.into_inner() {
+        match days_pair.as_rule() {
+            Rule::task_count => count = days_pair.as_str().parse().ok(),
+            Rule::week_unit => unit = 7,
+            _ => {}
+        }
+    }
+
+    count?.checked_mul(unit)
+}
+
+// Why a duration or a date of a gantt chart cannot be used, if it cannot
+fn gantt_value_error(pair: &pest::iterators::Pair<Rule>) -> Option<String> {
+    match pair.as_rule() {
+        Rule::task_days => task_days(pair.clone())
+            .is_none_or(|days| days > MAX_DAYS)
+            .then(|| {
+                format!(
+                    "duration too long, \"{}\" is more than {} days",
+                    pair.as_str(),
+                    MAX_DAYS
+                )
+            }),
+        Rule::gantt_date => {
+            let date: PlantUmlDate = gantt_date(pair.clone());
+
+            (date.month == 0 || date.month > 12 || date.day == 0 || date.day > days_in_month(&date))
+                .then(|| {
+                    format!(
+                        "invalid date, \"{}\" is not a day of the calendar",
+                        pair.as_str()
+                    )
+                })
+        }
+        _ => None,
+    }
+}
+
+fn days_in_month(date: &PlantUmlDate) -> u32 {
+    let leap: bool = date.year % 4 == 0 && (date.year % 100 != 0 || date.year % 400 == 0);
+
+    match date.month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The word starting at the failing position, so it can be underlined as a whole
+fn unexpected_token_at(input: &str, position: usize) -> &str {
+    let rest: &str = &input[position..];
+    let end: usize = rest
+        .char_indices()
+        .skip(1)
+        .find(|(_, ch)| ch.is_whitespace())
+        .map(|(index, _)| index)
+        .unwrap_or(rest.len());
+
+    rest[..end].trim_end()
+}
+
+/// Resolves the `\"`, `\n` and `\\` escape sequences allowed inside quoted and bracketed names
+fn unescape(value: &str) -> String {
+    let mut result: String = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(current) = chars.next() {
+        if current != '\\' {
+            result.push(current);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some(escaped) => result.push(escaped),
+            None => result.push(current),
+        }
     }
+
+    result
 }
 
 #[derive(Parser)]
@@ -67,6 +2124,8 @@ struct PestParser;
 mod tests {
     use pretty_assertions::assert_eq;
 
+    use crate::infra::models::plant_uml_diagram::PlantUmlDataValue;
+
     use super::*;
 
     macro_rules! parse_tests {
@@ -89,23 +2148,1241 @@ mod tests {
     }
 
     parse_tests! {
-        empty_input: ("", Err(PlantUmlParserError::UnknownError("Error { variant: ParsingError { positives: [], negatives: [] }, location: Pos(0), line_col: Pos((1, 1)), path: None, line: \"\", continued_line: None, parse_attempts: None }".to_owned()))),
+        empty_input: ("", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            expected: vec!["\"@startuml\"".to_owned()],
+            ..Diagnostic::new("expected \"@startuml\", found end of input".to_owned(), Span::new(0, 0), 1, 1)
+        }))),
+        missing_end_tag: ("@startuml\ncomponent A\n", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            expected: vec![
+                "a statement".to_owned(),
+                "an alias".to_owned(),
+                "a stereotype".to_owned(),
+                "\"@enduml\"".to_owned(),
+            ],
+            ..Diagnostic::new(
+                "expected a statement, an alias, a stereotype or \"@enduml\", found end of input".to_owned(),
+                Span::new(22, 22),
+                3,
+                1,
+            )
+        }))),
+        unexpected_token: ("@startuml\ncomponent A\na -> -> b\n@enduml", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            expected: vec!["a name".to_owned(), "a multiplicity".to_owned()],
+            source_line: "a -> -> b".to_owned(),
+            ..Diagnostic::new("expected a name or a multiplicity, found \"->\"".to_owned(), Span::new(27, 29), 3, 6)
+        }))),
         empty_diagram: ("@startuml@enduml", Ok(PlantUmlDiagram::new(vec![]))),
         empty_diagram_with_line_breaks: ("@startuml\n\n\n\n\n@enduml", Ok(PlantUmlDiagram::new(vec![]))),
-        one_component: ("@startuml\ncomponent MyComponent\n@enduml", Ok(PlantUmlDiagram::new(vec![PlantUmlElement::Component("MyComponent".to_string(), None)]))),
+        one_component: ("@startuml\ncomponent MyComponent\n@enduml", Ok(PlantUmlDiagram::new(vec![PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "MyComponent".to_string(), None))]))),
         multiple_components: (
             "@startuml\ncomponent MyComponent\ncomponent MyOtherComponent\n\n\n\n\ncomponent YetAnotherComponent\n@enduml",
             Ok(PlantUmlDiagram::new(vec![
-                PlantUmlElement::Component("MyComponent".to_string(), None),
-                PlantUmlElement::Component("MyOtherComponent".to_string(), None),
-                PlantUmlElement::Component("YetAnotherComponent".to_string(), None)
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "MyComponent".to_string(), None)),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "MyOtherComponent".to_string(), None)),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "YetAnotherComponent".to_string(), None))
             ]))
         ),
         component_with_alias: (
             "@startuml\ncomponent MyComponent as some_alias\n@enduml",
             Ok(PlantUmlDiagram::new(vec![
-                PlantUmlElement::Component("MyComponent".to_string(), Some("some_alias".to_string()))
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "MyComponent".to_string(), Some("some_alias".to_string())))
+            ]))
+        ),
+        solid_arrow_relationship: (
+            "@startuml\na --> b\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "a".to_string(),
+                    "b".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                    None,
+                ))
+            ]))
+        ),
+        solid_line_relationship: (
+            "@startuml\na -- b\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "a".to_string(),
+                    "b".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, None),
+                    None,
+                ))
+            ]))
+        ),
+        dotted_arrow_relationship: (
+            "@startuml\na ..> b\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "a".to_string(),
+                    "b".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Dotted, Some(PlantUmlArrowHead::Arrow)),
+                    None,
+                ))
+            ]))
+        ),
+        reversed_arrow_relationship: (
+            "@startuml\na <-- b\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "a".to_string(),
+                    "b".to_string(),
+                    PlantUmlArrow::new(Some(PlantUmlArrowHead::Arrow), PlantUmlArrowLine::Solid, None),
+                    None,
+                ))
+            ]))
+        ),
+        labeled_relationship_without_spaces: (
+            "@startuml\na-->b:some label  \n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "a".to_string(),
+                    "b".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                    Some("some label".to_string()),
+                ))
+            ]))
+        ),
+        components_with_relationship: (
+            "@startuml\ncomponent A as a\ncomponent B\na --> B : uses\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "A".to_string(), Some("a".to_string()))),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "B".to_string(), None)),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "a".to_string(),
+                    "B".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                    Some("uses".to_string()),
+                ))
+            ]))
+        ),
+        nested_containers: (
+            "@startuml\ncomponent uml {\n    package application as uml_application{\n        component repository\n    }\n}\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Container(
+                    PlantUmlContainerKind::Component,
+                    "uml".to_string(),
+                    None,
+                    vec![PlantUmlElement::Container(
+                        PlantUmlContainerKind::Package,
+                        "application".to_string(),
+                        Some("uml_application".to_string()),
+                        vec![PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "repository".to_string(), None))],
+                    )],
+                )
+            ]))
+        ),
+        container_kinds: (
+            "@startuml\nnode n {}\nfolder f {}\nframe fr {}\ncloud c {}\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Container(PlantUmlContainerKind::Node, "n".to_string(), None, vec![]),
+                PlantUmlElement::Container(PlantUmlContainerKind::Folder, "f".to_string(), None, vec![]),
+                PlantUmlElement::Container(PlantUmlContainerKind::Frame, "fr".to_string(), None, vec![]),
+                PlantUmlElement::Container(PlantUmlContainerKind::Cloud, "c".to_string(), None, vec![]),
+            ]))
+        ),
+        relationship_inside_container: (
+            "@startuml\npackage p {\n    a --> b\n}\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Container(
+                    PlantUmlContainerKind::Package,
+                    "p".to_string(),
+                    None,
+                    vec![PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                        "a".to_string(),
+                        "b".to_string(),
+                        PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                        None,
+                    ))],
+                )
+            ]))
+        ),
+        element_kinds: (
+            "@startuml\nrectangle r\ncircle c\ninterface i\nactor a\ndatabase d\nqueue q\nartifact ar\nstorage s\nusecase u as use_case\nboundary b\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Rectangle, "r".to_string(), None)),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Circle, "c".to_string(), None)),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Interface, "i".to_string(), None)),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Actor, "a".to_string(), None)),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Database, "d".to_string(), None)),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Queue, "q".to_string(), None)),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Artifact, "ar".to_string(), None)),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Storage, "s".to_string(), None)),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::UseCase, "u".to_string(), Some("use_case".to_string()))),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Boundary, "b".to_string(), None)),
+            ]))
+        ),
+        stereotypes_on_declarations: (
+            "@startuml\ncomponent uml <<lib>>\nrectangle repository <<data>> as repo <<interface>>\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration {
+                    stereotypes: vec!["lib".to_string()],
+                    ..PlantUmlDeclaration::new(PlantUmlElementKind::Component, "uml".to_string(), None)
+                }),
+                PlantUmlElement::Element(PlantUmlDeclaration {
+                    stereotypes: vec!["data".to_string(), "interface".to_string()],
+                    ..PlantUmlDeclaration::new(PlantUmlElementKind::Rectangle, "repository".to_string(), Some("repo".to_string()))
+                }),
+            ]))
+        ),
+        identifier_starting_with_as_after_declaration: (
+            "@startuml\ncomponent a\nassets --> a\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "a".to_string(), None)),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "assets".to_string(),
+                    "a".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                    None,
+                )),
+            ]))
+        ),
+        stereotypes_in_relationship_labels: (
+            "@startuml\na ..> b : <<runtime dependency>>\na --> b : <<uses>> <<reads>> some data\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Relationship(PlantUmlRelationship {
+                    stereotypes: vec!["runtime dependency".to_string()],
+                    ..PlantUmlRelationship::new(
+                        "a".to_string(),
+                        "b".to_string(),
+                        PlantUmlArrow::new(None, PlantUmlArrowLine::Dotted, Some(PlantUmlArrowHead::Arrow)),
+                        None,
+                    )
+                }),
+                PlantUmlElement::Relationship(PlantUmlRelationship {
+                    stereotypes: vec!["uses".to_string(), "reads".to_string()],
+                    ..PlantUmlRelationship::new(
+                        "a".to_string(),
+                        "b".to_string(),
+                        PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                        Some("some data".to_string()),
+                    )
+                }),
+            ]))
+        ),
+        quoted_name_with_alias: (
+            "@startuml\nrectangle \"Use Case Interactor\" as use_case_interactor\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Rectangle, "Use Case Interactor".to_string(), Some("use_case_interactor".to_string()))),
+            ]))
+        ),
+        alias_before_quoted_name: (
+            "@startuml\ncomponent api as \"Public API\"\npackage core as \"Core Logic\" {\n}\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "Public API".to_string(), Some("api".to_string()))),
+                PlantUmlElement::Container(PlantUmlContainerKind::Package, "Core Logic".to_string(), Some("core".to_string()), vec![]),
+            ]))
+        ),
+        escape_sequences_in_quoted_names: (
+            "@startuml\nactor \"The \\\"Admin\\\"\\nUser\" as admin\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Actor, "The \"Admin\"\nUser".to_string(), Some("admin".to_string()))),
+            ]))
+        ),
+        bracketed_declarations: (
+            "@startuml\n[My Component]\n[Other One] as other <<lib>>\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "My Component".to_string(), None)),
+                PlantUmlElement::Element(PlantUmlDeclaration {
+                    stereotypes: vec!["lib".to_string()],
+                    ..PlantUmlDeclaration::new(PlantUmlElementKind::Component, "Other One".to_string(), Some("other".to_string()))
+                }),
+            ]))
+        ),
+        bracketed_relationship_endpoints: (
+            "@startuml\n[Web App] --> \"Public API\" : calls\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration {
+                    implicit: true,
+                    ..PlantUmlDeclaration::new(PlantUmlElementKind::Component, "Web App".to_string(), None)
+                }),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "Web App".to_string(),
+                    "Public API".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                    Some("calls".to_string()),
+                )),
+            ]))
+        ),
+        use_case_shorthands: (
+            "@startuml\n:Customer: as c\nrectangle Shop {\n(Checkout) as UC1\nusecase (Pay)\n}\nc --> UC1\n(Checkout) .> (Pay) : <<include>>\n:Step 1: read;\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Actor, "Customer".to_string(), Some("c".to_string()))),
+                PlantUmlElement::Container(PlantUmlContainerKind::Rectangle, "Shop".to_string(), None, vec![
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::UseCase, "Checkout".to_string(), Some("UC1".to_string()))),
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::UseCase, "Pay".to_string(), None)),
+                ]),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "c".to_string(),
+                    "UC1".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                    None,
+                )),
+                PlantUmlElement::Element(PlantUmlDeclaration {
+                    implicit: true,
+                    ..PlantUmlDeclaration::new(PlantUmlElementKind::UseCase, "Checkout".to_string(), None)
+                }),
+                PlantUmlElement::Element(PlantUmlDeclaration {
+                    implicit: true,
+                    ..PlantUmlDeclaration::new(PlantUmlElementKind::UseCase, "Pay".to_string(), None)
+                }),
+                PlantUmlElement::Relationship(PlantUmlRelationship {
+                    stereotypes: vec!["include".to_string()],
+                    ..PlantUmlRelationship::new(
+                        "Checkout".to_string(),
+                        "Pay".to_string(),
+                        PlantUmlArrow {
+                            short: true,
+                            ..PlantUmlArrow::new(None, PlantUmlArrowLine::Dotted, Some(PlantUmlArrowHead::Arrow))
+                        },
+                        None,
+                    )
+                }),
+                PlantUmlElement::Activity(PlantUmlActivityStep::Action("Step 1: read".to_string())),
+            ]))
+        ),
+        line_and_block_comments: (
+            "@startuml\n' The entry point\ncomponent A\n\n/' Spans\n   several lines '/\n\ncomponent B\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Comment(PlantUmlComment::Line("The entry point".to_string())),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "A".to_string(), None)),
+                PlantUmlElement::Comment(PlantUmlComment::Block("Spans\n   several lines".to_string())),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "B".to_string(), None)),
+            ]))
+        ),
+        trailing_comments: (
+            "@startuml\ncomponent A ' first\na --> b : it's a label\npackage p { ' inside\n}\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "A".to_string(), None)),
+                PlantUmlElement::Comment(PlantUmlComment::Line("first".to_string())),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "a".to_string(),
+                    "b".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                    Some("it's a label".to_string()),
+                )),
+                PlantUmlElement::Container(PlantUmlContainerKind::Package, "p".to_string(), None, vec![
+                    PlantUmlElement::Comment(PlantUmlComment::Line("inside".to_string())),
+                ]),
+            ]))
+        ),
+        comments_outside_diagram_are_ignored: (
+            "' Header\n@startuml\n/' nothing here '/\n@enduml\n' Footer",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Comment(PlantUmlComment::Block("nothing here".to_string())),
+            ]))
+        ),
+        diagram_named_with_id: (
+            "@startuml(id=overview)\ncomponent A\n@enduml",
+            Ok(PlantUmlDiagram {
+                name: Some("overview".to_string()),
+                ..PlantUmlDiagram::new(vec![
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "A".to_string(), None)),
+                ])
+            })
+        ),
+        diagram_named_after_start_tag: (
+            "@startuml Main flow \n@enduml",
+            Ok(PlantUmlDiagram {
+                name: Some("Main flow".to_string()),
+                ..PlantUmlDiagram::new(vec![])
+            })
+        ),
+        first_of_several_diagrams_with_text_around: (
+            "Some notes\n\n@startuml\ncomponent A\n@enduml\n\nMore notes\n@startuml second\ncomponent B\n@enduml\n",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "A".to_string(), None)),
+            ]))
+        ),
+        mind_map_nodes: (
+            "@startmindmap ideas\n* Root\n** Child\n***:First line\n   second line;\n-- Left\n**[#Orange]_ Boxless\n@endmindmap",
+            Ok(PlantUmlDiagram {
+                name: Some("ideas".to_string()),
+                kind: PlantUmlDiagramKind::MindMap,
+                ..PlantUmlDiagram::new(vec![
+                    PlantUmlElement::TreeNode(PlantUmlTreeNode::new(1, None, "Root".to_string())),
+                    PlantUmlElement::TreeNode(PlantUmlTreeNode::new(2, None, "Child".to_string())),
+                    PlantUmlElement::TreeNode(PlantUmlTreeNode::new(3, None, "First line\nsecond line".to_string())),
+                    PlantUmlElement::TreeNode(PlantUmlTreeNode::new(2, Some(PlantUmlTreeSide::Left), "Left".to_string())),
+                    PlantUmlElement::TreeNode(PlantUmlTreeNode::new(2, None, "Boxless".to_string())),
+                ])
+            })
+        ),
+        wbs_nodes: (
+            "@startwbs\n* Release\n' Planned first\n++ Plan\n@endwbs",
+            Ok(PlantUmlDiagram {
+                kind: PlantUmlDiagramKind::Wbs,
+                ..PlantUmlDiagram::new(vec![
+                    PlantUmlElement::TreeNode(PlantUmlTreeNode::new(1, None, "Release".to_string())),
+                    PlantUmlElement::Comment(PlantUmlComment::Line("Planned first".to_string())),
+                    PlantUmlElement::TreeNode(PlantUmlTreeNode::new(2, Some(PlantUmlTreeSide::Right), "Plan".to_string())),
+                ])
+            })
+        ),
+        json_data: (
+            "@startjson\n#highlight \"address\" / \"city\"\n{\"name\": \"Ada\", \"address\": {\"city\": \"Paris\"}, \"admin\": false}\n@endjson",
+            Ok(PlantUmlDiagram {
+                kind: PlantUmlDiagramKind::Json,
+                ..PlantUmlDiagram::new(vec![
+                    PlantUmlElement::Highlight(vec!["address".to_string(), "city".to_string()]),
+                    PlantUmlElement::Data(PlantUmlDataValue::Object(vec![
+                        ("name".to_string(), PlantUmlDataValue::Text("Ada".to_string())),
+                        ("address".to_string(), PlantUmlDataValue::Object(vec![("city".to_string(), PlantUmlDataValue::Text("Paris".to_string()))])),
+                        ("admin".to_string(), PlantUmlDataValue::Boolean(false)),
+                    ])),
+                ])
+            })
+        ),
+        indented_yaml_data: (
+            "@startyaml payload\n  name: Ada\n  langs:\n    - en\n    - 42\n@endyaml",
+            Ok(PlantUmlDiagram {
+                name: Some("payload".to_string()),
+                kind: PlantUmlDiagramKind::Yaml,
+                ..PlantUmlDiagram::new(vec![
+                    PlantUmlElement::Data(PlantUmlDataValue::Object(vec![
+                        ("name".to_string(), PlantUmlDataValue::Text("Ada".to_string())),
+                        ("langs".to_string(), PlantUmlDataValue::Array(vec![PlantUmlDataValue::Text("en".to_string()), PlantUmlDataValue::Number("42".to_string())])),
+                    ])),
+                ])
+            })
+        ),
+        invalid_data: ("@startjson\n{\"a\": [1,}\n@endjson", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            source_line: "{\"a\": [1,}".to_string(),
+            ..Diagnostic::new("expected a value".to_string(), Span::new(20, 20), 2, 10)
+        }))),
+        json_with_a_trailing_comma: ("@startjson\n{\"a\": 1,}\n@endjson", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            source_line: "{\"a\": 1,}".to_string(),
+            ..Diagnostic::new("expected a key".to_string(), Span::new(19, 19), 2, 9)
+        }))),
+        timing_participants_and_changes: (
+            "@startuml\nrobust \"Web Browser\" as WB\nclock clk with period 50 pulse 10 offset 5\n@0\nWB is Idle\n@+100\nWB is \"Busy now\"\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Timing(PlantUmlTimingStep::Participant(PlantUmlTimingParticipant::new(PlantUmlTimingKind::Robust, "Web Browser".to_string(), Some("WB".to_string())))),
+                PlantUmlElement::Timing(PlantUmlTimingStep::Participant(PlantUmlTimingParticipant {
+                    clock: Some(PlantUmlClock { period: 50, pulse: Some(10), offset: Some(5) }),
+                    ..PlantUmlTimingParticipant::new(PlantUmlTimingKind::Clock, "clk".to_string(), None)
+                })),
+                PlantUmlElement::Timing(PlantUmlTimingStep::At(PlantUmlTime::Absolute(0))),
+                PlantUmlElement::Timing(PlantUmlTimingStep::StateChange("WB".to_string(), "Idle".to_string())),
+                PlantUmlElement::Timing(PlantUmlTimingStep::At(PlantUmlTime::Relative(100))),
+                PlantUmlElement::Timing(PlantUmlTimingStep::StateChange("WB".to_string(), "Busy now".to_string())),
+            ]))
+        ),
+        timing_by_participant: (
+            "@startuml\nconcise WU\n@WU\n0 is Idle\n+50 is Waiting\nWU@0 <-> @50 : {50 ms}\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Timing(PlantUmlTimingStep::Participant(PlantUmlTimingParticipant::new(PlantUmlTimingKind::Concise, "WU".to_string(), None))),
+                PlantUmlElement::Timing(PlantUmlTimingStep::AtParticipant("WU".to_string())),
+                PlantUmlElement::Timing(PlantUmlTimingStep::TimedStateChange(PlantUmlTime::Absolute(0), "Idle".to_string())),
+                PlantUmlElement::Timing(PlantUmlTimingStep::TimedStateChange(PlantUmlTime::Relative(50), "Waiting".to_string())),
+                PlantUmlElement::Timing(PlantUmlTimingStep::Constraint("WU".to_string(), PlantUmlTime::Absolute(0), PlantUmlTime::Absolute(50), Some("50 ms".to_string()))),
+            ]))
+        ),
+        timing_state_change_of_undeclared_participant: ("@startuml\nrobust WB\nDNS is Idle\n@enduml", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            source_line: "DNS is Idle".to_owned(),
+            ..Diagnostic::new("unrecognized statement, \"DNS\" is not a timing participant".to_owned(), Span::new(20, 31), 3, 1)
+        }))),
+        state_change_outside_timing_diagrams: ("@startuml\nclass Foo\nFoo is bar\n@enduml", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            expected: vec!["a multiplicity".to_owned(), "an arrow".to_owned(), "a text".to_owned()],
+            source_line: "Foo is bar".to_owned(),
+            ..Diagnostic::new("expected a multiplicity, an arrow or a text, found \"is\"".to_owned(), Span::new(24, 26), 3, 5)
+        }))),
+        timing_time_past_the_last_one: ("@startuml\nrobust WB\n@4000000000\n@+4000000000\n@enduml", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            source_line: "@+4000000000".to_owned(),
+            ..Diagnostic::new("time too large, it ends after 4294967295".to_owned(), Span::new(32, 44), 4, 1)
+        }))),
+        timing_number_too_large: ("@startuml\nclock clk with period 99999999999\n@enduml", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            source_line: "clock clk with period 99999999999".to_owned(),
+            ..Diagnostic::new("number too large, \"99999999999\" is more than 4294967295".to_owned(), Span::new(32, 43), 2, 23)
+        }))),
+        gantt_statements: (
+            "@startgantt\nProject starts 2026-01-05\nsaturday are closed\n2026-01-07 to 2026-01-08 is closed\n[Design] as [D] lasts 2 weeks\nthen [Build] starts 2 days after [D]'s start and lasts 3 days\n[Release] happens at [Build]'s end\n@endgantt",
+            Ok(PlantUmlDiagram {
+                kind: PlantUmlDiagramKind::Gantt,
+                ..PlantUmlDiagram::new(vec![
+                    PlantUmlElement::Gantt(PlantUmlGanttStatement::ProjectStart(PlantUmlDate::new(2026, 1, 5))),
+                    PlantUmlElement::Gantt(PlantUmlGanttStatement::ClosedWeekday(PlantUmlWeekday::Saturday)),
+                    PlantUmlElement::Gantt(PlantUmlGanttStatement::ClosedDates(PlantUmlDate::new(2026, 1, 7), PlantUmlDate::new(2026, 1, 8))),
+                    PlantUmlElement::Gantt(PlantUmlGanttStatement::Task(PlantUmlTask {
+                        constraints: vec![PlantUmlTaskConstraint::Lasts(14)],
+                        ..PlantUmlTask::new("Design".to_string(), Some("D".to_string()))
+                    })),
+                    PlantUmlElement::Gantt(PlantUmlGanttStatement::Task(PlantUmlTask {
+                        then: true,
+                        constraints: vec![
+                            PlantUmlTaskConstraint::Starts(PlantUmlTaskStart::After("D".to_string(), PlantUmlTaskAnchor::Start, 2)),
+                            PlantUmlTaskConstraint::Lasts(3),
+                        ],
+                        ..PlantUmlTask::new("Build".to_string(), None)
+                    })),
+                    PlantUmlElement::Gantt(PlantUmlGanttStatement::Task(PlantUmlTask {
+                        constraints: vec![PlantUmlTaskConstraint::Happens(PlantUmlTaskStart::After("Build".to_string(), PlantUmlTaskAnchor::End, 0))],
+                        ..PlantUmlTask::new("Release".to_string(), None)
+                    })),
+                ])
+            })
+        ),
+        gantt_duration_too_large: ("@startgantt\n[A] lasts 99999999999 days\n@endgantt", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            source_line: "[A] lasts 99999999999 days".to_owned(),
+            ..Diagnostic::new("duration too long, \"99999999999 days\" is more than 3660 days".to_owned(), Span::new(22, 38), 2, 11)
+        }))),
+        gantt_duration_past_the_last_day_drawn: ("@startgantt\n[A] lasts 523 weeks\n@endgantt", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            source_line: "[A] lasts 523 weeks".to_owned(),
+            ..Diagnostic::new("duration too long, \"523 weeks\" is more than 3660 days".to_owned(), Span::new(22, 31), 2, 11)
+        }))),
+        gantt_invalid_date: ("@startgantt\nProject starts 2026-13-45\n@endgantt", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            source_line: "Project starts 2026-13-45".to_owned(),
+            ..Diagnostic::new("invalid date, \"2026-13-45\" is not a day of the calendar".to_owned(), Span::new(27, 37), 2, 16)
+        }))),
+        gantt_task_starting_from_an_unknown_task: ("@startgantt\n[A] starts at [B]'s end\n@endgantt", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            source_line: "[A] starts at [B]'s end".to_owned(),
+            ..Diagnostic::new("unknown task, [B] is not in the plan of [A]".to_owned(), Span::new(26, 35), 2, 15)
+        }))),
+        gantt_tasks_starting_from_each_other: ("@startgantt\n[A] starts at [B]'s end\n[B] starts at [A]'s end\n@endgantt", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            source_line: "[B] starts at [A]'s end".to_owned(),
+            ..Diagnostic::new("tasks start from each other: [A] -> [B] -> [A]".to_owned(), Span::new(50, 59), 3, 15)
+        }))),
+        gantt_misspelled_duration: ("@startgantt\n[A] lasts 2 dayz\n@endgantt", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            source_line: "[A] lasts 2 dayz".to_owned(),
+            expected: vec!["\"days\" or \"weeks\"".to_owned()],
+            ..Diagnostic::new("expected \"days\" or \"weeks\", found \"dayz\"".to_owned(), Span::new(24, 28), 2, 13)
+        }))),
+        deployment_elements_and_ports: (
+            "@startuml\nnode Server\ncloud Internet\ndatabase DB {\n  node Replica\n}\ncomponent C {\n  port p\n  portin i\n  portout o\n}\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Node, "Server".to_string(), None)),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Cloud, "Internet".to_string(), None)),
+                PlantUmlElement::Container(PlantUmlContainerKind::Database, "DB".to_string(), None, vec![
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Node, "Replica".to_string(), None)),
+                ]),
+                PlantUmlElement::Container(PlantUmlContainerKind::Component, "C".to_string(), None, vec![
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Port, "p".to_string(), None)),
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::PortIn, "i".to_string(), None)),
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::PortOut, "o".to_string(), None)),
+                ]),
+            ]))
+        ),
+        lollipop_and_socket_interfaces: (
+            "@startuml\n() \"Data Access\" as DA\nStore - DA\nWeb -( DA\nDA )- Cli\nWeb --(Checkout)\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Interface, "Data Access".to_string(), Some("DA".to_string()))),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "Store".to_string(),
+                    "DA".to_string(),
+                    PlantUmlArrow {
+                        short: true,
+                        ..PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, None)
+                    },
+                    None,
+                )),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "Web".to_string(),
+                    "DA".to_string(),
+                    PlantUmlArrow {
+                        short: true,
+                        ..PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Socket))
+                    },
+                    None,
+                )),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "DA".to_string(),
+                    "Cli".to_string(),
+                    PlantUmlArrow {
+                        short: true,
+                        ..PlantUmlArrow::new(Some(PlantUmlArrowHead::Socket), PlantUmlArrowLine::Solid, None)
+                    },
+                    None,
+                )),
+                PlantUmlElement::Element(PlantUmlDeclaration {
+                    implicit: true,
+                    ..PlantUmlDeclaration::new(PlantUmlElementKind::UseCase, "Checkout".to_string(), None)
+                }),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "Web".to_string(),
+                    "Checkout".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, None),
+                    None,
+                )),
+            ]))
+        ),
+        single_line_directives: (
+            "@startuml\ntitle Our Architecture\nheader: Draft\nfooter Page 1\ncaption Figure 1\ncomponent title_component\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Directive(PlantUmlDirectiveKind::Title, "Our Architecture".to_string()),
+                PlantUmlElement::Directive(PlantUmlDirectiveKind::Header, "Draft".to_string()),
+                PlantUmlElement::Directive(PlantUmlDirectiveKind::Footer, "Page 1".to_string()),
+                PlantUmlElement::Directive(PlantUmlDirectiveKind::Caption, "Figure 1".to_string()),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "title_component".to_string(), None)),
+            ]))
+        ),
+        multi_line_directives: (
+            "@startuml\ntitle\n  Our\n  Architecture\nend title\nlegend top right\n  A: the API\n  B: the database\nendlegend\n' after the legend\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Directive(PlantUmlDirectiveKind::Title, "Our\nArchitecture".to_string()),
+                PlantUmlElement::Directive(PlantUmlDirectiveKind::Legend, "A: the API\nB: the database".to_string()),
+                PlantUmlElement::Comment(PlantUmlComment::Line("after the legend".to_string())),
+            ]))
+        ),
+        anchored_notes: (
+            "@startuml\ncomponent \"Public API\" as api\nnote left of api : Entry point\\nfor clients\nnote bottom of \"Public API\"\n  Rate limited\n  per user\nend note\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "Public API".to_string(), Some("api".to_string()))),
+                PlantUmlElement::Note(PlantUmlNote {
+                    anchor: Some("api".to_string()),
+                    side: PlantUmlNoteSide::Left,
+                    ..PlantUmlNote::new("Entry point\nfor clients".to_string())
+                }),
+                PlantUmlElement::Note(PlantUmlNote {
+                    anchor: Some("Public API".to_string()),
+                    side: PlantUmlNoteSide::Bottom,
+                    ..PlantUmlNote::new("Rate limited\nper user".to_string())
+                }),
+            ]))
+        ),
+        names_with_dots_and_dashes: (
+            "@startuml\nartifact app.jar\nnode web-01\napp.jar..>web-01\nweb-01-l->app.jar\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Artifact, "app.jar".to_string(), None)),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Node, "web-01".to_string(), None)),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "app.jar".to_string(),
+                    "web-01".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Dotted, Some(PlantUmlArrowHead::Arrow)),
+                    None,
+                )),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "web-01".to_string(),
+                    "app.jar".to_string(),
+                    PlantUmlArrow {
+                        direction: Some(PlantUmlArrowDirection::Left),
+                        ..PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow))
+                    },
+                    None,
+                )),
+            ]))
+        ),
+        direction_hints: (
+            "@startuml\nleft to right direction\na -up-> b\nb .l.> c\na -[hidden]- c\nc -[#red,hidden]down-> a\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Direction(PlantUmlLayoutDirection::LeftToRight),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "a".to_string(),
+                    "b".to_string(),
+                    PlantUmlArrow {
+                        direction: Some(PlantUmlArrowDirection::Up),
+                        ..PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow))
+                    },
+                    None,
+                )),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "b".to_string(),
+                    "c".to_string(),
+                    PlantUmlArrow {
+                        direction: Some(PlantUmlArrowDirection::Left),
+                        ..PlantUmlArrow::new(None, PlantUmlArrowLine::Dotted, Some(PlantUmlArrowHead::Arrow))
+                    },
+                    None,
+                )),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "a".to_string(),
+                    "c".to_string(),
+                    PlantUmlArrow {
+                        hidden: true,
+                        ..PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, None)
+                    },
+                    None,
+                )),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "c".to_string(),
+                    "a".to_string(),
+                    PlantUmlArrow {
+                        direction: Some(PlantUmlArrowDirection::Down),
+                        hidden: true,
+                        ..PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow))
+                    },
+                    None,
+                )),
+            ]))
+        ),
+        sequence_statements: (
+            "@startuml\nparticipant \"Web Server\" as web\nactivate web\nalt cache hit \nweb ->> db : read\nelse\ndb --> web\nend\n== Later ==\n...\n... 5 minutes ...\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Participant, "Web Server".to_string(), Some("web".to_string()))),
+                PlantUmlElement::Activation(PlantUmlActivationKind::Activate, "web".to_string()),
+                PlantUmlElement::FragmentStart(PlantUmlFragmentKind::Alt, Some("cache hit".to_string())),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "web".to_string(),
+                    "db".to_string(),
+                    PlantUmlArrow {
+                        short: true,
+                        ..PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Open))
+                    },
+                    Some("read".to_string()),
+                )),
+                PlantUmlElement::FragmentElse(None),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "db".to_string(),
+                    "web".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                    None,
+                )),
+                PlantUmlElement::FragmentEnd,
+                PlantUmlElement::Divider("Later".to_string()),
+                PlantUmlElement::Delay(None),
+                PlantUmlElement::Delay(Some("5 minutes".to_string())),
+            ]))
+        ),
+        empty_divider: (
+            "@startuml\nA -> B\n== ==\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "A".to_string(),
+                    "B".to_string(),
+                    PlantUmlArrow {
+                        short: true,
+                        ..PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow))
+                    },
+                    None,
+                )),
+                PlantUmlElement::Divider("".to_string()),
+            ]))
+        ),
+        fragment_end_without_fragment: ("@startuml\nA -> B\nend\n@enduml", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            source_line: "end".to_owned(),
+            ..Diagnostic::new("\"end\" without a fragment to close".to_owned(), Span::new(17, 20), 3, 1)
+        }))),
+        fragment_else_without_fragment: ("@startuml\nA -> B\nelse other\n@enduml", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            source_line: "else other".to_owned(),
+            ..Diagnostic::new("\"else\" outside of a fragment".to_owned(), Span::new(17, 27), 3, 1)
+        }))),
+        unclosed_fragment: ("@startuml\nalt ok\nA -> B\n@enduml", Err(PlantUmlParserError::SyntaxError(Diagnostic {
+            source_line: "alt ok".to_owned(),
+            ..Diagnostic::new("unclosed \"alt\" fragment, expected \"end\"".to_owned(), Span::new(10, 16), 2, 1)
+        }))),
+        object_declarations: (
+            "@startuml\nobject server <<vm>> {\n  host = db.local\n  port = 5432; debug\n}\nmap ports {\n  http => 80\n}\nobject empty\nserver --> ports\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration {
+                    stereotypes: vec!["vm".to_string()],
+                    fields: vec![
+                        PlantUmlField::new("host".to_string(), Some("db.local".to_string())),
+                        PlantUmlField::new("port".to_string(), Some("5432".to_string())),
+                        PlantUmlField::new("debug".to_string(), None),
+                    ],
+                    ..PlantUmlDeclaration::new(PlantUmlElementKind::Object, "server".to_string(), None)
+                }),
+                PlantUmlElement::Element(PlantUmlDeclaration {
+                    fields: vec![PlantUmlField::new("http".to_string(), Some("80".to_string()))],
+                    ..PlantUmlDeclaration::new(PlantUmlElementKind::Map, "ports".to_string(), None)
+                }),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Object, "empty".to_string(), None)),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "server".to_string(),
+                    "ports".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                    None,
+                )),
+            ]))
+        ),
+        members_of_declared_classes_after_a_colon: (
+            "@startuml\nclass ArrayList\nArrayList : size()\nArrayList : -elementData : Object[]\nIdle : waiting\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration {
+                    members: vec![
+                        PlantUmlMember {
+                            is_method: true,
+                            ..PlantUmlMember::new("size()".to_string())
+                        },
+                        PlantUmlMember {
+                            visibility: Some(PlantUmlVisibility::Private),
+                            ..PlantUmlMember::new("elementData : Object[]".to_string())
+                        },
+                    ],
+                    ..PlantUmlDeclaration::new(PlantUmlElementKind::Class, "ArrayList".to_string(), None)
+                }),
+                PlantUmlElement::StateDescription("Idle".to_string(), "waiting".to_string()),
+            ]))
+        ),
+        class_declarations: (
+            "@startuml\nabstract class Shape<K, List<V>> <<model>> {\n  -id : int\n  --\n  + {abstract} area() : double\n  {static} count : int; {method} reset\n}\ninterface Drawable { draw() }\nenum Color\nShape \"1\" *-- \"many\" Color : fills\nShape ..|> Drawable\nShape <|-- Circle\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Element(PlantUmlDeclaration {
+                    stereotypes: vec!["model".to_string()],
+                    generics: Some("K, List<V>".to_string()),
+                    members: vec![
+                        PlantUmlMember {
+                            visibility: Some(PlantUmlVisibility::Private),
+                            ..PlantUmlMember::new("id : int".to_string())
+                        },
+                        PlantUmlMember {
+                            visibility: Some(PlantUmlVisibility::Public),
+                            is_abstract: true,
+                            is_method: true,
+                            ..PlantUmlMember::new("area() : double".to_string())
+                        },
+                        PlantUmlMember {
+                            is_static: true,
+                            ..PlantUmlMember::new("count : int".to_string())
+                        },
+                        PlantUmlMember {
+                            is_method: true,
+                            ..PlantUmlMember::new("reset".to_string())
+                        },
+                    ],
+                    ..PlantUmlDeclaration::new(PlantUmlElementKind::AbstractClass, "Shape".to_string(), None)
+                }),
+                PlantUmlElement::Element(PlantUmlDeclaration {
+                    members: vec![PlantUmlMember {
+                        is_method: true,
+                        ..PlantUmlMember::new("draw()".to_string())
+                    }],
+                    ..PlantUmlDeclaration::new(PlantUmlElementKind::Interface, "Drawable".to_string(), None)
+                }),
+                PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Enum, "Color".to_string(), None)),
+                PlantUmlElement::Relationship(PlantUmlRelationship {
+                    from_multiplicity: Some("1".to_string()),
+                    to_multiplicity: Some("many".to_string()),
+                    ..PlantUmlRelationship::new(
+                        "Shape".to_string(),
+                        "Color".to_string(),
+                        PlantUmlArrow::new(Some(PlantUmlArrowHead::Composition), PlantUmlArrowLine::Solid, None),
+                        Some("fills".to_string()),
+                    )
+                }),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "Shape".to_string(),
+                    "Drawable".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Dotted, Some(PlantUmlArrowHead::Extension)),
+                    None,
+                )),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "Shape".to_string(),
+                    "Circle".to_string(),
+                    PlantUmlArrow::new(Some(PlantUmlArrowHead::Extension), PlantUmlArrowLine::Solid, None),
+                    None,
+                )),
+            ]))
+        ),
+        state_statements: (
+            "@startuml\n[*] --> Idle\nstate Idle : waiting\nIdle : since boot\nIdle --> Active : start [ready] / init()\nstate Active {\n  [*] --> Loading\n}\nstate f <<fork>>\nActive --> [*]\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "[*]".to_string(),
+                    "Idle".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                    None,
+                )),
+                PlantUmlElement::Element(PlantUmlDeclaration {
+                    description: Some("waiting".to_string()),
+                    ..PlantUmlDeclaration::new(PlantUmlElementKind::State, "Idle".to_string(), None)
+                }),
+                PlantUmlElement::StateDescription("Idle".to_string(), "since boot".to_string()),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "Idle".to_string(),
+                    "Active".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                    Some("start [ready] / init()".to_string()),
+                )),
+                PlantUmlElement::Container(
+                    PlantUmlContainerKind::State,
+                    "Active".to_string(),
+                    None,
+                    vec![PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                        "[*]".to_string(),
+                        "Loading".to_string(),
+                        PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                        None,
+                    ))],
+                ),
+                PlantUmlElement::Element(PlantUmlDeclaration {
+                    stereotypes: vec!["fork".to_string()],
+                    ..PlantUmlDeclaration::new(PlantUmlElementKind::State, "f".to_string(), None)
+                }),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "Active".to_string(),
+                    "[*]".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                    None,
+                )),
+            ]))
+        ),
+        activity_statements: (
+            "@startuml\n|Client|\nstart\n:Load\n  the session;\nif (size() > 0) then (yes)\nelseif (empty?)\nelse (no)\nendif\nwhile (more?) is (yes)\nendwhile (no)\nrepeat\nrepeat while (dirty?) not (clean)\nfork\nfork again\nend fork\nstop\nend\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Activity(PlantUmlActivityStep::Swimlane("Client".to_string())),
+                PlantUmlElement::Activity(PlantUmlActivityStep::Start),
+                PlantUmlElement::Activity(PlantUmlActivityStep::Action("Load\nthe session".to_string())),
+                PlantUmlElement::Activity(PlantUmlActivityStep::If("size() > 0".to_string(), Some("yes".to_string()))),
+                PlantUmlElement::Activity(PlantUmlActivityStep::ElseIf("empty?".to_string(), None)),
+                PlantUmlElement::FragmentElse(Some("(no)".to_string())),
+                PlantUmlElement::Activity(PlantUmlActivityStep::EndIf),
+                PlantUmlElement::Activity(PlantUmlActivityStep::While("more?".to_string(), Some("yes".to_string()))),
+                PlantUmlElement::Activity(PlantUmlActivityStep::EndWhile(Some("no".to_string()))),
+                PlantUmlElement::Activity(PlantUmlActivityStep::Repeat),
+                PlantUmlElement::Activity(PlantUmlActivityStep::RepeatWhile("dirty?".to_string(), None, Some("clean".to_string()))),
+                PlantUmlElement::Activity(PlantUmlActivityStep::Fork),
+                PlantUmlElement::Activity(PlantUmlActivityStep::ForkAgain),
+                PlantUmlElement::Activity(PlantUmlActivityStep::EndFork),
+                PlantUmlElement::Activity(PlantUmlActivityStep::Stop),
+                PlantUmlElement::FragmentEnd,
             ]))
         ),
+        floating_notes: (
+            "@startuml\nnote \"Shared by all\" as N1\nnote as N2\n  Deprecated\nendnote\nN1 .. N2\n@enduml",
+            Ok(PlantUmlDiagram::new(vec![
+                PlantUmlElement::Note(PlantUmlNote {
+                    alias: Some("N1".to_string()),
+                    ..PlantUmlNote::new("Shared by all".to_string())
+                }),
+                PlantUmlElement::Note(PlantUmlNote {
+                    alias: Some("N2".to_string()),
+                    ..PlantUmlNote::new("Deprecated".to_string())
+                }),
+                PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                    "N1".to_string(),
+                    "N2".to_string(),
+                    PlantUmlArrow::new(None, PlantUmlArrowLine::Dotted, None),
+                    None,
+                )),
+            ]))
+        ),
+    }
+
+    #[test]
+    fn parse_all_should_return_every_diagram() {
+        let input: &str = "Some notes\n@startuml(id=first)\ncomponent A\n@enduml\n\n@startuml\ncomponent B\n@enduml";
+        let expected: Result<Vec<PlantUmlDiagram>, PlantUmlParserError> = Ok(vec![
+            PlantUmlDiagram {
+                name: Some("first".to_string()),
+                ..PlantUmlDiagram::new(vec![PlantUmlElement::Element(PlantUmlDeclaration::new(
+                    PlantUmlElementKind::Component,
+                    "A".to_string(),
+                    None,
+                ))])
+            },
+            PlantUmlDiagram::new(vec![PlantUmlElement::Element(PlantUmlDeclaration::new(
+                PlantUmlElementKind::Component,
+                "B".to_string(),
+                None,
+            ))]),
+        ]);
+
+        let parser: PestPlantUmlParser = PestPlantUmlParser::new();
+        let result: Result<Vec<PlantUmlDiagram>, PlantUmlParserError> = parser.parse_all(input);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn parse_should_read_the_components_diagram_of_the_docs() {
+        let input: &str =
+            include_str!("../../../../../../docs/diagrams/plant_uml/uml_rs_components.puml");
+
+        let parser: PestPlantUmlParser = PestPlantUmlParser::new();
+        let result: Result<PlantUmlDiagram, PlantUmlParserError> = parser.parse(input);
+
+        let elements: Vec<PlantUmlElement> = result.expect("the docs diagram is valid").elements;
+        let relationships: Vec<(&str, &str)> = elements
+            .iter()
+            .filter_map(|element| match element {
+                PlantUmlElement::Relationship(r) => Some((r.from.as_str(), r.to.as_str())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            relationships,
+            vec![
+                ("ascii_renderer", "uml"),
+                ("plant_uml", "uml"),
+                ("plant_uml_ascii_renderer_cli", "plant_uml"),
+                ("plant_uml_ascii_renderer_cli", "ascii_renderer"),
+                ("plant_uml_ascii_renderer_cli", "cli"),
+                ("nvim_plugin", "plant_uml_ascii_renderer_cli"),
+            ]
+        );
+    }
+
+    macro_rules! parse_recovering_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (first_param, second_param) = $value;
+
+                let input: &str = first_param;
+                let expected: (PlantUmlDiagram, Vec<Diagnostic>) = second_param;
+
+                let parser: PestPlantUmlParser = PestPlantUmlParser::new();
+                let result: (PlantUmlDiagram, Vec<Diagnostic>) = parser.parse_recovering(input);
+
+                assert_eq!(result, expected);
+            }
+        )*
+        }
+    }
+
+    parse_recovering_tests! {
+        recovering_valid_diagram: (
+            "@startuml\ncomponent A\n@enduml",
+            (
+                PlantUmlDiagram::new(vec![
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "A".to_string(), None)),
+                ]),
+                vec![],
+            )
+        ),
+        recovering_skips_invalid_lines: (
+            "@startuml\ncomponent A\na -> -> b\ncomponent B\n@enduml",
+            (
+                PlantUmlDiagram::new(vec![
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "A".to_string(), None)),
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "B".to_string(), None)),
+                ]),
+                vec![Diagnostic {
+                    expected: vec!["a name".to_owned(), "a multiplicity".to_owned()],
+                    source_line: "a -> -> b".to_owned(),
+                    ..Diagnostic::new("expected a name or a multiplicity, found \"->\"".to_owned(), Span::new(27, 29), 3, 6)
+                }],
+            )
+        ),
+        recovering_keeps_container_children: (
+            "@startuml\npackage p {\ncomponent A\n???\n}\n@enduml",
+            (
+                PlantUmlDiagram::new(vec![
+                    PlantUmlElement::Container(PlantUmlContainerKind::Package, "p".to_string(), None, vec![
+                        PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "A".to_string(), None)),
+                    ]),
+                ]),
+                vec![Diagnostic {
+                    expected: vec!["a statement".to_owned()],
+                    source_line: "???".to_owned(),
+                    ..Diagnostic::new(
+                        "expected a statement, found \"???\"".to_owned(),
+                        Span::new(34, 37),
+                        4,
+                        1,
+                    )
+                }],
+            )
+        ),
+        recovering_keeps_nodes_around_undeclared_state_changes: (
+            "@startuml\ncomponent A\nthis is garbage %%%\nA --> B\n@enduml",
+            (
+                PlantUmlDiagram::new(vec![
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "A".to_string(), None)),
+                    PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                        "A".to_string(),
+                        "B".to_string(),
+                        PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow)),
+                        None,
+                    )),
+                ]),
+                vec![Diagnostic {
+                    source_line: "this is garbage %%%".to_owned(),
+                    ..Diagnostic::new(
+                        "unrecognized statement".to_owned(),
+                        Span::new(22, 41),
+                        3,
+                        1,
+                    )
+                }],
+            )
+        ),
+        recovering_state_change_outside_timing_diagrams: (
+            "@startuml\nactor User\nUser is happy\n@enduml",
+            (
+                PlantUmlDiagram::new(vec![
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Actor, "User".to_string(), None)),
+                ]),
+                vec![Diagnostic {
+                    source_line: "User is happy".to_owned(),
+                    ..Diagnostic::new("unrecognized statement".to_owned(), Span::new(21, 34), 3, 1)
+                }],
+            )
+        ),
+        recovering_closes_unclosed_fragments: (
+            "@startuml\nalt ok\nA -> B\n@enduml",
+            (
+                PlantUmlDiagram::new(vec![
+                    PlantUmlElement::FragmentStart(PlantUmlFragmentKind::Alt, Some("ok".to_string())),
+                    PlantUmlElement::Relationship(PlantUmlRelationship::new(
+                        "A".to_string(),
+                        "B".to_string(),
+                        PlantUmlArrow {
+                            short: true,
+                            ..PlantUmlArrow::new(None, PlantUmlArrowLine::Solid, Some(PlantUmlArrowHead::Arrow))
+                        },
+                        None,
+                    )),
+                    PlantUmlElement::FragmentEnd,
+                ]),
+                vec![Diagnostic {
+                    source_line: "alt ok".to_owned(),
+                    ..Diagnostic::new("unclosed \"alt\" fragment, expected \"end\"".to_owned(), Span::new(10, 16), 2, 1)
+                }],
+            )
+        ),
+        recovering_leaves_out_timing_numbers_too_large: (
+            "@startuml\nrobust WB\n@99999999999\nWB is Idle\n@enduml",
+            (
+                PlantUmlDiagram::new(vec![
+                    PlantUmlElement::Timing(PlantUmlTimingStep::Participant(PlantUmlTimingParticipant::new(PlantUmlTimingKind::Robust, "WB".to_string(), None))),
+                    PlantUmlElement::Timing(PlantUmlTimingStep::StateChange("WB".to_string(), "Idle".to_string())),
+                ]),
+                vec![Diagnostic {
+                    source_line: "@99999999999".to_owned(),
+                    ..Diagnostic::new(
+                        "number too large, \"99999999999\" is more than 4294967295".to_owned(),
+                        Span::new(21, 32),
+                        3,
+                        2,
+                    )
+                }],
+            )
+        ),
+        recovering_leaves_out_invalid_gantt_statements: (
+            "@startgantt\n[A] lasts 2 days\n[B] lasts 99999999999 days\n@endgantt",
+            (
+                PlantUmlDiagram {
+                    kind: PlantUmlDiagramKind::Gantt,
+                    ..PlantUmlDiagram::new(vec![
+                        PlantUmlElement::Gantt(PlantUmlGanttStatement::Task(PlantUmlTask {
+                            constraints: vec![PlantUmlTaskConstraint::Lasts(2)],
+                            ..PlantUmlTask::new("A".to_string(), None)
+                        })),
+                    ])
+                },
+                vec![Diagnostic {
+                    source_line: "[B] lasts 99999999999 days".to_owned(),
+                    ..Diagnostic::new(
+                        "duration too long, \"99999999999 days\" is more than 3660 days".to_owned(),
+                        Span::new(39, 55),
+                        3,
+                        11,
+                    )
+                }],
+            )
+        ),
+        recovering_invalid_data: (
+            "@startyaml\nname: [Ada\n@endyaml",
+            (
+                PlantUmlDiagram::new(vec![]),
+                vec![Diagnostic {
+                    source_line: "name: [Ada".to_owned(),
+                    ..Diagnostic::new("while parsing a flow sequence, expected ',' or ']'".to_owned(), Span::new(21, 21), 2, 11)
+                }],
+            )
+        ),
+        recovering_mind_map: (
+            "@startmindmap\n* Root\nno marker\n** Child\n@endmindmap",
+            (
+                PlantUmlDiagram {
+                    kind: PlantUmlDiagramKind::MindMap,
+                    ..PlantUmlDiagram::new(vec![
+                        PlantUmlElement::TreeNode(PlantUmlTreeNode::new(1, None, "Root".to_string())),
+                        PlantUmlElement::TreeNode(PlantUmlTreeNode::new(2, None, "Child".to_string())),
+                    ])
+                },
+                vec![Diagnostic {
+                    expected: vec!["a node".to_owned()],
+                    source_line: "no marker".to_owned(),
+                    ..Diagnostic::new("expected a node, found \"no\"".to_owned(), Span::new(21, 23), 3, 1)
+                }],
+            )
+        ),
+        recovering_wbs_without_end_tag: (
+            "@startwbs\n* Release\n** Plan\n",
+            (
+                PlantUmlDiagram {
+                    kind: PlantUmlDiagramKind::Wbs,
+                    ..PlantUmlDiagram::new(vec![
+                        PlantUmlElement::TreeNode(PlantUmlTreeNode::new(1, None, "Release".to_string())),
+                        PlantUmlElement::TreeNode(PlantUmlTreeNode::new(2, None, "Plan".to_string())),
+                    ])
+                },
+                vec![Diagnostic {
+                    severity: Severity::Warning,
+                    expected: vec!["\"@endwbs\"".to_owned()],
+                    ..Diagnostic::new("missing \"@endwbs\"".to_owned(), Span::new(28, 28), 4, 1)
+                }],
+            )
+        ),
+        recovering_json_without_end_tag: (
+            "@startjson\n{\"name\": \"Ada\"}",
+            (
+                PlantUmlDiagram {
+                    kind: PlantUmlDiagramKind::Json,
+                    ..PlantUmlDiagram::new(vec![
+                        PlantUmlElement::Data(PlantUmlDataValue::Object(vec![
+                            ("name".to_string(), PlantUmlDataValue::Text("Ada".to_string())),
+                        ])),
+                    ])
+                },
+                vec![Diagnostic {
+                    severity: Severity::Warning,
+                    expected: vec!["\"@endjson\"".to_owned()],
+                    source_line: "{\"name\": \"Ada\"}".to_owned(),
+                    ..Diagnostic::new("missing \"@endjson\"".to_owned(), Span::new(26, 26), 2, 16)
+                }],
+            )
+        ),
+        recovering_yaml_without_end_tag: (
+            "@startyaml\nname: [Ada\n",
+            (
+                PlantUmlDiagram {
+                    kind: PlantUmlDiagramKind::Yaml,
+                    ..PlantUmlDiagram::new(vec![])
+                },
+                vec![
+                    Diagnostic {
+                        severity: Severity::Warning,
+                        expected: vec!["\"@endyaml\"".to_owned()],
+                        ..Diagnostic::new("missing \"@endyaml\"".to_owned(), Span::new(22, 22), 3, 1)
+                    },
+                    Diagnostic::new("while parsing a flow sequence, expected ',' or ']'".to_owned(), Span::new(22, 22), 3, 1),
+                ],
+            )
+        ),
+        recovering_gantt: (
+            "@startgantt\n[A] lasts 2 days\n[B] lasts 2 dayz\n@endgantt",
+            (
+                PlantUmlDiagram {
+                    kind: PlantUmlDiagramKind::Gantt,
+                    ..PlantUmlDiagram::new(vec![
+                        PlantUmlElement::Gantt(PlantUmlGanttStatement::Task(PlantUmlTask {
+                            constraints: vec![PlantUmlTaskConstraint::Lasts(2)],
+                            ..PlantUmlTask::new("A".to_string(), None)
+                        })),
+                        PlantUmlElement::Gantt(PlantUmlGanttStatement::Task(PlantUmlTask::new("B".to_string(), None))),
+                    ])
+                },
+                vec![Diagnostic {
+                    expected: vec!["\"days\" or \"weeks\"".to_owned()],
+                    source_line: "[B] lasts 2 dayz".to_owned(),
+                    ..Diagnostic::new("expected \"days\" or \"weeks\", found \"dayz\"".to_owned(), Span::new(41, 45), 3, 13)
+                }],
+            )
+        ),
+        recovering_missing_tags: (
+            "component A\n",
+            (
+                PlantUmlDiagram::new(vec![
+                    PlantUmlElement::Element(PlantUmlDeclaration::new(PlantUmlElementKind::Component, "A".to_string(), None)),
+                ]),
+                vec![
+                    Diagnostic {
+                        severity: Severity::Warning,
+                        expected: vec!["\"@startuml\"".to_owned()],
+                        source_line: "component A".to_owned(),
+                        ..Diagnostic::new("missing \"@startuml\"".to_owned(), Span::new(0, 0), 1, 1)
+                    },
+                    Diagnostic {
+                        severity: Severity::Warning,
+                        expected: vec!["\"@enduml\"".to_owned()],
+                        ..Diagnostic::new("missing \"@enduml\"".to_owned(), Span::new(12, 12), 2, 1)
+                    },
+                ],
+            )
+        ),
     }
 }
@@ -0,0 +1 @@
+pub mod plant_uml_preprocessor_impl;
@@ -0,0 +1,1076 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use lib_core::domain::{
+    entities::diagnostic::{Diagnostic, Span},
+    repositories::file_repository::{FileRepository, FileRepositoryError},
+};
+
+use crate::{
+    adapters::repositories::diagram_repository_plant_uml_impl::{
+        PlantUmlPreprocessor, PlantUmlPreprocessorError,
+    },
+    infra::models::plant_uml_source::{PlantUmlSource, PlantUmlSourceLocation},
+};
+
+// Stops "!define A B" / "!define B A" and procedures calling themselves from looping forever
+const MAX_EXPANSION_DEPTH: usize = 32;
+
+/// Line based preprocessor: directives are whole lines starting with "!", any other line
+/// goes through variable and macro substitution
+pub struct PlantUmlPreprocessorImpl {
+    file_repository: Arc<dyn FileRepository>,
+}
+
+impl PlantUmlPreprocessorImpl {
+    pub(crate) fn new(file_repository: Arc<dyn FileRepository>) -> Self {
+        Self { file_repository }
+    }
+
+    fn preprocess_lines(
+        &self,
+        lines: &[SourceLine],
+        state: &mut Expansion,
+    ) -> Result<(), PlantUmlPreprocessorError> {
+        let mut conditions: Vec<Condition> = Vec::new();
+        let mut index: usize = 0;
+
+        while let Some(line) = lines.get(index) {
+            index += 1;
+
+            if let Err(error) =
+                self.preprocess_line(line, lines, &mut index, &mut conditions, state)
+            {
+                state.fail(error)?;
+            }
+        }
+
+        match conditions.last() {
+            Some(condition) => state.fail(invalid_directive(
+                &condition.opened_at,
+                "\"!if\" is never closed by \"!endif\"",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    // `index` is past `line`, a procedure declared at `line` moves it past its body
+    fn preprocess_line(
+        &self,
+        line: &SourceLine,
+        lines: &[SourceLine],
+        index: &mut usize,
+        conditions: &mut Vec<Condition>,
+        state: &mut Expansion,
+    ) -> Result<(), PlantUmlPreprocessorError> {
+        let active: bool = conditions.last().is_none_or(|c| c.active);
+        let directive: Directive = Directive::from(line.text.as_str());
+
+        match directive {
+            Directive::If(expression) => conditions.push(match active {
+                true => Condition::new(evaluate(&state.expand(expression)), line),
+                false => Condition::skipped(line),
+            }),
+            Directive::IfDefined(name, expected) => conditions.push(match active {
+                true => Condition::new(state.is_defined(name) == expected, line),
+                false => Condition::skipped(line),
+            }),
+            Directive::ElseIf(expression) => {
+                let condition: &mut Condition = conditions
+                    .last_mut()
+                    .ok_or_else(|| invalid_directive(line, "\"!elseif\" without \"!if\""))?;
+                let holds: bool = condition.parent_active
+                    && !condition.taken
+                    && evaluate(&state.expand(expression));
+
+                condition.active = holds;
+                condition.taken |= holds;
+            }
+            Directive::Else => {
+                let condition: &mut Condition = conditions
+                    .last_mut()
+                    .ok_or_else(|| invalid_directive(line, "\"!else\" without \"!if\""))?;
+
+                condition.active = condition.parent_active && !condition.taken;
+                condition.taken = true;
+            }
+            Directive::EndIf => {
+                conditions
+                    .pop()
+                    .ok_or_else(|| invalid_directive(line, "\"!endif\" without \"!if\""))?;
+            }
+            _ if !active => {}
+            Directive::Procedure(signature) => {
+                let end: usize = lines[*index..]
+                    .iter()
+                    .position(|l| Directive::from(l.text.as_str()) == Directive::EndProcedure)
+                    .map(|offset| *index + offset)
+                    .ok_or_else(|| invalid_directive(line, "\"!procedure\" is never closed"))?;
+                let (name, parameters) = parse_signature(signature)
+                    .ok_or_else(|| invalid_directive(line, "invalid procedure signature"))?;
+
+                state.procedures.insert(
+                    name,
+                    Procedure::new(parameters, lines[*index..end].to_vec()),
+                );
+                *index = end + 1;
+            }
+            Directive::EndProcedure => {
+                return Err(invalid_directive(
+                    line,
+                    "\"!endprocedure\" without \"!procedure\"",
+                ));
+            }
+            Directive::Include(path, many) => self.include(path, many, line, state)?,
+            Directive::Define(definition) => {
+                let (name, parameters, body) = parse_definition(definition)
+                    .ok_or_else(|| invalid_directive(line, "expected a macro name"))?;
+
+                state.defines.insert(name, Macro::new(parameters, body));
+            }
+            Directive::Undefine(name) => {
+                state.defines.remove(name.trim());
+            }
+            Directive::Assignment(assignment) => {
+                let (name, value, only_if_undefined) = parse_assignment(assignment)
+                    .ok_or_else(|| invalid_directive(line, "expected \"$name = value\""))?;
+
+                if !(only_if_undefined && state.is_defined(&name)) {
+                    let value: String = unquote(&state.expand(&value)).to_string();
+                    state.variables.insert(name, value);
+                }
+            }
+            Directive::Unknown(keyword) => {
+                return Err(invalid_directive(
+                    line,
+                    &format!("unknown preprocessor directive \"!{}\"", keyword),
+                ));
+            }
+            Directive::None => match parse_call(&line.text, state) {
+                Some((procedure, arguments)) => self.call(procedure, arguments, line, state)?,
+                None => state.push(state.expand(&line.text), line),
+            },
+        }
+
+        Ok(())
+    }
+
+    fn include(
+        &self,
+        argument: &str,
+        many: bool,
+        line: &SourceLine,
+        state: &mut Expansion,
+    ) -> Result<(), PlantUmlPreprocessorError> {
+        let argument: String = unquote(&state.expand(argument)).to_string();
+
+        if argument.starts_with('<') {
+            return Err(PlantUmlPreprocessorError::IncludeError(diagnostic_at(
+                line,
+                &format!("standard library include {} is not supported", argument),
+            )));
+        }
+
+        // Relative to the file holding the "!include", or to the working directory
+        let path: PathBuf = normalize(
+            &match line.location.file.as_deref().and_then(|f| f.parent()) {
+                Some(directory) => directory.join(&argument),
+                None => PathBuf::from(&argument),
+            },
+        );
+
+        if let Some(start) = state.include_stack.iter().position(|p| p == &path) {
+            let chain: Vec<String> = state.include_stack[start..]
+                .iter()
+                .chain([&path])
+                .map(|p| p.display().to_string())
+                .collect();
+
+            return Err(PlantUmlPreprocessorError::IncludeCycle(diagnostic_at(
+                line,
+                &format!("include cycle: {}", chain.join(" -> ")),
+            )));
+        }
+
+        // "!include" pulls a file in once, "!include_many" every time it is asked to
+        if !state.included.insert(path.clone()) && !many {
+            return Ok(());
+        }
+
+        let content: String = self.file_repository.get_file_content(&path).map_err(|e| {
+            let message: String = match e {
+                FileRepositoryError::InexistentFile => {
+                    format!("included file \"{}\" does not exist", argument)
+                }
+                FileRepositoryError::Unknown(reason) => {
+                    format!("unable to include \"{}\": {}", argument, reason)
+                }
+            };
+
+            PlantUmlPreprocessorError::IncludeError(diagnostic_at(line, &message))
+        })?;
+        let lines: Vec<SourceLine> = source_lines(&content, Some(&path));
+
+        state.include_stack.push(path);
+        let result = self.preprocess_lines(included_body(&lines), state);
+        state.include_stack.pop();
+
+        result
+    }
+
+    fn call(
+        &self,
+        procedure: Procedure,
+        arguments: Vec<String>,
+        line: &SourceLine,
+        state: &mut Expansion,
+    ) -> Result<(), PlantUmlPreprocessorError> {
+        if state.locals.len() >= MAX_EXPANSION_DEPTH {
+            return Err(invalid_directive(
+                line,
+                "procedure calls are nested too deeply",
+            ));
+        }
+
+        let locals: HashMap<String, String> = procedure
+            .parameters
+            .iter()
+            .enumerate()
+            .map(|(i, (name, default))| {
+                let value: String = arguments
+                    .get(i)
+                    .map(|argument| unquote(&state.expand(argument)).to_string())
+                    .or(default.clone())
+                    .unwrap_or_default();
+
+                (name.clone(), value)
+            })
+            .collect();
+
+        state.locals.push(locals);
+        let result = self.preprocess_lines(&procedure.body, state);
+        state.locals.pop();
+
+        result
+    }
+}
+
+impl PlantUmlPreprocessor for PlantUmlPreprocessorImpl {
+    fn preprocess(
+        &self,
+        input: &str,
+        origin: Option<&Path>,
+    ) -> Result<PlantUmlSource, PlantUmlPreprocessorError> {
+        let mut state: Expansion = Expansion::new(origin);
+
+        self.preprocess_lines(&source_lines(input, origin), &mut state)?;
+
+        Ok(state.source())
+    }
+
+    fn preprocess_recovering(
+        &self,
+        input: &str,
+        origin: Option<&Path>,
+    ) -> (PlantUmlSource, Vec<Diagnostic>) {
+        let mut state: Expansion = Expansion {
+            errors: Some(Vec::new()),
+            ..Expansion::new(origin)
+        };
+
+        let result = self.preprocess_lines(&source_lines(input, origin), &mut state);
+        let diagnostics: Vec<Diagnostic> = state
+            .errors
+            .take()
+            .into_iter()
+            .flatten()
+            .chain(result.err())
+            .map(Diagnostic::from)
+            .collect();
+
+        (state.source(), diagnostics)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SourceLine {
+    text: String,
+    location: PlantUmlSourceLocation,
+    // Byte offset of the line within its file
+    offset: usize,
+}
+
+fn source_lines(content: &str, file: Option<&Path>) -> Vec<SourceLine> {
+    let file: Option<Arc<PathBuf>> = file.map(|f| Arc::new(f.to_path_buf()));
+    let mut offset: usize = 0;
+
+    content
+        .split('\n')
+        .enumerate()
+        .map(|(i, text)| {
+            let line: SourceLine = SourceLine {
+                text: text.trim_end_matches('\r').to_string(),
+                location: PlantUmlSourceLocation::new(file.clone(), i + 1),
+                offset,
+            };
+            offset += text.len() + 1;
+
+            line
+        })
+        .collect()
+}
+
+// Only what lies between "@startuml" and "@enduml" is included when the file has them
+fn included_body(lines: &[SourceLine]) -> &[SourceLine] {
+    let is_tag = |line: &SourceLine, tag: &str| line.text.trim_start().starts_with(tag);
+
+    match lines.iter().position(|l| is_tag(l, "@startuml")) {
+        Some(start) => {
+            let end: usize = lines[start..]
+                .iter()
+                .position(|l| is_tag(l, "@enduml"))
+                .map_or(lines.len(), |offset| start + offset);
+
+            &lines[start + 1..end]
+        }
+        None => lines,
+    }
+}
+
+// Resolves "." and ".." without touching the file system, so cycles are spotted
+// whatever the path used to reach a file
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized: PathBuf = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if normalized.file_name().is_some() => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+
+    normalized
+}
+
+#[derive(Debug, Default)]
+struct Expansion {
+    lines: Vec<String>,
+    locations: Vec<PlantUmlSourceLocation>,
+    defines: HashMap<String, Macro>,
+    variables: HashMap<String, String>,
+    // Arguments of the procedures being expanded, innermost last
+    locals: Vec<HashMap<String, String>>,
+    procedures: HashMap<String, Procedure>,
+    include_stack: Vec<PathBuf>,
+    included: HashSet<PathBuf>,
+    // Set in the recovering mode, which leaves out the lines in error and carries on
+    errors: Option<Vec<PlantUmlPreprocessorError>>,
+}
+
+impl Expansion {
+    fn new(origin: Option<&Path>) -> Self {
+        Self {
+            include_stack: origin.map(normalize).into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    fn source(&self) -> PlantUmlSource {
+        PlantUmlSource::new(self.lines.join("\n"), self.locations.clone())
+    }
+
+    fn fail(&mut self, error: PlantUmlPreprocessorError) -> Result<(), PlantUmlPreprocessorError> {
+        match &mut self.errors {
+            Some(errors) => {
+                errors.push(error);
+                Ok(())
+            }
+            None => Err(error),
+        }
+    }
+
+    fn push(&mut self, text: String, line: &SourceLine) {
+        self.lines.push(text);
+        self.locations.push(line.location.clone());
+    }
+
+    fn is_defined(&self, name: &str) -> bool {
+        self.defines.contains_key(name) || self.variable(name).is_some()
+    }
+
+    fn variable(&self, name: &str) -> Option<&String> {
+        self.locals
+            .last()
+            .and_then(|locals| locals.get(name))
+            .or(self.variables.get(name))
+    }
+
+    fn expand(&self, text: &str) -> String {
+        let mut expanded: String = self.substitute_variables(text);
+
+        // Macros may expand to other macros
+        for _ in 0..MAX_EXPANSION_DEPTH {
+            let next: String = self.substitute_macros(&expanded);
+
+            if next == expanded {
+                break;
+            }
+            expanded = next;
+        }
+
+        expanded
+    }
+
+    fn substitute_variables(&self, text: &str) -> String {
+        replace_identifiers(text, |identifier, _| {
+            identifier.starts_with('$').then_some(())?;
+
+            self.variable(identifier).map(|value| (value.clone(), 0))
+        })
+    }
+
+    fn substitute_macros(&self, text: &str) -> String {
+        replace_identifiers(text, |identifier, rest| {
+            let definition: &Macro = self.defines.get(identifier)?;
+
+            match &definition.parameters {
+                None => Some((definition.body.clone(), 0)),
+                Some(parameters) => {
+                    let (arguments, consumed) = split_arguments(rest)?;
+                    let values: HashMap<&str, &str> = parameters
+                        .iter()
+                        .map(String::as_str)
+                        .zip(arguments.iter().map(String::as_str))
+                        .collect();
+                    let body: String = replace_identifiers(&definition.body, |parameter, _| {
+                        values.get(parameter).map(|value| (value.to_string(), 0))
+                    });
+
+                    Some((body, consumed))
+                }
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Macro {
+    // None for "!define NAME value", which is replaced even without parentheses
+    parameters: Option<Vec<String>>,
+    body: String,
+}
+
+impl Macro {
+    fn new(parameters: Option<Vec<String>>, body: String) -> Self {
+        Self { parameters, body }
+    }
+}
+
+// Name, Default value
+type Parameters = Vec<(String, Option<String>)>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Procedure {
+    parameters: Parameters,
+    body: Vec<SourceLine>,
+}
+
+impl Procedure {
+    fn new(parameters: Parameters, body: Vec<SourceLine>) -> Self {
+        Self { parameters, body }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Condition {
+    // Whether lines are currently kept
+    active: bool,
+    // Whether a branch of this "!if" has already been kept
+    taken: bool,
+    // False for an "!if" nested in a discarded branch, none of its branches are kept
+    parent_active: bool,
+    opened_at: SourceLine,
+}
+
+impl Condition {
+    fn new(holds: bool, line: &SourceLine) -> Self {
+        Self {
+            active: holds,
+            taken: holds,
+            parent_active: true,
+            opened_at: line.clone(),
+        }
+    }
+
+    fn skipped(line: &SourceLine) -> Self {
+        Self {
+            active: false,
+            taken: true,
+            parent_active: false,
+            opened_at: line.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Directive<'a> {
+    // Path, Many
+    Include(&'a str, bool),
+    Define(&'a str),
+    Undefine(&'a str),
+    Assignment(&'a str),
+    If(&'a str),
+    // Name, Expected to be defined
+    IfDefined(&'a str, bool),
+    ElseIf(&'a str),
+    Else,
+    EndIf,
+    Procedure(&'a str),
+    EndProcedure,
+    Unknown(&'a str),
+    None,
+}
+
+impl<'a> From<&'a str> for Directive<'a> {
+    fn from(value: &'a str) -> Self {
+        let Some(directive) = value.trim().strip_prefix('!') else {
+            return Directive::None;
+        };
+
+        if directive.starts_with('$') {
+            return Directive::Assignment(directive);
+        }
+
+        let keyword_length: usize = directive
+            .find(|c: char| !(c.is_ascii_alphabetic() || c == '_'))
+            .unwrap_or(directive.len());
+        let (keyword, argument) = directive.split_at(keyword_length);
+        let argument: &str = argument.trim();
+
+        match keyword {
+            "include" | "include_once" => Directive::Include(argument, false),
+            "include_many" => Directive::Include(argument, true),
+            "define" => Directive::Define(argument),
+            "undef" => Directive::Undefine(argument),
+            "if" => Directive::If(argument),
+            "ifdef" => Directive::IfDefined(argument, true),
+            "ifndef" => Directive::IfDefined(argument, false),
+            "elseif" => Directive::ElseIf(argument),
+            "else" => Directive::Else,
+            "endif" => Directive::EndIf,
+            "procedure" => Directive::Procedure(argument),
+            "endprocedure" => Directive::EndProcedure,
+            keyword => Directive::Unknown(keyword),
+        }
+    }
+}
+
+// "NAME value" or "NAME(a, b) body"
+fn parse_definition(definition: &str) -> Option<(String, Option<Vec<String>>, String)> {
+    let name_length: usize = identifier_length(definition);
+    (name_length > 0).then_some(())?;
+
+    let (name, rest) = definition.split_at(name_length);
+
+    match split_arguments(rest) {
+        Some((parameters, consumed)) => Some((
+            name.to_string(),
+            Some(parameters),
+            rest[consumed..].trim().to_string(),
+        )),
+        None => Some((name.to_string(), None, rest.trim().to_string())),
+    }
+}
+
+// "$name = value", or "$name ?= value" to only set it when undefined
+fn parse_assignment(assignment: &str) -> Option<(String, String, bool)> {
+    let (name, value) = assignment.split_once('=')?;
+    let (name, only_if_undefined) = match name.trim_end().strip_suffix('?') {
+        Some(name) => (name, true),
+        None => (name, false),
+    };
+    let name: &str = name.trim();
+
+    (identifier_length(name) == name.len() && name.len() > 1).then(|| {
+        (
+            name.to_string(),
+            value.trim().to_string(),
+            only_if_undefined,
+        )
+    })
+}
+
+// "$name($a, $b = \"default\")"
+fn parse_signature(signature: &str) -> Option<(String, Parameters)> {
+    let name_length: usize = identifier_length(signature);
+    (name_length > 0).then_some(())?;
+
+    let (name, rest) = signature.split_at(name_length);
+    let (parameters, _) = split_arguments(rest.trim_start())?;
+    let parameters: Parameters = parameters
+        .iter()
+        .map(|parameter| match parameter.split_once('=') {
+            Some((name, default)) => (
+                name.trim().to_string(),
+                Some(unquote(default.trim()).to_string()),
+            ),
+            None => (parameter.clone(), None),
+        })
+        .collect();
+
+    Some((name.to_string(), parameters))
+}
+
+// A line made of a single call to a known procedure, e.g., "$server("api")"
+fn parse_call(text: &str, state: &Expansion) -> Option<(Procedure, Vec<String>)> {
+    let text: &str = text.trim();
+    let name_length: usize = identifier_length(text);
+    let procedure: &Procedure = state.procedures.get(&text[..name_length])?;
+    let (arguments, consumed) = split_arguments(&text[name_length..])?;
+
+    (consumed == text.len() - name_length).then(|| (procedure.clone(), arguments))
+}
+
+fn identifier_length(text: &str) -> usize {
+    text.char_indices()
+        .find(|(i, c)| !(is_identifier_char(*c) || (*i == 0 && *c == '$')))
+        .map_or(text.len(), |(i, _)| i)
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Calls `replace` with every identifier of `text` (or "$variable") and what follows it;
+/// it returns the replacement along with how many bytes of what follows it consumed
+fn replace_identifiers<F>(text: &str, mut replace: F) -> String
+where
+    F: FnMut(&str, &str) -> Option<(String, usize)>,
+{
+    let mut result: String = String::new();
+    let mut index: usize = 0;
+
+    while index < text.len() {
+        let rest: &str = &text[index..];
+        let previous: Option<char> = text[..index].chars().next_back();
+        let starts_identifier: bool = !previous.is_some_and(|c| is_identifier_char(c) || c == '$')
+            && rest
+                .chars()
+                .next()
+                .is_some_and(|c| c == '$' || c.is_alphabetic() || c == '_');
+        let length: usize = match starts_identifier {
+            true => identifier_length(rest),
+            false => 0,
+        };
+
+        if length == 0 {
+            let c: char = rest.chars().next().unwrap_or_default();
+            result.push(c);
+            index += c.len_utf8();
+            continue;
+        }
+
+        match replace(&rest[..length], &rest[length..]) {
+            Some((replacement, consumed)) => {
+                result.push_str(&replacement);
+                index += length + consumed;
+            }
+            None => {
+                result.push_str(&rest[..length]);
+                index += length;
+            }
+        }
+    }
+
+    result
+}
+
+/// Splits "(a, "b, c", f(d))" into its arguments, returning them with the length of the
+/// parenthesized text
+fn split_arguments(text: &str) -> Option<(Vec<String>, usize)> {
+    text.starts_with('(').then_some(())?;
+
+    let mut arguments: Vec<String> = Vec::new();
+    let mut current: String = String::new();
+    let mut depth: usize = 0;
+    let mut quoted: bool = false;
+
+    for (i, c) in text.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '(' if !quoted => {
+                depth += 1;
+                if depth == 1 {
+                    continue;
+                }
+            }
+            ')' if !quoted => {
+                depth -= 1;
+                if depth == 0 {
+                    if !current.trim().is_empty() || !arguments.is_empty() {
+                        arguments.push(current.trim().to_string());
+                    }
+                    return Some((arguments, i + 1));
+                }
+            }
+            ',' if !quoted && depth == 1 => {
+                arguments.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+
+        current.push(c);
+    }
+
+    None
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .unwrap_or(value)
+}
+
+/// Evaluates an already expanded "!if" condition: comparisons, "&&", "||", "!" and
+/// parentheses; anything else is true unless empty, "0" or "false"
+fn evaluate(expression: &str) -> bool {
+    let expression: &str = expression.trim();
+
+    // "(a || b)" as a whole, but not "(a) || (b)"
+    if split_arguments(expression).is_some_and(|(_, length)| length == expression.len()) {
+        return evaluate(&expression[1..expression.len() - 1]);
+    }
+    if let Some((left, right)) = split_top_level(expression, "||") {
+        return evaluate(left) || evaluate(right);
+    }
+    if let Some((left, right)) = split_top_level(expression, "&&") {
+        return evaluate(left) && evaluate(right);
+    }
+    for operator in ["==", "!=", "<=", ">=", "<", ">"] {
+        if let Some((left, right)) = split_top_level(expression, operator) {
+            return compare(unquote(left.trim()), operator, unquote(right.trim()));
+        }
+    }
+    if let Some(negated) = expression.strip_prefix('!') {
+        return !evaluate(negated);
+    }
+
+    !matches!(unquote(expression), "" | "0" | "false")
+}
+
+fn compare(left: &str, operator: &str, right: &str) -> bool {
+    let ordering = match (left.parse::<i64>(), right.parse::<i64>()) {
+        (Ok(left), Ok(right)) => left.cmp(&right),
+        _ => left.cmp(right),
+    };
+
+    match operator {
+        "==" => ordering.is_eq(),
+        "!=" => ordering.is_ne(),
+        "<=" => ordering.is_le(),
+        ">=" => ordering.is_ge(),
+        "<" => ordering.is_lt(),
+        _ => ordering.is_gt(),
+    }
+}
+
+// Splits at the first operator found outside quotes and parentheses
+fn split_top_level<'a>(expression: &'a str, operator: &str) -> Option<(&'a str, &'a str)> {
+    let mut depth: usize = 0;
+    let mut quoted: bool = false;
+
+    for (i, c) in expression.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted => depth = depth.saturating_sub(1),
+            _ if !quoted && depth == 0 && expression[i..].starts_with(operator) => {
+                // "<" must not match the start of "<=", nor "!" the start of "!="
+                let next: &str = &expression[i + operator.len()..];
+                if operator.len() == 1 && next.starts_with('=') {
+                    continue;
+                }
+                return Some((&expression[..i], next));
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn invalid_directive(line: &SourceLine, message: &str) -> PlantUmlPreprocessorError {
+    PlantUmlPreprocessorError::InvalidDirective(diagnostic_at(line, message))
+}
+
+fn diagnostic_at(line: &SourceLine, message: &str) -> Diagnostic {
+    let indentation: usize = line.text.len() - line.text.trim_start().len();
+    let start: usize = line.offset + indentation;
+
+    Diagnostic {
+        file: line.location.file.clone(),
+        source_line: line.text.clone(),
+        ..Diagnostic::new(
+            message.to_string(),
+            Span::new(start, start + line.text.trim().len()),
+            line.location.line,
+            line.text[..indentation].chars().count() + 1,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+    use lib_core::domain::repositories::file_repository::{FileRepository, FileRepositoryError};
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn preprocess(
+        input: &str,
+        files: &[(&str, &str)],
+    ) -> Result<PlantUmlSource, PlantUmlPreprocessorError> {
+        let file_repository: Arc<dyn FileRepository> = Arc::new(FileRepositoryMockImpl::new(files));
+
+        PlantUmlPreprocessorImpl::new(file_repository)
+            .preprocess(input, Some(Path::new("docs/main.puml")))
+    }
+
+    fn location(file: &str, line: usize) -> PlantUmlSourceLocation {
+        PlantUmlSourceLocation::new(Some(Arc::new(PathBuf::from(file))), line)
+    }
+
+    fn error_message(error: PlantUmlPreprocessorError) -> (String, Option<PathBuf>, usize) {
+        let diagnostic: Diagnostic = Diagnostic::from(error);
+
+        (
+            diagnostic.message,
+            diagnostic.file.map(|f| f.as_ref().clone()),
+            diagnostic.line,
+        )
+    }
+
+    #[test]
+    fn content_without_directives_is_kept_as_is() {
+        let result = preprocess("@startuml\n[A] --> [B]\n@enduml", &[]);
+
+        assert_eq!(
+            result,
+            Ok(PlantUmlSource::new(
+                "@startuml\n[A] --> [B]\n@enduml".to_string(),
+                vec![
+                    location("docs/main.puml", 1),
+                    location("docs/main.puml", 2),
+                    location("docs/main.puml", 3),
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn includes_are_resolved_relative_to_the_including_file() {
+        let result = preprocess(
+            "@startuml\n!include common/styles.puml\n[A]\n@enduml",
+            &[
+                (
+                    "docs/common/styles.puml",
+                    "@startuml\n!include ../shared.puml\n[Styles]\n@enduml",
+                ),
+                ("docs/shared.puml", "[Shared]"),
+            ],
+        );
+
+        assert_eq!(
+            result,
+            Ok(PlantUmlSource::new(
+                "@startuml\n[Shared]\n[Styles]\n[A]\n@enduml".to_string(),
+                vec![
+                    location("docs/main.puml", 1),
+                    location("docs/shared.puml", 1),
+                    location("docs/common/styles.puml", 3),
+                    location("docs/main.puml", 3),
+                    location("docs/main.puml", 4),
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn include_pulls_a_file_once_and_include_many_every_time() {
+        let result = preprocess(
+            "!include part.puml\n!include part.puml\n!include_many part.puml",
+            &[("docs/part.puml", "[Part]")],
+        );
+
+        assert_eq!(
+            result.map(|source| source.text),
+            Ok("[Part]\n[Part]".to_string())
+        );
+    }
+
+    #[test]
+    fn include_cycles_are_reported() {
+        let result = preprocess(
+            "!include a.puml",
+            &[
+                ("docs/a.puml", "!include b.puml"),
+                ("docs/b.puml", "!include_many ./../docs/a.puml"),
+            ],
+        );
+
+        assert_eq!(
+            result.map_err(error_message),
+            Err((
+                "include cycle: docs/a.puml -> docs/b.puml -> docs/a.puml".to_string(),
+                Some(PathBuf::from("docs/b.puml")),
+                1
+            ))
+        );
+    }
+
+    #[test]
+    fn missing_includes_are_reported_at_the_include_line() {
+        let result = preprocess("@startuml\n  !include missing.puml\n@enduml", &[]);
+
+        assert_eq!(
+            result.map_err(error_message),
+            Err((
+                "included file \"missing.puml\" does not exist".to_string(),
+                Some(PathBuf::from("docs/main.puml")),
+                2
+            ))
+        );
+    }
+
+    #[test]
+    fn defines_are_substituted() {
+        let result = preprocess(
+            "!define SERVICE component\n!define LINK(a, b) a --> b : calls\nSERVICE api\nLINK(api, db)\n!undef SERVICE\nSERVICE",
+            &[],
+        );
+
+        assert_eq!(
+            result.map(|source| source.text),
+            Ok("component api\napi --> db : calls\nSERVICE".to_string())
+        );
+    }
+
+    #[test]
+    fn variables_and_conditions_are_evaluated() {
+        let result = preprocess(
+            "!$env = \"prod\"\n!$replicas ?= 3\n!$replicas ?= 1\n!if $env == \"dev\"\n[Dev]\n!elseif $replicas > 2 && $env != \"test\"\n[Cluster of $replicas]\n!else\n[Single]\n!endif\n!ifndef DEBUG\n!if 0\n[Never]\n!else\n[Nested never]\n!endif\n[Release]\n!endif",
+            &[],
+        );
+
+        assert_eq!(
+            result.map(|source| source.text),
+            Ok("[Cluster of 3]\n[Nested never]\n[Release]".to_string())
+        );
+    }
+
+    #[test]
+    fn procedures_are_expanded_at_their_body_lines() {
+        let result = preprocess(
+            "!procedure $service($name, $kind = \"component\")\n$kind $name\n!endprocedure\n$service(api)\n$service(\"db\", database)",
+            &[],
+        );
+
+        assert_eq!(
+            result,
+            Ok(PlantUmlSource::new(
+                "component api\ndatabase db".to_string(),
+                vec![location("docs/main.puml", 2), location("docs/main.puml", 2)]
+            ))
+        );
+    }
+
+    #[test]
+    fn unclosed_conditions_are_reported_where_they_open() {
+        let result = preprocess("[A]\n!ifdef DEBUG\n[B]", &[]);
+
+        assert_eq!(
+            result.map_err(error_message),
+            Err((
+                "\"!if\" is never closed by \"!endif\"".to_string(),
+                Some(PathBuf::from("docs/main.puml")),
+                2
+            ))
+        );
+    }
+
+    #[test]
+    fn unknown_directives_are_reported() {
+        let result = preprocess("!pragma layout smetana", &[]);
+
+        assert_eq!(
+            result.map_err(error_message),
+            Err((
+                "unknown preprocessor directive \"!pragma\"".to_string(),
+                Some(PathBuf::from("docs/main.puml")),
+                1
+            ))
+        );
+    }
+
+    #[test]
+    fn recovering_leaves_out_the_lines_in_error_and_carries_on() {
+        let file_repository: Arc<dyn FileRepository> = Arc::new(FileRepositoryMockImpl::new(&[]));
+
+        let (source, diagnostics) = PlantUmlPreprocessorImpl::new(file_repository)
+            .preprocess_recovering(
+                "@startuml\n!include missing.puml\n[A]\n!pragma layout smetana\n!ifdef DEBUG\n[B]",
+                Some(Path::new("docs/main.puml")),
+            );
+
+        assert_eq!(source.text, "@startuml\n[A]".to_string());
+        assert_eq!(
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| (diagnostic.message, diagnostic.line))
+                .collect::<Vec<(String, usize)>>(),
+            vec![
+                (
+                    "included file \"missing.puml\" does not exist".to_string(),
+                    2
+                ),
+                ("unknown preprocessor directive \"!pragma\"".to_string(), 4),
+                ("\"!if\" is never closed by \"!endif\"".to_string(), 5),
+            ]
+        );
+    }
+
+    struct FileRepositoryMockImpl {
+        files: HashMap<PathBuf, String>,
+    }
+
+    impl FileRepositoryMockImpl {
+        fn new(files: &[(&str, &str)]) -> Self {
+            Self {
+                files: files
+                    .iter()
+                    .map(|(path, content)| (PathBuf::from(path), content.to_string()))
+                    .collect(),
+            }
+        }
+    }
+
+    impl FileRepository for FileRepositoryMockImpl {
+        fn get_file_content(&self, file_path: &Path) -> Result<String, FileRepositoryError> {
+            self.files
+                .get(file_path)
+                .cloned()
+                .ok_or(FileRepositoryError::InexistentFile)
+        }
+    }
+}
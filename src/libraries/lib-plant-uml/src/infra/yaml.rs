@@ -0,0 +1 @@
+pub mod yaml_data_reader;
@@ -0,0 +1,345 @@
+use yaml_rust2::{Yaml, YamlLoader, scanner::ScanError};
+
+use crate::infra::models::plant_uml_diagram::PlantUmlDataValue;
+
+/// Reads the data of JSON and YAML diagrams alike, as JSON is a subset of YAML.
+/// JSON is checked on its own first, YAML would also take what JSON does not allow,
+/// e.g., a trailing comma.
+pub struct YamlDataReader;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YamlDataError {
+    pub message: String,
+    // In bytes from the start of the data
+    pub offset: usize,
+}
+
+impl YamlDataReader {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Only the first document is read when several are separated by "---"
+    pub fn read(&self, text: &str) -> Result<PlantUmlDataValue, YamlDataError> {
+        let documents: Vec<Yaml> =
+            YamlLoader::load_from_str(text).map_err(|e| data_error(text, e))?;
+
+        Ok(documents
+            .first()
+            .map(data_value)
+            .unwrap_or(PlantUmlDataValue::Null))
+    }
+
+    pub fn read_json(&self, text: &str) -> Result<PlantUmlDataValue, YamlDataError> {
+        let mut checker: JsonChecker = JsonChecker { text, offset: 0 };
+
+        checker.value()?;
+        checker.whitespace();
+        if checker.offset < text.len() {
+            return Err(checker.error("expected end of data"));
+        }
+
+        self.read(text)
+    }
+}
+
+// Follows the JSON grammar of RFC 8259, stopping at the first byte out of it
+struct JsonChecker<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+impl JsonChecker<'_> {
+    fn value(&mut self) -> Result<(), YamlDataError> {
+        self.whitespace();
+
+        match self.peek() {
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => self.string(),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ => ["true", "false", "null"]
+                .iter()
+                .find(|word| self.text[self.offset..].starts_with(*word))
+                .map(|word| self.offset += word.len())
+                .ok_or(self.error("expected a value")),
+        }
+    }
+
+    fn object(&mut self) -> Result<(), YamlDataError> {
+        self.offset += 1;
+        self.whitespace();
+        if self.eat(b'}') {
+            return Ok(());
+        }
+
+        loop {
+            self.whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected a key"));
+            }
+            self.string()?;
+            self.whitespace();
+            if !self.eat(b':') {
+                return Err(self.error("expected ':'"));
+            }
+            self.value()?;
+            self.whitespace();
+            if self.eat(b'}') {
+                return Ok(());
+            }
+            if !self.eat(b',') {
+                return Err(self.error("expected ',' or '}'"));
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<(), YamlDataError> {
+        self.offset += 1;
+        self.whitespace();
+        if self.eat(b']') {
+            return Ok(());
+        }
+
+        loop {
+            self.value()?;
+            self.whitespace();
+            if self.eat(b']') {
+                return Ok(());
+            }
+            if !self.eat(b',') {
+                return Err(self.error("expected ',' or ']'"));
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<(), YamlDataError> {
+        self.offset += 1;
+
+        loop {
+            match self.peek() {
+                Some(b'"') => {
+                    self.offset += 1;
+                    return Ok(());
+                }
+                Some(b'\\') => {
+                    self.offset += 1;
+                    match self.peek() {
+                        Some(b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't') => {
+                            self.offset += 1
+                        }
+                        Some(b'u') => {
+                            self.offset += 1;
+                            for _ in 0..4 {
+                                if !self.peek().is_some_and(|b| b.is_ascii_hexdigit()) {
+                                    return Err(self.error("expected a hexadecimal digit"));
+                                }
+                                self.offset += 1;
+                            }
+                        }
+                        _ => return Err(self.error("unknown escape")),
+                    }
+                }
+                Some(byte) if byte >= 0x20 => self.offset += 1,
+                Some(_) => return Err(self.error("control character in a string")),
+                None => return Err(self.error("expected '\"'")),
+            }
+        }
+    }
+
+    // e.g., "-0.5e+3", but neither "01" nor ".5"
+    fn number(&mut self) -> Result<(), YamlDataError> {
+        self.eat(b'-');
+        if !self.eat(b'0') && self.digits() == 0 {
+            return Err(self.error("expected a digit"));
+        }
+        if self.eat(b'.') && self.digits() == 0 {
+            return Err(self.error("expected a digit"));
+        }
+        if self.eat(b'e') || self.eat(b'E') {
+            let _ = self.eat(b'+') || self.eat(b'-');
+            if self.digits() == 0 {
+                return Err(self.error("expected a digit"));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn digits(&mut self) -> usize {
+        let start: usize = self.offset;
+
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.offset += 1;
+        }
+
+        self.offset - start
+    }
+
+    fn whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.offset += 1;
+        }
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let found: bool = self.peek() == Some(byte);
+
+        if found {
+            self.offset += 1;
+        }
+
+        found
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.offset).copied()
+    }
+
+    fn error(&self, message: &str) -> YamlDataError {
+        YamlDataError {
+            message: message.to_string(),
+            offset: self.offset,
+        }
+    }
+}
+
+fn data_value(yaml: &Yaml) -> PlantUmlDataValue {
+    match yaml {
+        Yaml::Boolean(value) => PlantUmlDataValue::Boolean(*value),
+        Yaml::Integer(value) => PlantUmlDataValue::Number(value.to_string()),
+        Yaml::Real(value) => PlantUmlDataValue::Number(value.clone()),
+        Yaml::String(value) => PlantUmlDataValue::Text(value.clone()),
+        Yaml::Array(items) => PlantUmlDataValue::Array(items.iter().map(data_value).collect()),
+        Yaml::Hash(entries) => PlantUmlDataValue::Object(
+            entries
+                .iter()
+                .map(|(key, value)| (key_text(key), data_value(value)))
+                .collect(),
+        ),
+        Yaml::Null | Yaml::Alias(_) | Yaml::BadValue => PlantUmlDataValue::Null,
+    }
+}
+
+// YAML keys need not be strings, e.g., "1: one"
+fn key_text(key: &Yaml) -> String {
+    match data_value(key) {
+        PlantUmlDataValue::Null => "null".to_string(),
+        PlantUmlDataValue::Boolean(value) => value.to_string(),
+        PlantUmlDataValue::Number(value) | PlantUmlDataValue::Text(value) => value,
+        PlantUmlDataValue::Array(_) | PlantUmlDataValue::Object(_) => "?".to_string(),
+    }
+}
+
+// The scanner counts characters, not bytes
+fn data_error(text: &str, error: ScanError) -> YamlDataError {
+    YamlDataError {
+        message: error.info().to_string(),
+        offset: text
+            .char_indices()
+            .nth(error.marker().index())
+            .map(|(offset, _)| offset)
+            .unwrap_or(text.len()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn text(value: &str) -> PlantUmlDataValue {
+        PlantUmlDataValue::Text(value.to_string())
+    }
+
+    #[test]
+    fn json_keys_keep_their_order() {
+        let result =
+            YamlDataReader::new().read(r#"{"name": "Ada", "age": 36, "tags": [true, null]}"#);
+
+        assert_eq!(
+            Ok(PlantUmlDataValue::Object(vec![
+                ("name".to_string(), text("Ada")),
+                (
+                    "age".to_string(),
+                    PlantUmlDataValue::Number("36".to_string())
+                ),
+                (
+                    "tags".to_string(),
+                    PlantUmlDataValue::Array(vec![
+                        PlantUmlDataValue::Boolean(true),
+                        PlantUmlDataValue::Null,
+                    ])
+                ),
+            ])),
+            result
+        );
+    }
+
+    #[test]
+    fn yaml_may_be_indented() {
+        let result = YamlDataReader::new().read("  price: 1.50\n  sizes:\n    - S\n    - M\n");
+
+        assert_eq!(
+            Ok(PlantUmlDataValue::Object(vec![
+                (
+                    "price".to_string(),
+                    PlantUmlDataValue::Number("1.50".to_string())
+                ),
+                (
+                    "sizes".to_string(),
+                    PlantUmlDataValue::Array(vec![text("S"), text("M")])
+                ),
+            ])),
+            result
+        );
+    }
+
+    #[test]
+    fn json_is_read_strictly() {
+        let reader: YamlDataReader = YamlDataReader::new();
+
+        assert_eq!(
+            Err(YamlDataError {
+                message: "expected a key".to_string(),
+                offset: 8,
+            }),
+            reader.read_json(r#"{"a": 1,}"#)
+        );
+        assert_eq!(
+            Err(YamlDataError {
+                message: "expected a value".to_string(),
+                offset: 6,
+            }),
+            reader.read_json("{\"a\": 'b'}")
+        );
+        assert_eq!(
+            Err(YamlDataError {
+                message: "expected end of data".to_string(),
+                offset: 4,
+            }),
+            reader.read_json("[1]\n[2]")
+        );
+        assert_eq!(
+            Ok(PlantUmlDataValue::Array(vec![
+                PlantUmlDataValue::Number("-0.5e+3".to_string()),
+                text("éé"),
+            ])),
+            reader.read_json(r#" [-0.5e+3, "é\u00e9"] "#)
+        );
+    }
+
+    #[test]
+    fn errors_are_located_in_bytes() {
+        let result = YamlDataReader::new().read("{\"é\": [1,}");
+
+        assert_eq!(
+            Err(YamlDataError {
+                message: "while parsing a node, did not find expected node content".to_string(),
+                offset: 10,
+            }),
+            result
+        );
+    }
+}